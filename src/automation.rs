@@ -1,15 +1,12 @@
+use crate::backend::actionability::wait_until_actionable;
+use crate::backend::{BrowserBackend, Check};
 use crate::browser::Browser;
-use crate::error::{Result, WebSpecError};
-
-#[cfg(feature = "chromiumoxide-backend")]
-use chromiumoxide::page::ScreenshotParams;
-
-#[cfg(feature = "chromiumoxide-backend")]
-use chromiumoxide::Page;
-
-#[cfg(feature = "webdriver")]
-use thirtyfour::{WebElement, prelude::*};
+use crate::error::Result;
 
+/// Element-level actions on a browser, whichever backend drives it
+///
+/// Actions first wait, up to [`Browser::auto_wait`], for their element to pass
+/// the [`Check`]s the action needs, and fail naming the check that did not pass.
 pub struct Automation<'a> {
     browser: &'a mut Browser,
 }
@@ -23,585 +20,160 @@ impl<'a> Automation<'a> {
         self.browser
     }
 
-    #[cfg(feature = "chromiumoxide-backend")]
-    fn page(&self) -> Result<&Page> {
-        self.browser.chromium_page().ok_or_else(|| {
-            WebSpecError::Automation("No chromiumoxide page initialized".to_string())
-        })
+    fn backend(&self) -> &dyn BrowserBackend {
+        self.browser.backend()
     }
 
-    #[cfg(feature = "webdriver")]
-    fn driver(&self) -> Result<&WebDriver> {
-        self.browser
-            .driver()
-            .ok_or_else(|| WebSpecError::Automation("No WebDriver initialized".to_string()))
+    async fn actionable(&self, selector: &str, checks: &[Check]) -> Result<()> {
+        wait_until_actionable(self.backend(), selector, checks, self.browser.auto_wait()).await
     }
 
-    #[cfg(feature = "chromiumoxide-backend")]
     pub async fn click(&self, selector: &str) -> Result<()> {
-        let page = self.page()?;
-        let escaped_selector = selector.replace('\\', "\\\\").replace('\'', "\\'");
-        let script = format!("document.querySelector('{}').click()", escaped_selector);
-        page.evaluate(script.as_str()).await?;
-        Ok(())
+        self.actionable(selector, Check::POINTER).await?;
+        self.backend().click(selector).await
     }
 
-    #[cfg(feature = "webdriver")]
-    pub async fn click(&self, selector: &str) -> Result<WebElement> {
-        let driver = self.driver()?;
-        let element = driver.find(By::Css(selector)).await?;
-        element.click().await?;
-        Ok(element)
-    }
-
-    #[cfg(feature = "chromiumoxide-backend")]
     pub async fn type_text(&self, selector: &str, text: &str) -> Result<()> {
-        let page = self.page()?;
-        let escaped_selector = selector.replace('\\', "\\\\").replace('\'', "\\'");
-        let escaped_text = text.replace('\\', "\\\\").replace('\'', "\\'");
-        let script = format!(
-            "document.querySelector('{}').value = '{}'",
-            escaped_selector, escaped_text
-        );
-        page.evaluate(script.as_str()).await?;
-        Ok(())
-    }
-
-    #[cfg(feature = "webdriver")]
-    pub async fn type_text(&self, selector: &str, text: &str) -> Result<WebElement> {
-        let driver = self.driver()?;
-        let element = driver.find(By::Css(selector)).await?;
-        element.send_keys(text).await?;
-        Ok(element)
+        self.actionable(selector, Check::INPUT).await?;
+        self.backend().type_text(selector, text).await
     }
 
-    #[cfg(feature = "chromiumoxide-backend")]
     pub async fn clear_text(&self, selector: &str) -> Result<()> {
-        let page = self.page()?;
-        let script = format!(
-            "document.querySelector('{}').value = ''",
-            selector.replace("'", "\\'")
-        );
-        page.evaluate(script.as_str()).await?;
-        Ok(())
+        self.actionable(selector, Check::INPUT).await?;
+        self.backend().clear_text(selector).await
     }
 
-    #[cfg(feature = "webdriver")]
-    pub async fn clear_text(&self, selector: &str) -> Result<WebElement> {
-        let driver = self.driver()?;
-        let element = driver.find(By::Css(selector)).await?;
-        element.clear().await?;
-        Ok(element)
+    pub async fn select_option(&self, selector: &str, value: &str) -> Result<()> {
+        self.actionable(selector, Check::INPUT).await?;
+        self.backend().select_option(selector, value).await
     }
 
-    #[cfg(feature = "chromiumoxide-backend")]
-    pub async fn select_option(&self, selector: &str, value: &str) -> Result<()> {
-        let page = self.page()?;
-        let script = format!(
-            "document.querySelector('{}').value = '{}'",
-            selector.replace("'", "\\'"),
-            value.replace("'", "\\'")
-        );
-        page.evaluate(script.as_str()).await?;
-        Ok(())
+    /// Press `key` in the focused element, e.g. `Enter`, `ArrowDown` or `a`
+    pub async fn press_key(&self, key: &str) -> Result<()> {
+        self.backend().press_key(key).await
     }
 
-    #[cfg(feature = "webdriver")]
-    pub async fn select_option(&self, selector: &str, value: &str) -> Result<()> {
-        let driver = self.driver()?;
-        let element = driver.find(By::Css(selector)).await?;
-        element.send_keys(value).await?;
-        Ok(())
+    pub async fn drag_and_drop(&self, source: &str, target: &str) -> Result<()> {
+        self.actionable(source, Check::HOVER).await?;
+        self.actionable(target, Check::TARGET).await?;
+        self.backend().drag_and_drop(source, target).await
     }
 
-    #[cfg(feature = "chromiumoxide-backend")]
-    pub async fn wait_for_element(&self, selector: &str, _timeout_ms: u64) -> Result<()> {
-        let page = self.page()?;
-        let script = format!(
-            "!!document.querySelector('{}')",
-            selector.replace("'", "\\'")
-        );
-        page.evaluate(script.as_str()).await?;
-        Ok(())
+    pub async fn drag_by(&self, selector: &str, x: i64, y: i64) -> Result<()> {
+        self.actionable(selector, Check::HOVER).await?;
+        self.backend().drag_by(selector, x, y).await
     }
 
-    #[cfg(feature = "webdriver")]
-    pub async fn wait_for_element(&self, selector: &str, timeout_ms: u64) -> Result<WebElement> {
-        let driver = self.driver()?;
-        let element = driver
-            .query(By::Css(selector))
-            .wait(
-                std::time::Duration::from_millis(timeout_ms),
-                std::time::Duration::from_millis(100),
-            )
-            .first()
-            .await
-            .map_err(|_| WebSpecError::Timeout)?;
-        Ok(element)
-    }
-
-    #[cfg(feature = "chromiumoxide-backend")]
-    pub async fn wait_for_element_visible(&self, selector: &str, _timeout_ms: u64) -> Result<bool> {
-        let page = self.page()?;
-        let script = format!(
-            "!!document.querySelector('{}')",
-            selector.replace("'", "\\'")
-        );
-        let value: serde_json::Value = page.evaluate(script.as_str()).await?.into_value()?;
-        Ok(value.as_bool().unwrap_or(false))
-    }
-
-    #[cfg(feature = "webdriver")]
-    pub async fn wait_for_element_visible(&self, selector: &str, timeout_ms: u64) -> Result<bool> {
-        let driver = self.driver()?;
-        let element = driver
-            .query(By::Css(selector))
-            .wait(
-                std::time::Duration::from_millis(timeout_ms),
-                std::time::Duration::from_millis(100),
-            )
-            .first()
-            .await;
-        Ok(element.is_ok())
-    }
-
-    #[cfg(feature = "chromiumoxide-backend")]
-    pub async fn element_exists(&self, selector: &str) -> Result<bool> {
-        let page = self.page()?;
-        let script = format!(
-            "!!document.querySelector('{}')",
-            selector.replace("'", "\\'")
-        );
-        let value: serde_json::Value = page.evaluate(script.as_str()).await?.into_value()?;
-        Ok(value.as_bool().unwrap_or(false))
+    pub async fn tap(&self, selector: &str, fingers: u32) -> Result<()> {
+        self.actionable(selector, Check::HOVER).await?;
+        self.backend().tap(selector, fingers).await
     }
 
-    #[cfg(feature = "webdriver")]
-    pub async fn element_exists(&self, selector: &str) -> Result<bool> {
-        let driver = self.driver()?;
-        match driver.query(By::Css(selector)).first().await {
-            Ok(_) => Ok(true),
-            Err(_) => Ok(false),
-        }
+    pub async fn swipe(&self, from: &str, to: &str) -> Result<()> {
+        self.actionable(from, Check::HOVER).await?;
+        self.actionable(to, Check::TARGET).await?;
+        self.backend().swipe(from, to).await
     }
 
-    #[cfg(feature = "chromiumoxide-backend")]
-    pub async fn element_visible(&self, selector: &str) -> Result<bool> {
-        let page = self.page()?;
-        let script = format!(
-            r#"(() => {{
-                const el = document.querySelector('{}');
-                return el && el.offsetParent !== null;
-            }})()"#,
-            selector.replace("'", "\\'")
-        );
-        let value: serde_json::Value = page.evaluate(script.as_str()).await?.into_value()?;
-        Ok(value.as_bool().unwrap_or(false))
-    }
-
-    #[cfg(feature = "webdriver")]
-    pub async fn element_visible(&self, selector: &str) -> Result<bool> {
-        let driver = self.driver()?;
-        match driver.find(By::Css(selector)).await {
-            Ok(element) => {
-                let displayed = element.is_displayed().await.unwrap_or(false);
-                Ok(displayed)
-            }
-            Err(_) => Ok(false),
-        }
+    pub async fn pinch(&self, selector: &str, scale: f64) -> Result<()> {
+        self.actionable(selector, Check::HOVER).await?;
+        self.backend().pinch(selector, scale).await
     }
 
-    #[cfg(feature = "chromiumoxide-backend")]
-    pub async fn scroll_to_bottom(&self) -> Result<()> {
-        let page = self.page()?;
-        page.evaluate("window.scrollTo(0, document.body.scrollHeight)")
-            .await?;
-        Ok(())
+    pub async fn rotate(&self, selector: &str, degrees: f64) -> Result<()> {
+        self.actionable(selector, Check::HOVER).await?;
+        self.backend().rotate(selector, degrees).await
     }
 
-    #[cfg(feature = "webdriver")]
-    pub async fn scroll_to_bottom(&self) -> Result<()> {
-        let driver = self.driver()?;
-        driver
-            .execute("window.scrollTo(0, document.body.scrollHeight);", vec![])
-            .await?;
-        Ok(())
+    pub async fn wait_for_element(&self, selector: &str, timeout_ms: u64) -> Result<()> {
+        self.backend().wait_for_element(selector, timeout_ms).await
     }
 
-    #[cfg(feature = "chromiumoxide-backend")]
-    pub async fn scroll_to_top(&self) -> Result<()> {
-        let page = self.page()?;
-        page.evaluate("window.scrollTo(0, 0)").await?;
-        Ok(())
+    pub async fn wait_for_element_visible(&self, selector: &str, timeout_ms: u64) -> Result<()> {
+        self.backend()
+            .wait_for_element_visible(selector, timeout_ms)
+            .await
     }
 
-    #[cfg(feature = "webdriver")]
-    pub async fn scroll_to_top(&self) -> Result<()> {
-        let driver = self.driver()?;
-        driver.execute("window.scrollTo(0, 0);", vec![]).await?;
-        Ok(())
+    pub async fn element_exists(&self, selector: &str) -> Result<bool> {
+        self.backend().element_exists(selector).await
     }
 
-    #[cfg(feature = "chromiumoxide-backend")]
-    pub async fn scroll_to_element(&self, selector: &str) -> Result<()> {
-        let page = self.page()?;
-        let script = format!(
-            "document.querySelector('{}').scrollIntoView({{behavior: 'smooth', block: 'center'}})",
-            selector.replace("'", "\\'")
-        );
-        page.evaluate(script.as_str()).await?;
-        Ok(())
+    pub async fn element_visible(&self, selector: &str) -> Result<bool> {
+        self.backend().element_visible(selector).await
     }
 
-    #[cfg(feature = "webdriver")]
-    pub async fn scroll_to_element(&self, selector: &str) -> Result<WebElement> {
-        let driver = self.driver()?;
-        let element = driver.find(By::Css(selector)).await?;
-        driver
-            .execute(
-                "arguments[0].scrollIntoView({behavior: 'smooth', block: 'center'});",
-                vec![serde_json::to_value(&element)?],
-            )
-            .await?;
-        Ok(element)
+    pub async fn scroll_to_bottom(&self) -> Result<()> {
+        self.backend().scroll_to_bottom().await
     }
 
-    #[cfg(feature = "chromiumoxide-backend")]
-    pub async fn scroll_by(&self, x: i64, y: i64) -> Result<()> {
-        let page = self.page()?;
-        page.evaluate(format!("window.scrollBy({}, {})", x, y).as_str())
-            .await?;
-        Ok(())
+    pub async fn scroll_to_top(&self) -> Result<()> {
+        self.backend().scroll_to_top().await
     }
 
-    #[cfg(feature = "webdriver")]
-    pub async fn scroll_by(&self, x: i64, y: i64) -> Result<()> {
-        let driver = self.driver()?;
-        driver
-            .execute(&format!("window.scrollBy({}, {});", x, y), vec![])
-            .await?;
-        Ok(())
+    pub async fn scroll_to_element(&self, selector: &str) -> Result<()> {
+        self.actionable(selector, Check::SCROLL).await?;
+        self.backend().scroll_to_element(selector).await
     }
 
-    #[cfg(feature = "chromiumoxide-backend")]
-    pub async fn execute_script(&self, script: &str) -> Result<()> {
-        let page = self.page()?;
-        page.evaluate(script).await?;
-        Ok(())
+    pub async fn scroll_by(&self, x: i64, y: i64) -> Result<()> {
+        self.backend().scroll_by(x, y).await
     }
 
-    #[cfg(feature = "webdriver")]
     pub async fn execute_script(&self, script: &str) -> Result<()> {
-        let driver = self.driver()?;
-        driver.execute(script, vec![]).await?;
-        Ok(())
+        self.backend().execute_script(script).await
     }
 
-    #[cfg(feature = "chromiumoxide-backend")]
-    pub async fn get_text(&self, selector: &str) -> Result<String> {
-        let page = self.page()?;
-        let script = format!(
-            "document.querySelector('{}').textContent",
-            selector.replace("'", "\\'")
-        );
-        let value: serde_json::Value = page.evaluate(script.as_str()).await?.into_value()?;
-        if let Some(text) = value.as_str() {
-            Ok(text.trim().to_string())
-        } else {
-            Ok(value.to_string())
-        }
-    }
-
-    #[cfg(feature = "webdriver")]
     pub async fn get_text(&self, selector: &str) -> Result<String> {
-        let driver = self.driver()?;
-        let element = driver.find(By::Css(selector)).await?;
-        let text = element.text().await?;
-        Ok(text)
+        self.backend().get_text(selector).await
     }
 
-    #[cfg(feature = "chromiumoxide-backend")]
-    pub async fn get_attribute(&self, selector: &str, attribute: &str) -> Result<String> {
-        let page = self.page()?;
-        let script = format!(
-            "document.querySelector('{}').getAttribute('{}')",
-            selector.replace("'", "\\'"),
-            attribute.replace("'", "\\'")
-        );
-        let value: serde_json::Value = page.evaluate(script.as_str()).await?.into_value()?;
-        if let Some(attr) = value.as_str() {
-            Ok(attr.trim().to_string())
-        } else {
-            Err(WebSpecError::Automation(format!(
-                "Attribute '{}' not found",
-                attribute
-            )))
-        }
-    }
-
-    #[cfg(feature = "webdriver")]
     pub async fn get_attribute(&self, selector: &str, attribute: &str) -> Result<String> {
-        let driver = self.driver()?;
-        let element = driver.find(By::Css(selector)).await?;
-        let attr = element.attr(attribute).await?.ok_or_else(|| {
-            WebSpecError::Automation(format!("Attribute '{}' not found", attribute))
-        })?;
-        Ok(attr)
+        self.backend().get_attribute(selector, attribute).await
     }
 
-    #[cfg(feature = "chromiumoxide-backend")]
     pub async fn get_html(&self, selector: &str) -> Result<String> {
-        let page = self.page()?;
-        let script = format!(
-            "document.querySelector('{}').outerHTML",
-            selector.replace("'", "\\'")
-        );
-        let value: serde_json::Value = page.evaluate(script.as_str()).await?.into_value()?;
-        if let Some(html_str) = value.as_str() {
-            Ok(html_str.to_string())
-        } else {
-            Err(WebSpecError::Automation(
-                "Failed to convert HTML to string".to_string(),
-            ))
-        }
-    }
-
-    #[cfg(feature = "webdriver")]
-    pub async fn get_html(&self, selector: &str) -> Result<String> {
-        let driver = self.driver()?;
-        let element = driver.find(By::Css(selector)).await?;
-        let html = driver
-            .execute(
-                "return arguments[0].outerHTML;",
-                vec![serde_json::to_value(&element)?],
-            )
-            .await?;
-        let html_value = html.json();
-        if let Some(html_str) = html_value.as_str() {
-            Ok(html_str.to_string())
-        } else {
-            Err(WebSpecError::Automation(
-                "Failed to convert HTML to string".to_string(),
-            ))
-        }
-    }
-
-    #[cfg(feature = "chromiumoxide-backend")]
-    pub async fn wait_for_load(&self) -> Result<()> {
-        tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
-        Ok(())
+        self.backend().get_html(selector).await
     }
 
-    #[cfg(feature = "webdriver")]
     pub async fn wait_for_load(&self) -> Result<()> {
         tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
         Ok(())
     }
 
-    #[cfg(feature = "chromiumoxide-backend")]
     pub async fn hover(&self, selector: &str) -> Result<()> {
-        let page = self.page()?;
-        let script = format!(
-            r#"const el = document.querySelector('{}'); 
-            const evt = new MouseEvent('mouseover', {{bubbles: true, cancelable: true}}); 
-            el.dispatchEvent(evt);"#,
-            selector.replace("'", "\\'")
-        );
-        page.evaluate(script.as_str()).await?;
-        Ok(())
-    }
-
-    #[cfg(feature = "webdriver")]
-    pub async fn hover(&self, selector: &str) -> Result<WebElement> {
-        let driver = self.driver()?;
-        let element = driver.find(By::Css(selector)).await?;
-        driver.execute(
-            "var evt = new MouseEvent('mouseover', {bubbles: true, cancelable: true}); arguments[0].dispatchEvent(evt);",
-            vec![serde_json::to_value(&element)?]
-        ).await?;
-        Ok(element)
+        self.actionable(selector, Check::HOVER).await?;
+        self.backend().hover(selector).await
     }
 
-    #[cfg(feature = "chromiumoxide-backend")]
     pub async fn right_click(&self, selector: &str) -> Result<()> {
-        let page = self.page()?;
-        let script = format!(
-            r#"const el = document.querySelector('{}'); 
-            const evt = new MouseEvent('contextmenu', {{bubbles: true, cancelable: true}}); 
-            el.dispatchEvent(evt);"#,
-            selector.replace("'", "\\'")
-        );
-        page.evaluate(script.as_str()).await?;
-        Ok(())
+        self.actionable(selector, Check::POINTER).await?;
+        self.backend().right_click(selector).await
     }
 
-    #[cfg(feature = "webdriver")]
-    pub async fn right_click(&self, selector: &str) -> Result<WebElement> {
-        let driver = self.driver()?;
-        let element = driver.find(By::Css(selector)).await?;
-        driver.execute(
-            "var evt = new MouseEvent('contextmenu', {bubbles: true, cancelable: true}); arguments[0].dispatchEvent(evt);",
-            vec![serde_json::to_value(&element)?]
-        ).await?;
-        Ok(element)
-    }
-
-    #[cfg(feature = "chromiumoxide-backend")]
     pub async fn double_click(&self, selector: &str) -> Result<()> {
-        let page = self.page()?;
-        let script = format!(
-            r#"const el = document.querySelector('{}'); 
-            const evt = new MouseEvent('dblclick', {{bubbles: true, cancelable: true}}); 
-            el.dispatchEvent(evt);"#,
-            selector.replace("'", "\\'")
-        );
-        page.evaluate(script.as_str()).await?;
-        Ok(())
+        self.actionable(selector, Check::POINTER).await?;
+        self.backend().double_click(selector).await
     }
 
-    #[cfg(feature = "webdriver")]
-    pub async fn double_click(&self, selector: &str) -> Result<WebElement> {
-        let driver = self.driver()?;
-        let element = driver.find(By::Css(selector)).await?;
-        driver.execute(
-            "var evt = new MouseEvent('dblclick', {bubbles: true, cancelable: true}); arguments[0].dispatchEvent(evt);",
-            vec![serde_json::to_value(&element)?]
-        ).await?;
-        Ok(element)
+    pub async fn get_all_links(&self) -> Result<Vec<String>> {
+        self.backend().get_all_links().await
     }
 
-    #[cfg(feature = "chromiumoxide-backend")]
-    pub async fn get_all_links(&self) -> Result<Vec<String>> {
-        let page = self.page()?;
-        let result = page
-            .evaluate("Array.from(document.querySelectorAll('a[href]')).map(a => a.href)")
-            .await?;
-        let value: serde_json::Value = result.into_value()?;
-        if let Some(arr) = value.as_array() {
-            let mut links = Vec::new();
-            for item in arr {
-                if let Some(s) = item.as_str() {
-                    links.push(s.to_string());
-                }
-            }
-            Ok(links)
-        } else {
-            Ok(Vec::new())
-        }
-    }
-
-    #[cfg(feature = "webdriver")]
-    pub async fn get_all_links(&self) -> Result<Vec<String>> {
-        let driver = self.driver()?;
-        let elements = driver.find_all(By::Css("a[href]")).await?;
-        let mut links = Vec::new();
-        for element in elements {
-            if let Some(href) = element.attr("href").await? {
-                links.push(href);
-            }
-        }
-        Ok(links)
-    }
-
-    #[cfg(feature = "chromiumoxide-backend")]
-    pub async fn get_all_images(&self) -> Result<Vec<String>> {
-        let page = self.page()?;
-        let result = page
-            .evaluate("Array.from(document.querySelectorAll('img[src]')).map(img => img.src)")
-            .await?;
-        let value: serde_json::Value = result.into_value()?;
-        if let Some(arr) = value.as_array() {
-            let mut images = Vec::new();
-            for item in arr {
-                if let Some(s) = item.as_str() {
-                    images.push(s.to_string());
-                }
-            }
-            Ok(images)
-        } else {
-            Ok(Vec::new())
-        }
-    }
-
-    #[cfg(feature = "webdriver")]
     pub async fn get_all_images(&self) -> Result<Vec<String>> {
-        let driver = self.driver()?;
-        let elements = driver.find_all(By::Css("img[src]")).await?;
-        let mut images = Vec::new();
-        for element in elements {
-            if let Some(src) = element.attr("src").await? {
-                images.push(src);
-            }
-        }
-        Ok(images)
-    }
-
-    #[cfg(feature = "chromiumoxide-backend")]
-    pub async fn get_all_headings(&self, level: u32) -> Result<Vec<String>> {
-        let page = self.page()?;
-        let script = format!(
-            "Array.from(document.querySelectorAll('h{}')).map(h => h.textContent)",
-            level
-        );
-        let result = page.evaluate(script.as_str()).await?;
-        let value: serde_json::Value = result.into_value()?;
-        if let Some(arr) = value.as_array() {
-            let mut headings = Vec::new();
-            for item in arr {
-                if let Some(s) = item.as_str() {
-                    headings.push(s.trim().to_string());
-                }
-            }
-            Ok(headings)
-        } else {
-            Ok(Vec::new())
-        }
-    }
-
-    #[cfg(feature = "webdriver")]
-    pub async fn get_all_headings(&self, level: u32) -> Result<Vec<String>> {
-        let driver = self.driver()?;
-        let selector = &format!("h{}", level);
-        let elements = driver.find_all(By::Css(selector)).await?;
-        let mut headings = Vec::new();
-        for element in elements {
-            let text = element.text().await?;
-            headings.push(text);
-        }
-        Ok(headings)
-    }
-
-    #[cfg(feature = "chromiumoxide-backend")]
-    pub async fn count_elements(&self, selector: &str) -> Result<usize> {
-        let page = self.page()?;
-        let script = format!(
-            "document.querySelectorAll('{}').length",
-            selector.replace("'", "\\'")
-        );
-        let result = page.evaluate(script.as_str()).await?;
-        let value: serde_json::Value = result.into_value()?;
-        Ok(value.as_u64().unwrap_or(0) as usize)
+        self.backend().get_all_images().await
     }
 
-    #[cfg(feature = "webdriver")]
-    pub async fn count_elements(&self, selector: &str) -> Result<usize> {
-        let driver = self.driver()?;
-        let elements = driver.find_all(By::Css(selector)).await?;
-        Ok(elements.len())
+    pub async fn get_all_headings(&self, level: u32) -> Result<Vec<String>> {
+        self.backend().get_all_headings(level).await
     }
 
-    #[cfg(feature = "chromiumoxide-backend")]
-    pub async fn take_screenshot(&self, path: &str) -> Result<()> {
-        let page = self.page()?;
-        let screenshot = page.screenshot(ScreenshotParams::builder().build()).await?;
-        std::fs::write(path, screenshot)?;
-        Ok(())
+    pub async fn count_elements(&self, selector: &str) -> Result<usize> {
+        self.backend().count_elements(selector).await
     }
 
-    #[cfg(feature = "webdriver")]
     pub async fn take_screenshot(&self, path: &str) -> Result<()> {
-        let driver = self.driver()?;
-        let screenshot = driver.screenshot_as_png().await?;
+        let screenshot = self.backend().screenshot().await?;
         std::fs::write(path, screenshot)?;
         Ok(())
     }
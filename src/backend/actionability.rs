@@ -0,0 +1,233 @@
+// Actionability checks that `Automation` runs before acting on an element
+use super::{BrowserBackend, POLL_INTERVAL, check_selector, js_string};
+use crate::error::{Result, WebSpecError};
+use serde_json::{Value, json};
+use std::fmt;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// How long actions wait for their element to become actionable by default
+pub const DEFAULT_AUTO_WAIT_MS: u64 = 5000;
+
+/// `el => bool`: whether an element has a layout box and is not hidden by
+/// `visibility`, or by `display: none` or zero opacity on it or an ancestor
+pub(crate) const IS_VISIBLE: &str = r#"el => {
+    if (!el || !el.isConnected) return false;
+    const rect = el.getBoundingClientRect();
+    if (rect.width === 0 || rect.height === 0) return false;
+    if (getComputedStyle(el).visibility !== 'visible') return false;
+    for (let node = el; node; node = node.parentElement) {
+        const style = getComputedStyle(node);
+        if (style.display === 'none' || parseFloat(style.opacity) === 0) return false;
+    }
+    return true;
+}"#;
+
+/// `async (el, checks) => true | [check, detail]`: the first of `checks` the
+/// element fails, in [`Check`] order
+const FIRST_FAILED_CHECK: &str = r#"async (el, checks) => {
+    if (!el || !el.isConnected) return ['attached', 'no element matches'];
+    if (checks.includes('visible') && !(IS_VISIBLE)(el)) {
+        return ['visible', 'it has no size, or display, visibility or opacity hides it'];
+    }
+    if (checks.includes('stable')) {
+        const frame = () => new Promise(requestAnimationFrame);
+        const before = el.getBoundingClientRect();
+        await frame();
+        await frame();
+        const after = el.getBoundingClientRect();
+        if (['x', 'y', 'width', 'height'].some(key => before[key] !== after[key])) {
+            return ['stable', 'it is still moving or resizing'];
+        }
+    }
+    if (checks.includes('enabled')
+        && (el.matches(':disabled') || el.getAttribute('aria-disabled') === 'true')) {
+        return ['enabled', 'it is disabled'];
+    }
+    if (checks.includes('receives_events')) {
+        el.scrollIntoView({block: 'center', inline: 'center', behavior: 'instant'});
+        const rect = el.getBoundingClientRect();
+        const hit = document.elementFromPoint(rect.left + rect.width / 2, rect.top + rect.height / 2);
+        if (!hit) return ['receives_events', 'its center is outside the viewport'];
+        if (hit !== el && !el.contains(hit)) {
+            const id = hit.id ? '#' + hit.id : '';
+            const classes = [...hit.classList].map(name => '.' + name).join('');
+            return ['receives_events', `${hit.tagName.toLowerCase()}${id}${classes} is on top of it`];
+        }
+    }
+    return true;
+}"#;
+
+/// A condition an element must meet before an action is performed on it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Check {
+    /// An element matches the selector and is in the document
+    Attached,
+    /// It has a layout box and is not hidden by `display`, `visibility` or opacity
+    Visible,
+    /// Its bounding box is the same over two animation frames
+    Stable,
+    /// It is not `disabled` (also through a fieldset) or `aria-disabled`
+    Enabled,
+    /// Once scrolled into view, it or a descendant is the hit target at its center
+    ReceivesEvents,
+}
+
+impl Check {
+    /// Clicks, double clicks and right clicks
+    pub const POINTER: &'static [Check] = &[
+        Check::Attached,
+        Check::Visible,
+        Check::Stable,
+        Check::Enabled,
+        Check::ReceivesEvents,
+    ];
+
+    /// Hovering, dragging and touch gestures, which work on disabled elements too
+    pub const HOVER: &'static [Check] = &[
+        Check::Attached,
+        Check::Visible,
+        Check::Stable,
+        Check::ReceivesEvents,
+    ];
+
+    /// Typing, clearing and selecting
+    pub const INPUT: &'static [Check] = &[Check::Attached, Check::Visible, Check::Enabled];
+
+    /// Where something is dragged or swiped to
+    pub const TARGET: &'static [Check] = &[Check::Attached, Check::Visible];
+
+    /// Scrolling an element into view
+    pub const SCROLL: &'static [Check] = &[Check::Attached];
+
+    fn key(self) -> &'static str {
+        match self {
+            Check::Attached => "attached",
+            Check::Visible => "visible",
+            Check::Stable => "stable",
+            Check::Enabled => "enabled",
+            Check::ReceivesEvents => "receives_events",
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        [
+            Check::Attached,
+            Check::Visible,
+            Check::Stable,
+            Check::Enabled,
+            Check::ReceivesEvents,
+        ]
+        .into_iter()
+        .find(|check| check.key() == key)
+    }
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Check::ReceivesEvents => write!(f, "receiving events"),
+            other => write!(f, "{}", other.key()),
+        }
+    }
+}
+
+/// Wait up to `timeout` for the first element matching `selector` to pass `checks`
+///
+/// Fails with [`WebSpecError::NotActionable`] naming the check that was still
+/// failing when the time ran out, or when the page did not finish checking in time.
+pub async fn wait_until_actionable(
+    backend: &dyn BrowserBackend,
+    selector: &str,
+    checks: &[Check],
+    timeout: Duration,
+) -> Result<()> {
+    check_selector(backend, selector).await?;
+    let keys: Vec<&str> = checks.iter().map(|check| check.key()).collect();
+    let script = format!(
+        "({})(document.querySelector({}), {})",
+        FIRST_FAILED_CHECK.replace("IS_VISIBLE", IS_VISIBLE),
+        js_string(selector),
+        json!(keys)
+    );
+
+    let deadline = Instant::now() + timeout;
+    loop {
+        // Evaluation fails while a navigation swaps the document, so that is retried too
+        // The stable check waits for animation frames, which throttled pages may never run
+        let evaluation = match tokio::time::timeout_at(deadline, backend.evaluate(&script)).await {
+            Ok(evaluation) => evaluation,
+            Err(_) => {
+                return Err(WebSpecError::NotActionable {
+                    selector: selector.to_string(),
+                    check: if checks.contains(&Check::Stable) {
+                        Check::Stable
+                    } else {
+                        Check::Attached
+                    },
+                    waited_ms: timeout.as_millis() as u64,
+                    detail: "the page did not finish the checks in time".to_string(),
+                });
+            }
+        };
+        let (check, detail) = match evaluation {
+            Ok(Value::Bool(true)) => return Ok(()),
+            Ok(Value::Array(failure)) => (
+                failure
+                    .first()
+                    .and_then(Value::as_str)
+                    .and_then(Check::from_key)
+                    .unwrap_or(Check::Attached),
+                failure
+                    .get(1)
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+            ),
+            Ok(other) => (
+                Check::Attached,
+                format!("unexpected check result {}", other),
+            ),
+            Err(e) => (Check::Attached, e.to_string()),
+        };
+        let now = Instant::now();
+        if now >= deadline {
+            return Err(WebSpecError::NotActionable {
+                selector: selector.to_string(),
+                check,
+                waited_ms: timeout.as_millis() as u64,
+                detail,
+            });
+        }
+        tokio::time::sleep(POLL_INTERVAL.min(deadline - now)).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_keys_round_trip() {
+        for &check in Check::POINTER {
+            assert_eq!(Check::from_key(check.key()), Some(check));
+            assert!(FIRST_FAILED_CHECK.contains(&format!("'{}'", check.key())));
+        }
+        assert_eq!(Check::ReceivesEvents.to_string(), "receiving events");
+        assert!(!Check::HOVER.contains(&Check::Enabled));
+    }
+
+    #[test]
+    fn test_not_actionable_message() {
+        let err = WebSpecError::NotActionable {
+            selector: "#submit".to_string(),
+            check: Check::ReceivesEvents,
+            waited_ms: 5000,
+            detail: "div.overlay is on top of it".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "'#submit' is not receiving events after 5000 ms: div.overlay is on top of it"
+        );
+    }
+}
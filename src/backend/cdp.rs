@@ -0,0 +1,856 @@
+// Chromium driven over the DevTools protocol with chromiumoxide
+use super::actionability::IS_VISIBLE;
+use super::{BrowserBackend, BrowserConfig, POLL_INTERVAL, check_selector, js_string};
+use crate::browser::BrowserType;
+use crate::error::{Result, WebSpecError};
+use async_trait::async_trait;
+use chromiumoxide::browser::HeadlessMode;
+use chromiumoxide::cdp::browser_protocol::emulation::{
+    SetLocaleOverrideParams, SetTimezoneOverrideParams,
+};
+use chromiumoxide::cdp::browser_protocol::input::{
+    DispatchDragEventParams, DispatchDragEventType, DispatchKeyEventParams, DispatchKeyEventType,
+    DispatchMouseEventParams, DispatchMouseEventType, DispatchTouchEventParams,
+    DispatchTouchEventType, EventDragIntercepted, InsertTextParams, MouseButton,
+    SetInterceptDragsParams, TouchPoint,
+};
+use chromiumoxide::handler::viewport::Viewport;
+use chromiumoxide::keys;
+use chromiumoxide::layout::Point;
+use chromiumoxide::page::ScreenshotParams;
+use chromiumoxide::{Browser as ChromiumBrowser, Page};
+use futures_util::StreamExt;
+use std::any::Any;
+use std::path::PathBuf;
+use std::time::Duration;
+use tokio::time::Instant;
+
+pub struct CdpBackend {
+    browser: ChromiumBrowser,
+    page: Page,
+    #[allow(dead_code)]
+    handler_task: tokio::task::JoinHandle<()>,
+    /// Profile directory owned by this browser, removed when it closes
+    profile_dir: Option<PathBuf>,
+    locale: Option<String>,
+    timezone: Option<String>,
+}
+
+impl CdpBackend {
+    /// Launch Chromium as `config` describes and open a blank page
+    ///
+    /// A `profile_dir` replaces the configured user data dir and is removed when
+    /// the browser closes.
+    pub async fn launch(config: &BrowserConfig, profile_dir: Option<PathBuf>) -> Result<Self> {
+        let mut builder = chromiumoxide::BrowserConfig::builder()
+            .no_sandbox()
+            .headless_mode(if config.headless {
+                HeadlessMode::New
+            } else {
+                HeadlessMode::False
+            })
+            .args(&config.args);
+        if let Some(executable) = &config.executable {
+            builder = builder.chrome_executable(executable);
+        }
+        if let Some(size) = config.window_size {
+            builder = builder
+                .window_size(size.width, size.height)
+                .viewport(Viewport {
+                    width: size.width,
+                    height: size.height,
+                    ..Default::default()
+                });
+        }
+        if let Some(locale) = &config.locale {
+            builder = builder.arg(format!("--lang={}", locale));
+        }
+        if let Some(timezone) = &config.timezone {
+            builder = builder.env("TZ", timezone);
+        }
+        if let Some(proxy) = &config.proxy {
+            builder = builder.arg(format!("--proxy-server={}", proxy));
+        }
+        if let Some(dir) = profile_dir.as_ref().or(config.user_data_dir.as_ref()) {
+            builder = builder.user_data_dir(dir);
+        }
+
+        let (browser, mut handler) = ChromiumBrowser::launch(builder.build()?).await?;
+
+        let handler_task = tokio::spawn(async move {
+            while let Some(_event) = handler.next().await {
+                // Just consume events
+            }
+        });
+
+        let page = browser.new_page("about:blank").await?;
+        let backend = Self {
+            browser,
+            page,
+            handler_task,
+            profile_dir,
+            locale: config.locale.clone(),
+            timezone: config.timezone.clone(),
+        };
+        backend.emulate(&backend.page).await?;
+        Ok(backend)
+    }
+
+    /// Apply the configured locale and time zone to a new page
+    async fn emulate(&self, page: &Page) -> Result<()> {
+        if let Some(locale) = &self.locale {
+            page.emulate_locale(SetLocaleOverrideParams::builder().locale(locale).build())
+                .await?;
+        }
+        if let Some(timezone) = &self.timezone {
+            page.emulate_timezone(SetTimezoneOverrideParams::new(timezone))
+                .await?;
+        }
+        Ok(())
+    }
+
+    pub fn browser(&self) -> &ChromiumBrowser {
+        &self.browser
+    }
+
+    pub fn page(&self) -> &Page {
+        &self.page
+    }
+
+    async fn evaluate_strings(&self, script: &str) -> Result<Vec<String>> {
+        let value = self.evaluate(script).await?;
+        Ok(value
+            .as_array()
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(|item| item.as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    /// Evaluate `condition` every [`POLL_INTERVAL`] until it returns `true`, failing
+    /// with a timeout that says what `selector` did not do
+    async fn poll(
+        &self,
+        selector: &str,
+        condition: &str,
+        timeout_ms: u64,
+        what: &str,
+    ) -> Result<()> {
+        check_selector(self, selector).await?;
+
+        let deadline = Instant::now() + Duration::from_millis(timeout_ms);
+        loop {
+            // Evaluation fails while a navigation swaps the document, so that is retried too
+            let result = tokio::time::timeout_at(deadline, self.evaluate(condition)).await;
+            if matches!(result, Ok(Ok(serde_json::Value::Bool(true)))) {
+                return Ok(());
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(WebSpecError::Timeout(format!(
+                    "'{}' did not {} within {} ms",
+                    selector, what, timeout_ms
+                )));
+            }
+            tokio::time::sleep(POLL_INTERVAL.min(deadline - now)).await;
+        }
+    }
+
+    /// Center of the first element matching `selector` in viewport CSS pixels,
+    /// after scrolling it into view if it is outside
+    async fn center(&self, selector: &str) -> Result<Point> {
+        let script = format!(
+            r#"(el => {{
+                if (!el) return false;
+                el.scrollIntoViewIfNeeded(true);
+                const rect = el.getBoundingClientRect();
+                return [rect.left + rect.width / 2, rect.top + rect.height / 2];
+            }})(document.querySelector({}))"#,
+            js_string(selector)
+        );
+        match self.evaluate(&script).await? {
+            serde_json::Value::Array(xy) => Ok(Point {
+                x: xy
+                    .first()
+                    .and_then(serde_json::Value::as_f64)
+                    .unwrap_or(0.0),
+                y: xy.get(1).and_then(serde_json::Value::as_f64).unwrap_or(0.0),
+            }),
+            _ => Err(WebSpecError::Automation(format!(
+                "No element matches '{}'",
+                selector
+            ))),
+        }
+    }
+
+    /// Focus the element matching `selector` and select all of its text, so typing
+    /// replaces it
+    async fn focus_and_select(&self, selector: &str) -> Result<()> {
+        let script = format!(
+            r#"(el => {{
+                if (!el) return false;
+                el.focus();
+                if (typeof el.select === 'function') {{
+                    el.select();
+                }} else {{
+                    const range = document.createRange();
+                    range.selectNodeContents(el);
+                    getSelection().removeAllRanges();
+                    getSelection().addRange(range);
+                }}
+                return true;
+            }})(document.querySelector({}))"#,
+            js_string(selector)
+        );
+        match self.evaluate(&script).await? {
+            serde_json::Value::Bool(true) => Ok(()),
+            _ => Err(WebSpecError::Automation(format!(
+                "No element matches '{}'",
+                selector
+            ))),
+        }
+    }
+
+    /// `Input.dispatchMouseEvent` at `point`; `buttons` is the mask of buttons held
+    async fn mouse(
+        &self,
+        kind: DispatchMouseEventType,
+        point: Point,
+        button: MouseButton,
+        buttons: i64,
+        click_count: i64,
+    ) -> Result<()> {
+        let params = DispatchMouseEventParams::builder()
+            .r#type(kind)
+            .x(point.x)
+            .y(point.y)
+            .button(button)
+            .buttons(buttons)
+            .click_count(click_count)
+            .build()?;
+        self.page.execute(params).await?;
+        Ok(())
+    }
+
+    /// Move to `point` and press and release `button`, `count` times in a row
+    async fn click_at(&self, point: Point, button: MouseButton, count: i64) -> Result<()> {
+        let mask = match button {
+            MouseButton::Right => 2,
+            MouseButton::Middle => 4,
+            _ => 1,
+        };
+        self.mouse(
+            DispatchMouseEventType::MouseMoved,
+            point,
+            MouseButton::None,
+            0,
+            0,
+        )
+        .await?;
+        for click_count in 1..=count {
+            self.mouse(
+                DispatchMouseEventType::MousePressed,
+                point,
+                button.clone(),
+                mask,
+                click_count,
+            )
+            .await?;
+            self.mouse(
+                DispatchMouseEventType::MouseReleased,
+                point,
+                button.clone(),
+                0,
+                click_count,
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    /// Press the left button at `from`, move to `to` in steps and release it there
+    ///
+    /// Native HTML drags are intercepted and finished with `Input.dispatchDragEvent`,
+    /// which headless Chromium needs to drop anything.
+    async fn drag(&self, from: Point, to: Point) -> Result<()> {
+        self.page
+            .execute(SetInterceptDragsParams::new(true))
+            .await?;
+        let result = self.drag_intercepted(from, to).await;
+        self.page
+            .execute(SetInterceptDragsParams::new(false))
+            .await?;
+        result
+    }
+
+    async fn drag_intercepted(&self, from: Point, to: Point) -> Result<()> {
+        let mut intercepted = self.page.event_listener::<EventDragIntercepted>().await?;
+        self.mouse(
+            DispatchMouseEventType::MouseMoved,
+            from,
+            MouseButton::None,
+            0,
+            0,
+        )
+        .await?;
+        self.mouse(
+            DispatchMouseEventType::MousePressed,
+            from,
+            MouseButton::Left,
+            1,
+            1,
+        )
+        .await?;
+        for step in 1..=GESTURE_STEPS {
+            let point = lerp(from, to, step as f64 / GESTURE_STEPS as f64);
+            self.mouse(
+                DispatchMouseEventType::MouseMoved,
+                point,
+                MouseButton::Left,
+                1,
+                0,
+            )
+            .await?;
+        }
+
+        // Pages that drag with plain mouse events never start a native drag
+        let drag = tokio::time::timeout(POLL_INTERVAL, intercepted.next())
+            .await
+            .ok()
+            .flatten();
+        if let Some(drag) = drag {
+            for kind in [
+                DispatchDragEventType::DragEnter,
+                DispatchDragEventType::DragOver,
+                DispatchDragEventType::Drop,
+            ] {
+                self.page
+                    .execute(DispatchDragEventParams::new(
+                        kind,
+                        to.x,
+                        to.y,
+                        drag.data.clone(),
+                    ))
+                    .await?;
+            }
+        }
+        self.mouse(
+            DispatchMouseEventType::MouseReleased,
+            to,
+            MouseButton::Left,
+            0,
+            1,
+        )
+        .await
+    }
+
+    /// Put `fingers` fingers down, move them through `GESTURE_STEPS` steps and lift
+    /// them; `at(finger, progress)` places each finger, with progress from 0 to 1
+    async fn gesture(
+        &self,
+        fingers: usize,
+        moves: bool,
+        at: impl Fn(usize, f64) -> Point + Send + Sync,
+    ) -> Result<()> {
+        let points = |progress: f64| {
+            (0..fingers)
+                .map(|finger| {
+                    let point = at(finger, progress);
+                    let mut touch = TouchPoint::new(point.x, point.y);
+                    touch.id = Some(finger as f64);
+                    touch
+                })
+                .collect::<Vec<_>>()
+        };
+        self.page
+            .execute(DispatchTouchEventParams::new(
+                DispatchTouchEventType::TouchStart,
+                points(0.0),
+            ))
+            .await?;
+        if moves {
+            for step in 1..=GESTURE_STEPS {
+                self.page
+                    .execute(DispatchTouchEventParams::new(
+                        DispatchTouchEventType::TouchMove,
+                        points(step as f64 / GESTURE_STEPS as f64),
+                    ))
+                    .await?;
+            }
+        }
+        self.page
+            .execute(DispatchTouchEventParams::new(
+                DispatchTouchEventType::TouchEnd,
+                Vec::new(),
+            ))
+            .await?;
+        Ok(())
+    }
+}
+
+/// Intermediate pointer positions sent while dragging, swiping and pinching
+const GESTURE_STEPS: usize = 10;
+
+/// Distance from the center of a pinch or rotation to each finger, in CSS pixels
+const FINGER_RADIUS: f64 = 40.0;
+
+fn lerp(from: Point, to: Point, progress: f64) -> Point {
+    Point {
+        x: from.x + (to.x - from.x) * progress,
+        y: from.y + (to.y - from.y) * progress,
+    }
+}
+
+#[async_trait]
+impl BrowserBackend for CdpBackend {
+    fn browser_type(&self) -> BrowserType {
+        BrowserType::Chromiumoxide
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn navigate_to(&self, url: &str) -> Result<()> {
+        self.page.goto(url).await?;
+        Ok(())
+    }
+
+    async fn page_html(&self) -> Result<String> {
+        let html = self
+            .page
+            .evaluate("document.documentElement.outerHTML")
+            .await?
+            .into_value()?;
+        Ok(html)
+    }
+
+    async fn new_context(&mut self) -> Result<()> {
+        self.browser.quit_incognito_context().await?;
+        self.browser.start_incognito_context().await?;
+        let page = self.browser.new_page("about:blank").await?;
+        self.emulate(&page).await?;
+        // The launch page lives in the default context, which is never disposed
+        let previous = std::mem::replace(&mut self.page, page);
+        let _ = previous.close().await;
+        Ok(())
+    }
+
+    async fn close(mut self: Box<Self>) -> Result<()> {
+        self.browser.close().await?;
+        self.browser.wait().await?;
+        if let Some(dir) = self.profile_dir.take() {
+            let _ = std::fs::remove_dir_all(dir);
+        }
+        Ok(())
+    }
+
+    async fn click(&self, selector: &str) -> Result<()> {
+        let point = self.center(selector).await?;
+        self.click_at(point, MouseButton::Left, 1).await
+    }
+
+    async fn type_text(&self, selector: &str, text: &str) -> Result<()> {
+        self.focus_and_select(selector).await?;
+        if text.is_empty() {
+            return self.press_key("Backspace").await;
+        }
+        for c in text.chars() {
+            self.press_key(c.encode_utf8(&mut [0; 4])).await?;
+        }
+        Ok(())
+    }
+
+    async fn clear_text(&self, selector: &str) -> Result<()> {
+        self.focus_and_select(selector).await?;
+        self.press_key("Backspace").await
+    }
+
+    async fn select_option(&self, selector: &str, value: &str) -> Result<()> {
+        let script = format!(
+            r#"(el => {{
+                el.value = {};
+                el.dispatchEvent(new Event('input', {{bubbles: true}}));
+                el.dispatchEvent(new Event('change', {{bubbles: true}}));
+            }})(document.querySelector({}))"#,
+            js_string(value),
+            js_string(selector)
+        );
+        self.page.evaluate(script.as_str()).await?;
+        Ok(())
+    }
+
+    async fn hover(&self, selector: &str) -> Result<()> {
+        let point = self.center(selector).await?;
+        self.mouse(
+            DispatchMouseEventType::MouseMoved,
+            point,
+            MouseButton::None,
+            0,
+            0,
+        )
+        .await
+    }
+
+    async fn right_click(&self, selector: &str) -> Result<()> {
+        let point = self.center(selector).await?;
+        self.click_at(point, MouseButton::Right, 1).await
+    }
+
+    async fn double_click(&self, selector: &str) -> Result<()> {
+        let point = self.center(selector).await?;
+        self.click_at(point, MouseButton::Left, 2).await
+    }
+
+    /// Keys on a US keyboard are pressed with their key code; other characters are
+    /// inserted as text, as an input method would
+    async fn press_key(&self, key: &str) -> Result<()> {
+        let key = if key == "Space" { " " } else { key };
+        let Some(definition) = keys::get_key_definition(key) else {
+            if key.chars().count() == 1 {
+                self.page.execute(InsertTextParams::new(key)).await?;
+                return Ok(());
+            }
+            return Err(WebSpecError::Automation(format!("Unknown key '{}'", key)));
+        };
+
+        // Keys that produce text send it with their keydown, like Puppeteer
+        let text = definition
+            .text
+            .or((definition.key.chars().count() == 1).then_some(definition.key));
+        let mut down = DispatchKeyEventParams::builder()
+            .r#type(if text.is_some() {
+                DispatchKeyEventType::KeyDown
+            } else {
+                DispatchKeyEventType::RawKeyDown
+            })
+            .key(definition.key)
+            .code(definition.code)
+            .windows_virtual_key_code(definition.key_code)
+            .native_virtual_key_code(definition.key_code);
+        if let Some(text) = text {
+            down = down.text(text);
+        }
+        self.page.execute(down.build()?).await?;
+        let up = DispatchKeyEventParams::builder()
+            .r#type(DispatchKeyEventType::KeyUp)
+            .key(definition.key)
+            .code(definition.code)
+            .windows_virtual_key_code(definition.key_code)
+            .native_virtual_key_code(definition.key_code)
+            .build()?;
+        self.page.execute(up).await?;
+        Ok(())
+    }
+
+    async fn drag_and_drop(&self, source: &str, target: &str) -> Result<()> {
+        let from = self.center(source).await?;
+        let to = self.center(target).await?;
+        self.drag(from, to).await
+    }
+
+    async fn drag_by(&self, selector: &str, x: i64, y: i64) -> Result<()> {
+        let from = self.center(selector).await?;
+        let to = Point {
+            x: from.x + x as f64,
+            y: from.y + y as f64,
+        };
+        self.drag(from, to).await
+    }
+
+    async fn tap(&self, selector: &str, fingers: u32) -> Result<()> {
+        let center = self.center(selector).await?;
+        let fingers = fingers.max(1) as usize;
+        // Fingers side by side, 20px apart, centered on the element
+        self.gesture(fingers, false, |finger, _| Point {
+            x: center.x + (finger as f64 - (fingers - 1) as f64 / 2.0) * 20.0,
+            y: center.y,
+        })
+        .await
+    }
+
+    async fn swipe(&self, from: &str, to: &str) -> Result<()> {
+        let start = self.center(from).await?;
+        let end = self.center(to).await?;
+        self.gesture(1, true, |_, progress| lerp(start, end, progress))
+            .await
+    }
+
+    async fn pinch(&self, selector: &str, scale: f64) -> Result<()> {
+        let center = self.center(selector).await?;
+        self.gesture(2, true, |finger, progress| {
+            let side = if finger == 0 { -1.0 } else { 1.0 };
+            let radius = FINGER_RADIUS * (1.0 + (scale - 1.0) * progress);
+            Point {
+                x: center.x + side * radius,
+                y: center.y,
+            }
+        })
+        .await
+    }
+
+    async fn rotate(&self, selector: &str, degrees: f64) -> Result<()> {
+        let center = self.center(selector).await?;
+        self.gesture(2, true, |finger, progress| {
+            let angle = (degrees * progress).to_radians() + std::f64::consts::PI * finger as f64;
+            Point {
+                x: center.x + FINGER_RADIUS * angle.cos(),
+                y: center.y + FINGER_RADIUS * angle.sin(),
+            }
+        })
+        .await
+    }
+
+    async fn wait_for_element(&self, selector: &str, timeout_ms: u64) -> Result<()> {
+        let condition = format!("document.querySelector({}) !== null", js_string(selector));
+        self.poll(selector, &condition, timeout_ms, "appear").await
+    }
+
+    async fn wait_for_element_visible(&self, selector: &str, timeout_ms: u64) -> Result<()> {
+        let condition = format!(
+            "({})(document.querySelector({}))",
+            IS_VISIBLE,
+            js_string(selector)
+        );
+        self.poll(selector, &condition, timeout_ms, "become visible")
+            .await
+    }
+
+    async fn element_exists(&self, selector: &str) -> Result<bool> {
+        let script = format!("!!document.querySelector({})", js_string(selector));
+        Ok(self.evaluate(&script).await?.as_bool().unwrap_or(false))
+    }
+
+    async fn element_visible(&self, selector: &str) -> Result<bool> {
+        let script = format!(
+            "({})(document.querySelector({}))",
+            IS_VISIBLE,
+            js_string(selector)
+        );
+        Ok(self.evaluate(&script).await?.as_bool().unwrap_or(false))
+    }
+
+    async fn scroll_to_bottom(&self) -> Result<()> {
+        self.page
+            .evaluate("window.scrollTo(0, document.body.scrollHeight)")
+            .await?;
+        Ok(())
+    }
+
+    async fn scroll_to_top(&self) -> Result<()> {
+        self.page.evaluate("window.scrollTo(0, 0)").await?;
+        Ok(())
+    }
+
+    async fn scroll_to_element(&self, selector: &str) -> Result<()> {
+        let script = format!(
+            "document.querySelector({}).scrollIntoView({{behavior: 'smooth', block: 'center'}})",
+            js_string(selector)
+        );
+        self.page.evaluate(script.as_str()).await?;
+        Ok(())
+    }
+
+    async fn scroll_by(&self, x: i64, y: i64) -> Result<()> {
+        self.page
+            .evaluate(format!("window.scrollBy({}, {})", x, y).as_str())
+            .await?;
+        Ok(())
+    }
+
+    async fn execute_script(&self, script: &str) -> Result<()> {
+        self.page.evaluate(script).await?;
+        Ok(())
+    }
+
+    async fn evaluate(&self, expression: &str) -> Result<serde_json::Value> {
+        Ok(self.page.evaluate(expression).await?.into_value()?)
+    }
+
+    async fn get_text(&self, selector: &str) -> Result<String> {
+        let script = format!(
+            "document.querySelector({}).textContent",
+            js_string(selector)
+        );
+        let value = self.evaluate(&script).await?;
+        if let Some(text) = value.as_str() {
+            Ok(text.trim().to_string())
+        } else {
+            Ok(value.to_string())
+        }
+    }
+
+    async fn get_attribute(&self, selector: &str, attribute: &str) -> Result<String> {
+        let script = format!(
+            "document.querySelector({}).getAttribute({})",
+            js_string(selector),
+            js_string(attribute)
+        );
+        let value = self.evaluate(&script).await?;
+        if let Some(attr) = value.as_str() {
+            Ok(attr.trim().to_string())
+        } else {
+            Err(WebSpecError::Automation(format!(
+                "Attribute '{}' not found",
+                attribute
+            )))
+        }
+    }
+
+    async fn get_html(&self, selector: &str) -> Result<String> {
+        let script = format!("document.querySelector({}).outerHTML", js_string(selector));
+        let value = self.evaluate(&script).await?;
+        if let Some(html_str) = value.as_str() {
+            Ok(html_str.to_string())
+        } else {
+            Err(WebSpecError::Automation(
+                "Failed to convert HTML to string".to_string(),
+            ))
+        }
+    }
+
+    async fn get_all_links(&self) -> Result<Vec<String>> {
+        self.evaluate_strings("Array.from(document.querySelectorAll('a[href]')).map(a => a.href)")
+            .await
+    }
+
+    async fn get_all_images(&self) -> Result<Vec<String>> {
+        self.evaluate_strings(
+            "Array.from(document.querySelectorAll('img[src]')).map(img => img.src)",
+        )
+        .await
+    }
+
+    async fn get_all_headings(&self, level: u32) -> Result<Vec<String>> {
+        let script = format!(
+            "Array.from(document.querySelectorAll('h{}')).map(h => h.textContent)",
+            level
+        );
+        let headings = self.evaluate_strings(&script).await?;
+        Ok(headings.iter().map(|h| h.trim().to_string()).collect())
+    }
+
+    async fn count_elements(&self, selector: &str) -> Result<usize> {
+        let script = format!("document.querySelectorAll({}).length", js_string(selector));
+        Ok(self.evaluate(&script).await?.as_u64().unwrap_or(0) as usize)
+    }
+
+    async fn screenshot(&self) -> Result<Vec<u8>> {
+        Ok(self
+            .page
+            .screenshot(ScreenshotParams::builder().build())
+            .await?)
+    }
+}
+
+impl Drop for CdpBackend {
+    fn drop(&mut self) {
+        if let Some(dir) = self.profile_dir.take() {
+            let _ = std::fs::remove_dir_all(dir);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    #[ignore]
+    async fn test_waits_poll_until_timeout() {
+        let backend = CdpBackend::launch(&BrowserConfig::default(), None)
+            .await
+            .unwrap();
+        backend
+            .execute_script(
+                r#"document.body.innerHTML = '<p id="late" style="opacity: 0">late</p>';
+                setTimeout(() => document.getElementById('late').style.opacity = '1', 300);"#,
+            )
+            .await
+            .unwrap();
+
+        assert!(backend.element_exists("#late").await.unwrap());
+        assert!(!backend.element_visible("#late").await.unwrap());
+        backend
+            .wait_for_element_visible("#late", 5000)
+            .await
+            .unwrap();
+
+        let err = backend.wait_for_element("#never", 300).await.unwrap_err();
+        assert!(matches!(&err, WebSpecError::Timeout(message) if message.contains("#never")));
+        assert!(backend.wait_for_element("p[", 5000).await.is_err());
+        Box::new(backend).close().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn test_actionability_names_failed_check() {
+        use crate::backend::Check;
+        use crate::backend::actionability::wait_until_actionable;
+
+        let backend = CdpBackend::launch(&BrowserConfig::default(), None)
+            .await
+            .unwrap();
+        backend
+            .execute_script(
+                r#"document.body.innerHTML = '<button id="go">Go</button><button id="off" disabled>Off</button>'
+                    + '<div class="overlay" style="position: fixed; inset: 0"></div>';"#,
+            )
+            .await
+            .unwrap();
+
+        let timeout = Duration::from_millis(300);
+        wait_until_actionable(&backend, "#go", Check::INPUT, timeout)
+            .await
+            .unwrap();
+        let err = wait_until_actionable(&backend, "#go", Check::POINTER, timeout)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            &err,
+            WebSpecError::NotActionable { check: Check::ReceivesEvents, detail, .. }
+                if detail.contains("div.overlay")
+        ));
+        let err = wait_until_actionable(&backend, "#off", Check::INPUT, timeout)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            WebSpecError::NotActionable {
+                check: Check::Enabled,
+                ..
+            }
+        ));
+        Box::new(backend).close().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn test_typing_sends_key_events() {
+        let backend = CdpBackend::launch(&BrowserConfig::default(), None)
+            .await
+            .unwrap();
+        backend
+            .execute_script(
+                r#"document.body.innerHTML = '<input id="name" value="old">';
+                window.inputs = 0;
+                document.getElementById('name').addEventListener('input', () => window.inputs++);"#,
+            )
+            .await
+            .unwrap();
+
+        backend.type_text("#name", "hé").await.unwrap();
+        let state = backend
+            .evaluate(
+                "[document.getElementById('name').value, window.inputs, document.activeElement.id]",
+            )
+            .await
+            .unwrap();
+        assert_eq!(state, serde_json::json!(["hé", 2, "name"]));
+        assert!(backend.press_key("NoSuchKey").await.is_err());
+        Box::new(backend).close().await.unwrap();
+    }
+}
@@ -0,0 +1,211 @@
+// Launch options shared by the browser backends, from `browser.yml` or CLI flags
+use super::DEFAULT_AUTO_WAIT_MS;
+use crate::browser::{BrowserType, DEFAULT_WEBDRIVER_URL};
+use crate::error::{Result, WebSpecError};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// How a browser is launched, whichever backend launches it
+///
+/// ```yaml
+/// backend: webdriver
+/// headless: false
+/// window_size: 1280x720
+/// locale: de-DE
+/// timezone: Europe/Berlin
+/// proxy: http://proxy.internal:3128
+/// args: [--disable-gpu]
+/// webdriver_url: http://localhost:9515
+/// auto_wait_ms: 10000
+/// capabilities:
+///   acceptInsecureCerts: true
+/// ```
+///
+/// Chromium-specific options (executable, window size, locale, proxy, args, user
+/// data dir) reach WebDriver through `goog:chromeOptions`; other browsers take
+/// theirs from `capabilities`, which are merged over the defaults.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BrowserConfig {
+    pub backend: BrowserType,
+    /// Browser binary to launch instead of the detected one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub executable: Option<PathBuf>,
+    pub headless: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub window_size: Option<WindowSize>,
+    /// BCP 47 language tag for `navigator.language` and `Accept-Language`, e.g. `de-DE`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    /// IANA time zone the page sees, e.g. `Europe/Berlin`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// Proxy server for all traffic, e.g. `http://proxy:3128` or `socks5://proxy:1080`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<String>,
+    /// Extra command-line arguments for the browser
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<String>,
+    /// Profile directory to use; isolated browsers always get their own temporary one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_data_dir: Option<PathBuf>,
+    pub webdriver_url: String,
+    /// How long actions wait for their element to become actionable; 0 checks once
+    pub auto_wait_ms: u64,
+    /// WebDriver capabilities merged over the Chrome defaults
+    #[serde(skip_serializing_if = "serde_json::Map::is_empty")]
+    pub capabilities: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Default for BrowserConfig {
+    fn default() -> Self {
+        BrowserConfig {
+            backend: BrowserType::default(),
+            executable: None,
+            headless: true,
+            window_size: None,
+            locale: None,
+            timezone: None,
+            proxy: None,
+            args: Vec::new(),
+            user_data_dir: None,
+            webdriver_url: DEFAULT_WEBDRIVER_URL.to_string(),
+            auto_wait_ms: DEFAULT_AUTO_WAIT_MS,
+            capabilities: serde_json::Map::new(),
+        }
+    }
+}
+
+impl BrowserConfig {
+    /// Default options for the given backend
+    pub fn new(backend: BrowserType) -> Self {
+        BrowserConfig {
+            backend,
+            ..Default::default()
+        }
+    }
+
+    /// Parse launch options from YAML
+    pub fn parse(content: &str) -> Result<Self> {
+        serde_yaml::from_str(content).map_err(|e| WebSpecError::Parse {
+            line: e.location().map_or(0, |l| l.line()),
+            message: e.to_string(),
+        })
+    }
+
+    /// Read and parse a launch options file such as `browser.yml`
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+}
+
+/// Browser window size in CSS pixels, written as `1280x720`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct WindowSize {
+    pub width: u32,
+    pub height: u32,
+}
+
+impl FromStr for WindowSize {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let invalid = || {
+            format!(
+                "Invalid window size '{}': expected WIDTHxHEIGHT, e.g. 1280x720",
+                s
+            )
+        };
+        let (width, height) = s.trim().split_once(['x', 'X']).ok_or_else(invalid)?;
+        let width: u32 = width.trim().parse().map_err(|_| invalid())?;
+        let height: u32 = height.trim().parse().map_err(|_| invalid())?;
+        if width == 0 || height == 0 {
+            return Err(invalid());
+        }
+        Ok(WindowSize { width, height })
+    }
+}
+
+impl TryFrom<String> for WindowSize {
+    type Error = String;
+
+    fn try_from(s: String) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<WindowSize> for String {
+    fn from(size: WindowSize) -> Self {
+        size.to_string()
+    }
+}
+
+impl fmt::Display for WindowSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}x{}", self.width, self.height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_browser_config() {
+        let config = BrowserConfig::parse(
+            r#"
+backend: webdriver
+headless: false
+window_size: 1280x720
+locale: de-DE
+timezone: Europe/Berlin
+args: [--disable-gpu]
+webdriver_url: http://localhost:9515
+auto_wait_ms: 250
+capabilities:
+  acceptInsecureCerts: true
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.backend, BrowserType::WebDriver);
+        assert!(!config.headless);
+        assert_eq!(
+            config.window_size,
+            Some(WindowSize {
+                width: 1280,
+                height: 720
+            })
+        );
+        assert_eq!(config.timezone.as_deref(), Some("Europe/Berlin"));
+        assert_eq!(config.args, vec!["--disable-gpu".to_string()]);
+        assert_eq!(config.webdriver_url, "http://localhost:9515");
+        assert_eq!(config.auto_wait_ms, 250);
+        assert_eq!(config.capabilities["acceptInsecureCerts"], true);
+        assert_eq!(config.proxy, None);
+
+        assert_eq!(BrowserConfig::parse("").unwrap(), BrowserConfig::default());
+        assert!(BrowserConfig::parse("window_size: big").is_err());
+        assert!(BrowserConfig::parse("headles: true").is_err());
+    }
+
+    #[test]
+    fn test_parse_window_size() {
+        assert_eq!(
+            "1920x1080".parse(),
+            Ok(WindowSize {
+                width: 1920,
+                height: 1080
+            })
+        );
+        assert!("1920".parse::<WindowSize>().is_err());
+        assert!("0x600".parse::<WindowSize>().is_err());
+        assert_eq!(
+            "800X600".parse::<WindowSize>().unwrap().to_string(),
+            "800x600"
+        );
+    }
+}
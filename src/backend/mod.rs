@@ -0,0 +1,158 @@
+// Browser backends: the protocol-specific half of `Browser` and `Automation`
+pub mod actionability;
+#[cfg(feature = "chromiumoxide-backend")]
+pub mod cdp;
+pub mod config;
+#[cfg(test)]
+pub(crate) mod no_page;
+pub mod webdriver;
+
+pub use actionability::{Check, DEFAULT_AUTO_WAIT_MS};
+#[cfg(feature = "chromiumoxide-backend")]
+pub use cdp::CdpBackend;
+pub use config::{BrowserConfig, WindowSize};
+pub use webdriver::WebDriverBackend;
+
+use crate::browser::BrowserType;
+use crate::error::{Result, WebSpecError};
+use async_trait::async_trait;
+use std::any::Any;
+use std::time::Duration;
+
+/// How often waits re-check the page until their condition holds or they time out
+pub const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// A driven browser: navigation, contexts and the element operations steps rely on
+///
+/// Selectors are CSS selectors. [`Browser`](crate::Browser) holds one backend,
+/// chosen by [`BrowserType`] when it is launched, and
+/// [`Automation`](crate::Automation) forwards to it, so the same code drives
+/// Chromium over CDP or any browser behind a WebDriver server.
+#[async_trait]
+pub trait BrowserBackend: Send + Sync {
+    fn browser_type(&self) -> BrowserType;
+
+    /// The concrete backend, for reaching protocol-specific handles
+    fn as_any(&self) -> &dyn Any;
+
+    async fn navigate_to(&self, url: &str) -> Result<()>;
+
+    /// Outer HTML of the whole document
+    async fn page_html(&self) -> Result<String>;
+
+    /// Continue with fresh cookies and storage, dropping the current page
+    async fn new_context(&mut self) -> Result<()>;
+
+    /// Shut the browser or session down and release what it holds
+    async fn close(self: Box<Self>) -> Result<()>;
+
+    async fn click(&self, selector: &str) -> Result<()>;
+
+    async fn type_text(&self, selector: &str, text: &str) -> Result<()>;
+
+    async fn clear_text(&self, selector: &str) -> Result<()>;
+
+    async fn select_option(&self, selector: &str, value: &str) -> Result<()>;
+
+    async fn hover(&self, selector: &str) -> Result<()>;
+
+    async fn right_click(&self, selector: &str) -> Result<()>;
+
+    async fn double_click(&self, selector: &str) -> Result<()>;
+
+    /// Press and release `key` in the focused element: a key name such as `Enter`
+    /// or `ArrowDown`, or a single character
+    async fn press_key(&self, key: &str) -> Result<()>;
+
+    /// Drag the element matching `source` with the mouse and drop it on `target`
+    async fn drag_and_drop(&self, source: &str, target: &str) -> Result<()>;
+
+    /// Drag the element matching `selector` by `x` and `y` CSS pixels
+    async fn drag_by(&self, selector: &str, x: i64, y: i64) -> Result<()>;
+
+    /// Touch the element with `fingers` fingers at once and lift them
+    async fn tap(&self, selector: &str, fingers: u32) -> Result<()>;
+
+    /// Slide one finger from the element matching `from` to the one matching `to`
+    async fn swipe(&self, from: &str, to: &str) -> Result<()>;
+
+    /// Move two fingers on the element apart (`scale` above 1) or together
+    async fn pinch(&self, selector: &str, scale: f64) -> Result<()>;
+
+    /// Turn two fingers on the element clockwise by `degrees`
+    async fn rotate(&self, selector: &str, degrees: f64) -> Result<()>;
+
+    /// Wait until an element matches `selector`, or fail with
+    /// [`WebSpecError::Timeout`](crate::WebSpecError::Timeout) after `timeout_ms`
+    async fn wait_for_element(&self, selector: &str, timeout_ms: u64) -> Result<()>;
+
+    /// Like [`wait_for_element`](Self::wait_for_element), but the element must also
+    /// be visible
+    async fn wait_for_element_visible(&self, selector: &str, timeout_ms: u64) -> Result<()>;
+
+    async fn element_exists(&self, selector: &str) -> Result<bool>;
+
+    /// Whether the first match has a layout box and is not hidden by `display`,
+    /// `visibility` or zero opacity
+    async fn element_visible(&self, selector: &str) -> Result<bool>;
+
+    async fn scroll_to_bottom(&self) -> Result<()>;
+
+    async fn scroll_to_top(&self) -> Result<()>;
+
+    async fn scroll_to_element(&self, selector: &str) -> Result<()>;
+
+    async fn scroll_by(&self, x: i64, y: i64) -> Result<()>;
+
+    async fn execute_script(&self, script: &str) -> Result<()>;
+
+    /// Value of a JavaScript expression, after awaiting it if it is a promise
+    async fn evaluate(&self, expression: &str) -> Result<serde_json::Value>;
+
+    async fn get_text(&self, selector: &str) -> Result<String>;
+
+    async fn get_attribute(&self, selector: &str, attribute: &str) -> Result<String>;
+
+    /// Outer HTML of the first element matching `selector`
+    async fn get_html(&self, selector: &str) -> Result<String>;
+
+    async fn get_all_links(&self) -> Result<Vec<String>>;
+
+    async fn get_all_images(&self) -> Result<Vec<String>>;
+
+    async fn get_all_headings(&self, level: u32) -> Result<Vec<String>>;
+
+    async fn count_elements(&self, selector: &str) -> Result<usize>;
+
+    /// PNG screenshot of the viewport
+    async fn screenshot(&self) -> Result<Vec<u8>>;
+}
+
+/// `s` as a JavaScript string literal
+pub(crate) fn js_string(s: &str) -> String {
+    serde_json::Value::from(s).to_string()
+}
+
+/// Fail on a selector no page can match, so waits do not poll it until they time out
+pub(crate) async fn check_selector(backend: &dyn BrowserBackend, selector: &str) -> Result<()> {
+    let script = format!(
+        "document.createDocumentFragment().querySelector({}) === null",
+        js_string(selector)
+    );
+    backend
+        .evaluate(&script)
+        .await
+        .map_err(|e| WebSpecError::Automation(format!("Invalid selector '{}': {}", selector, e)))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_js_string_escapes_selector() {
+        assert_eq!(js_string("a[title='x']"), r#""a[title='x']""#);
+        assert_eq!(js_string(r#"input[name="q"]"#), r#""input[name=\"q\"]""#);
+    }
+}
@@ -0,0 +1,130 @@
+// Test backend without a page
+use super::BrowserBackend;
+use crate::browser::BrowserType;
+use crate::error::{Result, WebSpecError};
+use async_trait::async_trait;
+
+/// Backend whose every page operation fails at once, so steps fail without a browser
+pub(crate) struct NoPage;
+
+fn no_page<T>() -> Result<T> {
+    Err(WebSpecError::Browser("no page in tests".to_string()))
+}
+
+#[async_trait]
+impl BrowserBackend for NoPage {
+    fn browser_type(&self) -> BrowserType {
+        BrowserType::WebDriver
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+    async fn navigate_to(&self, _: &str) -> Result<()> {
+        no_page()
+    }
+    async fn page_html(&self) -> Result<String> {
+        no_page()
+    }
+    async fn new_context(&mut self) -> Result<()> {
+        Ok(())
+    }
+    async fn close(self: Box<Self>) -> Result<()> {
+        Ok(())
+    }
+    async fn click(&self, _: &str) -> Result<()> {
+        no_page()
+    }
+    async fn type_text(&self, _: &str, _: &str) -> Result<()> {
+        no_page()
+    }
+    async fn clear_text(&self, _: &str) -> Result<()> {
+        no_page()
+    }
+    async fn select_option(&self, _: &str, _: &str) -> Result<()> {
+        no_page()
+    }
+    async fn hover(&self, _: &str) -> Result<()> {
+        no_page()
+    }
+    async fn right_click(&self, _: &str) -> Result<()> {
+        no_page()
+    }
+    async fn double_click(&self, _: &str) -> Result<()> {
+        no_page()
+    }
+    async fn press_key(&self, _: &str) -> Result<()> {
+        no_page()
+    }
+    async fn drag_and_drop(&self, _: &str, _: &str) -> Result<()> {
+        no_page()
+    }
+    async fn drag_by(&self, _: &str, _: i64, _: i64) -> Result<()> {
+        no_page()
+    }
+    async fn tap(&self, _: &str, _: u32) -> Result<()> {
+        no_page()
+    }
+    async fn swipe(&self, _: &str, _: &str) -> Result<()> {
+        no_page()
+    }
+    async fn pinch(&self, _: &str, _: f64) -> Result<()> {
+        no_page()
+    }
+    async fn rotate(&self, _: &str, _: f64) -> Result<()> {
+        no_page()
+    }
+    async fn wait_for_element(&self, _: &str, _: u64) -> Result<()> {
+        no_page()
+    }
+    async fn wait_for_element_visible(&self, _: &str, _: u64) -> Result<()> {
+        no_page()
+    }
+    async fn element_exists(&self, _: &str) -> Result<bool> {
+        no_page()
+    }
+    async fn element_visible(&self, _: &str) -> Result<bool> {
+        no_page()
+    }
+    async fn scroll_to_bottom(&self) -> Result<()> {
+        no_page()
+    }
+    async fn scroll_to_top(&self) -> Result<()> {
+        no_page()
+    }
+    async fn scroll_to_element(&self, _: &str) -> Result<()> {
+        no_page()
+    }
+    async fn scroll_by(&self, _: i64, _: i64) -> Result<()> {
+        no_page()
+    }
+    async fn execute_script(&self, _: &str) -> Result<()> {
+        no_page()
+    }
+    async fn evaluate(&self, _: &str) -> Result<serde_json::Value> {
+        no_page()
+    }
+    async fn get_text(&self, _: &str) -> Result<String> {
+        no_page()
+    }
+    async fn get_attribute(&self, _: &str, _: &str) -> Result<String> {
+        no_page()
+    }
+    async fn get_html(&self, _: &str) -> Result<String> {
+        no_page()
+    }
+    async fn get_all_links(&self) -> Result<Vec<String>> {
+        no_page()
+    }
+    async fn get_all_images(&self) -> Result<Vec<String>> {
+        no_page()
+    }
+    async fn get_all_headings(&self, _: u32) -> Result<Vec<String>> {
+        no_page()
+    }
+    async fn count_elements(&self, _: &str) -> Result<usize> {
+        no_page()
+    }
+    async fn screenshot(&self) -> Result<Vec<u8>> {
+        no_page()
+    }
+}
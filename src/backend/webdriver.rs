@@ -0,0 +1,520 @@
+// Any browser behind a WebDriver server, driven with thirtyfour
+use super::{BrowserBackend, BrowserConfig, POLL_INTERVAL};
+use crate::browser::BrowserType;
+use crate::error::{Result, WebSpecError};
+use async_trait::async_trait;
+use serde_json::{Value, json};
+use std::any::Any;
+use std::time::Duration;
+use thirtyfour::extensions::cdp::ChromeDevTools;
+use thirtyfour::prelude::*;
+
+pub struct WebDriverBackend {
+    /// Taken when the session is closed, so dropping does not quit it twice
+    driver: Option<WebDriver>,
+}
+
+impl WebDriverBackend {
+    /// Start a session on `config.webdriver_url` with capabilities built from `config`
+    ///
+    /// The time zone is set through Chrome's DevTools, so it needs a Chromium-based
+    /// browser.
+    pub async fn launch(config: &BrowserConfig) -> Result<Self> {
+        let backend = Self::connect(&config.webdriver_url, capabilities(config)).await?;
+        if let Some(timezone) = &config.timezone {
+            ChromeDevTools::new(backend.driver()?.handle.clone())
+                .execute_cdp_with_params(
+                    "Emulation.setTimezoneOverride",
+                    json!({ "timezoneId": timezone }),
+                )
+                .await?;
+        }
+        Ok(backend)
+    }
+
+    /// Start a session on the WebDriver server at `url`
+    pub async fn connect(url: &str, capabilities: impl Into<Capabilities>) -> Result<Self> {
+        let driver = WebDriver::new(url, capabilities).await?;
+        Ok(Self {
+            driver: Some(driver),
+        })
+    }
+
+    pub fn driver(&self) -> Result<&WebDriver> {
+        self.driver
+            .as_ref()
+            .ok_or_else(|| WebSpecError::Browser("WebDriver session is closed".to_string()))
+    }
+
+    async fn find(&self, selector: &str) -> Result<WebElement> {
+        Ok(self.driver()?.find(By::Css(selector)).await?)
+    }
+
+    /// Run a script with the first element matching `selector` as `arguments[0]`
+    async fn execute_on(&self, selector: &str, script: &str) -> Result<ScriptRet> {
+        let element = self.find(selector).await?;
+        Ok(self
+            .driver()?
+            .execute(script, vec![serde_json::to_value(&element)?])
+            .await?)
+    }
+
+    async fn attributes(&self, selector: &str, attribute: &str) -> Result<Vec<String>> {
+        let elements = self.driver()?.find_all(By::Css(selector)).await?;
+        let mut values = Vec::new();
+        for element in elements {
+            if let Some(value) = element.attr(attribute).await? {
+                values.push(value);
+            }
+        }
+        Ok(values)
+    }
+}
+
+/// Chrome capabilities for `config`, with its own capabilities merged on top
+fn capabilities(config: &BrowserConfig) -> Capabilities {
+    let mut args = Vec::new();
+    if config.headless {
+        args.push("--headless=new".to_string());
+    }
+    if let Some(size) = config.window_size {
+        args.push(format!("--window-size={},{}", size.width, size.height));
+    }
+    if let Some(locale) = &config.locale {
+        args.push(format!("--lang={}", locale));
+    }
+    if let Some(proxy) = &config.proxy {
+        args.push(format!("--proxy-server={}", proxy));
+    }
+    if let Some(dir) = &config.user_data_dir {
+        args.push(format!("--user-data-dir={}", dir.display()));
+    }
+    args.extend(config.args.iter().cloned());
+
+    let mut chrome_options = json!({ "args": args });
+    if let Some(executable) = &config.executable {
+        chrome_options["binary"] = json!(executable);
+    }
+    if let Some(locale) = &config.locale {
+        chrome_options["prefs"] = json!({ "intl.accept_languages": locale });
+    }
+
+    let mut caps: Capabilities = DesiredCapabilities::chrome().into();
+    merge(
+        &mut caps,
+        json!({ "goog:chromeOptions": chrome_options })
+            .as_object()
+            .cloned()
+            .unwrap_or_default(),
+    );
+    merge(&mut caps, config.capabilities.clone());
+    caps
+}
+
+/// The character WebDriver sends for a key name such as `Enter`, or a single character
+fn webdriver_key(key: &str) -> Option<char> {
+    let mut chars = key.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(c);
+    }
+    let key = match key {
+        "Enter" => Key::Enter,
+        "Tab" => Key::Tab,
+        "Escape" => Key::Escape,
+        "Backspace" => Key::Backspace,
+        "Delete" => Key::Delete,
+        "Insert" => Key::Insert,
+        "Space" => Key::Space,
+        "ArrowUp" => Key::Up,
+        "ArrowDown" => Key::Down,
+        "ArrowLeft" => Key::Left,
+        "ArrowRight" => Key::Right,
+        "Home" => Key::Home,
+        "End" => Key::End,
+        "PageUp" => Key::PageUp,
+        "PageDown" => Key::PageDown,
+        "Shift" => Key::Shift,
+        "Control" => Key::Control,
+        "Alt" => Key::Alt,
+        "Meta" => Key::Meta,
+        "F1" => Key::F1,
+        "F2" => Key::F2,
+        "F3" => Key::F3,
+        "F4" => Key::F4,
+        "F5" => Key::F5,
+        "F6" => Key::F6,
+        "F7" => Key::F7,
+        "F8" => Key::F8,
+        "F9" => Key::F9,
+        "F10" => Key::F10,
+        "F11" => Key::F11,
+        "F12" => Key::F12,
+        _ => return None,
+    };
+    Some(key.value())
+}
+
+/// WebDriver's actions have no touch input that thirtyfour can send
+fn touch_unsupported() -> WebSpecError {
+    WebSpecError::Automation(
+        "Touch gestures need the chromiumoxide backend; WebDriver cannot send them".to_string(),
+    )
+}
+
+/// Merge `overlay` into `base`: objects key by key, arrays appended, anything else replaced
+fn merge(base: &mut serde_json::Map<String, Value>, overlay: serde_json::Map<String, Value>) {
+    for (key, value) in overlay {
+        match (base.get_mut(&key), value) {
+            (Some(Value::Object(existing)), Value::Object(value)) => merge(existing, value),
+            (Some(Value::Array(existing)), Value::Array(value)) => existing.extend(value),
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl BrowserBackend for WebDriverBackend {
+    fn browser_type(&self) -> BrowserType {
+        BrowserType::WebDriver
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn navigate_to(&self, url: &str) -> Result<()> {
+        self.driver()?.goto(url).await?;
+        Ok(())
+    }
+
+    async fn page_html(&self) -> Result<String> {
+        let result = self
+            .driver()?
+            .execute("return document.documentElement.outerHTML;", Vec::new())
+            .await?;
+        let html = result
+            .json()
+            .as_str()
+            .ok_or_else(|| WebSpecError::Browser("Failed to get HTML".to_string()))?
+            .to_string();
+        Ok(html)
+    }
+
+    /// WebDriver sessions have no contexts, so only the cookies are deleted
+    async fn new_context(&mut self) -> Result<()> {
+        self.driver()?.delete_all_cookies().await?;
+        Ok(())
+    }
+
+    async fn close(mut self: Box<Self>) -> Result<()> {
+        if let Some(driver) = self.driver.take() {
+            driver.quit().await?;
+        }
+        Ok(())
+    }
+
+    async fn click(&self, selector: &str) -> Result<()> {
+        self.find(selector).await?.click().await?;
+        Ok(())
+    }
+
+    /// Replaces the field's value, like the CDP backend
+    async fn type_text(&self, selector: &str, text: &str) -> Result<()> {
+        let element = self.find(selector).await?;
+        element.clear().await?;
+        element.send_keys(text).await?;
+        Ok(())
+    }
+
+    async fn clear_text(&self, selector: &str) -> Result<()> {
+        self.find(selector).await?.clear().await?;
+        Ok(())
+    }
+
+    async fn select_option(&self, selector: &str, value: &str) -> Result<()> {
+        self.find(selector).await?.send_keys(value).await?;
+        Ok(())
+    }
+
+    async fn hover(&self, selector: &str) -> Result<()> {
+        let element = self.find(selector).await?;
+        self.driver()?
+            .action_chain()
+            .move_to_element_center(&element)
+            .perform()
+            .await?;
+        Ok(())
+    }
+
+    async fn right_click(&self, selector: &str) -> Result<()> {
+        let element = self.find(selector).await?;
+        self.driver()?
+            .action_chain()
+            .context_click_element(&element)
+            .perform()
+            .await?;
+        Ok(())
+    }
+
+    async fn double_click(&self, selector: &str) -> Result<()> {
+        let element = self.find(selector).await?;
+        self.driver()?
+            .action_chain()
+            .double_click_element(&element)
+            .perform()
+            .await?;
+        Ok(())
+    }
+
+    async fn press_key(&self, key: &str) -> Result<()> {
+        let key = webdriver_key(key)
+            .ok_or_else(|| WebSpecError::Automation(format!("Unknown key '{}'", key)))?;
+        self.driver()?
+            .action_chain()
+            .send_keys(key.to_string())
+            .perform()
+            .await?;
+        Ok(())
+    }
+
+    async fn drag_and_drop(&self, source: &str, target: &str) -> Result<()> {
+        let source = self.find(source).await?;
+        let target = self.find(target).await?;
+        self.driver()?
+            .action_chain()
+            .drag_and_drop_element(&source, &target)
+            .perform()
+            .await?;
+        Ok(())
+    }
+
+    async fn drag_by(&self, selector: &str, x: i64, y: i64) -> Result<()> {
+        let element = self.find(selector).await?;
+        self.driver()?
+            .action_chain()
+            .drag_and_drop_element_by_offset(&element, x, y)
+            .perform()
+            .await?;
+        Ok(())
+    }
+
+    async fn tap(&self, _selector: &str, _fingers: u32) -> Result<()> {
+        Err(touch_unsupported())
+    }
+
+    async fn swipe(&self, _from: &str, _to: &str) -> Result<()> {
+        Err(touch_unsupported())
+    }
+
+    async fn pinch(&self, _selector: &str, _scale: f64) -> Result<()> {
+        Err(touch_unsupported())
+    }
+
+    async fn rotate(&self, _selector: &str, _degrees: f64) -> Result<()> {
+        Err(touch_unsupported())
+    }
+
+    async fn wait_for_element(&self, selector: &str, timeout_ms: u64) -> Result<()> {
+        self.driver()?
+            .query(By::Css(selector))
+            .wait(Duration::from_millis(timeout_ms), POLL_INTERVAL)
+            .first()
+            .await
+            .map_err(|_| {
+                WebSpecError::Timeout(format!(
+                    "'{}' did not appear within {} ms",
+                    selector, timeout_ms
+                ))
+            })?;
+        Ok(())
+    }
+
+    async fn wait_for_element_visible(&self, selector: &str, timeout_ms: u64) -> Result<()> {
+        self.driver()?
+            .query(By::Css(selector))
+            .and_displayed()
+            .wait(Duration::from_millis(timeout_ms), POLL_INTERVAL)
+            .first()
+            .await
+            .map_err(|_| {
+                WebSpecError::Timeout(format!(
+                    "'{}' did not become visible within {} ms",
+                    selector, timeout_ms
+                ))
+            })?;
+        Ok(())
+    }
+
+    async fn element_exists(&self, selector: &str) -> Result<bool> {
+        Ok(self
+            .driver()?
+            .query(By::Css(selector))
+            .first()
+            .await
+            .is_ok())
+    }
+
+    async fn element_visible(&self, selector: &str) -> Result<bool> {
+        match self.find(selector).await {
+            Ok(element) => Ok(element.is_displayed().await.unwrap_or(false)),
+            Err(_) => Ok(false),
+        }
+    }
+
+    async fn scroll_to_bottom(&self) -> Result<()> {
+        self.execute_script("window.scrollTo(0, document.body.scrollHeight);")
+            .await
+    }
+
+    async fn scroll_to_top(&self) -> Result<()> {
+        self.execute_script("window.scrollTo(0, 0);").await
+    }
+
+    async fn scroll_to_element(&self, selector: &str) -> Result<()> {
+        self.execute_on(
+            selector,
+            "arguments[0].scrollIntoView({behavior: 'smooth', block: 'center'});",
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn scroll_by(&self, x: i64, y: i64) -> Result<()> {
+        self.execute_script(&format!("window.scrollBy({}, {});", x, y))
+            .await
+    }
+
+    async fn execute_script(&self, script: &str) -> Result<()> {
+        self.driver()?.execute(script, vec![]).await?;
+        Ok(())
+    }
+
+    async fn evaluate(&self, expression: &str) -> Result<Value> {
+        let script = format!("return ({});", expression);
+        Ok(self
+            .driver()?
+            .execute(&script, Vec::new())
+            .await?
+            .json()
+            .clone())
+    }
+
+    async fn get_text(&self, selector: &str) -> Result<String> {
+        Ok(self.find(selector).await?.text().await?)
+    }
+
+    async fn get_attribute(&self, selector: &str, attribute: &str) -> Result<String> {
+        let element = self.find(selector).await?;
+        let attr = element.attr(attribute).await?.ok_or_else(|| {
+            WebSpecError::Automation(format!("Attribute '{}' not found", attribute))
+        })?;
+        Ok(attr)
+    }
+
+    async fn get_html(&self, selector: &str) -> Result<String> {
+        let html = self
+            .execute_on(selector, "return arguments[0].outerHTML;")
+            .await?;
+        if let Some(html_str) = html.json().as_str() {
+            Ok(html_str.to_string())
+        } else {
+            Err(WebSpecError::Automation(
+                "Failed to convert HTML to string".to_string(),
+            ))
+        }
+    }
+
+    async fn get_all_links(&self) -> Result<Vec<String>> {
+        self.attributes("a[href]", "href").await
+    }
+
+    async fn get_all_images(&self) -> Result<Vec<String>> {
+        self.attributes("img[src]", "src").await
+    }
+
+    async fn get_all_headings(&self, level: u32) -> Result<Vec<String>> {
+        let selector = format!("h{}", level);
+        let elements = self.driver()?.find_all(By::Css(&selector)).await?;
+        let mut headings = Vec::new();
+        for element in elements {
+            headings.push(element.text().await?);
+        }
+        Ok(headings)
+    }
+
+    async fn count_elements(&self, selector: &str) -> Result<usize> {
+        Ok(self.driver()?.find_all(By::Css(selector)).await?.len())
+    }
+
+    async fn screenshot(&self) -> Result<Vec<u8>> {
+        Ok(self.driver()?.screenshot_as_png().await?)
+    }
+}
+
+impl Drop for WebDriverBackend {
+    fn drop(&mut self) {
+        if let Some(driver) = self.driver.take() {
+            tokio::spawn(async move {
+                let _ = driver.quit().await;
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::WindowSize;
+
+    #[test]
+    fn test_webdriver_key() {
+        assert_eq!(webdriver_key("a"), Some('a'));
+        assert_eq!(webdriver_key("Enter"), Some(Key::Enter.value()));
+        assert_eq!(webdriver_key("ArrowDown"), Some(Key::Down.value()));
+        assert_eq!(webdriver_key("Hyper"), None);
+    }
+
+    #[test]
+    fn test_capabilities_from_config() {
+        let mut config = BrowserConfig::new(BrowserType::WebDriver);
+        config.window_size = Some(WindowSize {
+            width: 1280,
+            height: 720,
+        });
+        config.proxy = Some("http://proxy:3128".to_string());
+        config.args = vec!["--disable-gpu".to_string()];
+        config.capabilities = json!({
+            "acceptInsecureCerts": true,
+            "goog:chromeOptions": { "args": ["--mute-audio"] },
+        })
+        .as_object()
+        .cloned()
+        .unwrap();
+
+        let caps = capabilities(&config);
+        assert_eq!(caps["browserName"], "chrome");
+        assert_eq!(caps["acceptInsecureCerts"], true);
+        assert_eq!(
+            caps["goog:chromeOptions"]["args"],
+            json!([
+                "--headless=new",
+                "--window-size=1280,720",
+                "--proxy-server=http://proxy:3128",
+                "--disable-gpu",
+                "--mute-audio",
+            ])
+        );
+
+        config.headless = false;
+        config.args.clear();
+        config.capabilities.clear();
+        config.proxy = None;
+        config.window_size = None;
+        assert_eq!(
+            capabilities(&config)["goog:chromeOptions"]["args"],
+            json!([])
+        );
+    }
+}
@@ -1,242 +1,251 @@
-use crate::error::{Result, WebSpecError};
+use crate::backend::{BrowserBackend, BrowserConfig, DEFAULT_AUTO_WAIT_MS, WebDriverBackend};
+use crate::error::Result;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
 use thirtyfour::prelude::*;
 
 #[cfg(feature = "chromiumoxide-backend")]
-use chromiumoxide::browser::HeadlessMode;
+use crate::backend::{CdpBackend, WindowSize};
 #[cfg(feature = "chromiumoxide-backend")]
-use chromiumoxide::{Browser as ChromiumBrowser, BrowserConfig, Page};
-#[cfg(feature = "chromiumoxide-backend")]
-use futures_util::StreamExt;
+use chromiumoxide::{Browser as ChromiumBrowser, Page};
+
+/// WebDriver server that `BrowserType::WebDriver` connects to
+pub const DEFAULT_WEBDRIVER_URL: &str = "http://localhost:4444";
 
-#[derive(Debug, Clone)]
+/// Backend a [`Browser`] is launched with, chosen at runtime
+///
+/// Parsed from `webdriver` or `chromiumoxide` (also `cdp`); defaults to
+/// chromiumoxide when it is built in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum BrowserType {
     WebDriver,
     #[cfg(feature = "chromiumoxide-backend")]
+    #[serde(alias = "cdp")]
     Chromiumoxide,
 }
 
+impl Default for BrowserType {
+    fn default() -> Self {
+        #[cfg(feature = "chromiumoxide-backend")]
+        return BrowserType::Chromiumoxide;
+        #[cfg(not(feature = "chromiumoxide-backend"))]
+        return BrowserType::WebDriver;
+    }
+}
+
+impl FromStr for BrowserType {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "webdriver" => Ok(BrowserType::WebDriver),
+            #[cfg(feature = "chromiumoxide-backend")]
+            "chromiumoxide" | "cdp" => Ok(BrowserType::Chromiumoxide),
+            #[cfg(not(feature = "chromiumoxide-backend"))]
+            "chromiumoxide" | "cdp" => Err(
+                "The chromiumoxide backend is not built in; rebuild with --features chromiumoxide-backend"
+                    .to_string(),
+            ),
+            other => Err(format!(
+                "Unknown browser backend '{}': expected chromiumoxide or webdriver",
+                other
+            )),
+        }
+    }
+}
+
+impl fmt::Display for BrowserType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BrowserType::WebDriver => write!(f, "webdriver"),
+            #[cfg(feature = "chromiumoxide-backend")]
+            BrowserType::Chromiumoxide => write!(f, "chromiumoxide"),
+        }
+    }
+}
+
+/// A launched browser, driven through whichever [`BrowserBackend`] it was created with
 pub struct Browser {
-    _browser_type: BrowserType,
-    driver: Option<WebDriver>,
-    #[cfg(feature = "chromiumoxide-backend")]
-    chromium: Option<ChromiumBrowser>,
-    #[cfg(feature = "chromiumoxide-backend")]
-    chromium_page: Option<Page>,
-    #[cfg(feature = "chromiumoxide-backend")]
-    #[allow(dead_code)]
-    handler_task: Option<tokio::task::JoinHandle<()>>,
+    backend: Box<dyn BrowserBackend>,
+    auto_wait: Duration,
 }
 
 impl Browser {
+    /// Launch a browser with the given backend and default options
+    ///
+    /// WebDriver connects to Chrome behind [`DEFAULT_WEBDRIVER_URL`]; chromiumoxide
+    /// launches a headless Chromium.
     pub async fn new(browser_type: BrowserType) -> Result<Self> {
-        let driver = match browser_type {
-            BrowserType::WebDriver => {
-                let caps = DesiredCapabilities::chrome();
-                let driver = WebDriver::new("http://localhost:4444", caps).await?;
-                Some(driver)
-            }
+        Self::launch(&BrowserConfig::new(browser_type)).await
+    }
+
+    /// Launch a browser as `config` describes, with the backend it names
+    pub async fn launch(config: &BrowserConfig) -> Result<Self> {
+        let mut browser = match config.backend {
+            BrowserType::WebDriver => Self::from_backend(WebDriverBackend::launch(config).await?),
             #[cfg(feature = "chromiumoxide-backend")]
             BrowserType::Chromiumoxide => {
-                return Err(WebSpecError::Browser(
-                    "Chromiumoxide backend needs to be initialized with Browser::new_chromiumoxide()".to_string(),
-                ));
+                Self::from_backend(CdpBackend::launch(config, None).await?)
             }
         };
+        browser.set_auto_wait(Duration::from_millis(config.auto_wait_ms));
+        Ok(browser)
+    }
 
-        Ok(Self {
-            _browser_type: browser_type,
-            driver,
-            #[cfg(feature = "chromiumoxide-backend")]
-            chromium: None,
-            #[cfg(feature = "chromiumoxide-backend")]
-            chromium_page: None,
+    /// Launch a browser that shares no cookies or storage with others, so several
+    /// can run at once
+    pub async fn new_isolated(browser_type: BrowserType) -> Result<Self> {
+        Self::launch_isolated(&BrowserConfig::new(browser_type)).await
+    }
+
+    /// Like [`Browser::launch`], but isolated from other browsers
+    ///
+    /// Every WebDriver session is already isolated; chromiumoxide gets its own
+    /// temporary profile in place of `user_data_dir`.
+    pub async fn launch_isolated(config: &BrowserConfig) -> Result<Self> {
+        match config.backend {
+            BrowserType::WebDriver => Self::launch(config).await,
             #[cfg(feature = "chromiumoxide-backend")]
-            handler_task: None,
-        })
+            BrowserType::Chromiumoxide => {
+                static NEXT_PROFILE: std::sync::atomic::AtomicUsize =
+                    std::sync::atomic::AtomicUsize::new(0);
+                let profile_dir = std::env::temp_dir().join(format!(
+                    "web-spec-profile-{}-{}",
+                    std::process::id(),
+                    NEXT_PROFILE.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+                ));
+                let mut browser =
+                    Self::from_backend(CdpBackend::launch(config, Some(profile_dir)).await?);
+                browser.set_auto_wait(Duration::from_millis(config.auto_wait_ms));
+                Ok(browser)
+            }
+        }
+    }
+
+    /// Browser driven by a custom backend
+    pub fn from_backend(backend: impl BrowserBackend + 'static) -> Self {
+        Self {
+            backend: Box::new(backend),
+            auto_wait: Duration::from_millis(DEFAULT_AUTO_WAIT_MS),
+        }
     }
 
     #[cfg(feature = "chromiumoxide-backend")]
     pub async fn new_chromiumoxide() -> Result<Self> {
-        eprintln!("Creating browser config...");
-        let config = BrowserConfig::builder()
-            .no_sandbox()
-            .headless_mode(HeadlessMode::New)
-            .build()?;
-
-        eprintln!("Launching chromium browser...");
-        let (chromium, mut handler) = ChromiumBrowser::launch(config).await?;
-
-        eprintln!("Starting event handler...");
-        let handler_task = tokio::spawn(async move {
-            eprintln!("Event handler loop started");
-            while let Some(_event) = handler.next().await {
-                // Just consume events
-            }
-            eprintln!("Event handler loop ended");
-        });
-
-        eprintln!("Creating new page...");
-        let page = chromium.new_page("about:blank").await?;
-
-        eprintln!("Page created successfully");
-        Ok(Self {
-            _browser_type: BrowserType::Chromiumoxide,
-            driver: None,
-            chromium: Some(chromium),
-            chromium_page: Some(page),
-            handler_task: Some(handler_task),
-        })
+        Self::new(BrowserType::Chromiumoxide).await
     }
 
+    /// Headless browser with its own temporary profile, so several can run at once
     #[cfg(feature = "chromiumoxide-backend")]
-    pub async fn new_chromiumoxide_with_path(chrome_path: &str) -> Result<Self> {
-        eprintln!("Creating browser config...");
-        let config = BrowserConfig::builder()
-            .chrome_executable(chrome_path)
-            .arg("--no-sandbox")
-            .arg("--disable-dev-shm-usage")
-            .arg("--disable-setuid-sandbox")
-            .arg("--disable-gpu")
-            .arg("--disable-software-rasterizer")
-            .headless_mode(HeadlessMode::False)
-            .window_size(1920, 1080)
-            .build()?;
-
-        eprintln!("Launching chromium browser...");
-        let (chromium, mut handler) = ChromiumBrowser::launch(config).await?;
-
-        eprintln!("Starting event handler...");
-        let handler_task = tokio::spawn(async move {
-            eprintln!("Event handler loop started");
-            while let Some(_event) = handler.next().await {
-                // Just consume events
-            }
-            eprintln!("Event handler loop ended");
-        });
-
-        eprintln!("Creating new page...");
-        let page = chromium.new_page("about:blank").await?;
-
-        eprintln!("Page created successfully");
-        Ok(Self {
-            _browser_type: BrowserType::Chromiumoxide,
-            driver: None,
-            chromium: Some(chromium),
-            chromium_page: Some(page),
-            handler_task: Some(handler_task),
-        })
+    pub async fn new_chromiumoxide_isolated() -> Result<Self> {
+        Self::new_isolated(BrowserType::Chromiumoxide).await
     }
 
+    /// Headed 1920x1080 Chromium from `chrome_path`
     #[cfg(feature = "chromiumoxide-backend")]
-    pub async fn navigate_to(&mut self, url: &str) -> Result<()> {
-        if let Some(driver) = &self.driver {
-            driver.goto(url).await?;
-        } else if let Some(page) = &self.chromium_page {
-            page.goto(url).await?;
-        } else {
-            return Err(WebSpecError::Browser("No driver initialized".to_string()));
-        }
-        Ok(())
+    pub async fn new_chromiumoxide_with_path(chrome_path: &str) -> Result<Self> {
+        let config = BrowserConfig {
+            executable: Some(chrome_path.into()),
+            headless: false,
+            window_size: Some(WindowSize {
+                width: 1920,
+                height: 1080,
+            }),
+            args: [
+                "--disable-dev-shm-usage",
+                "--disable-setuid-sandbox",
+                "--disable-gpu",
+                "--disable-software-rasterizer",
+            ]
+            .map(String::from)
+            .to_vec(),
+            ..BrowserConfig::new(BrowserType::Chromiumoxide)
+        };
+        Self::launch(&config).await
     }
 
-    #[cfg(not(feature = "chromiumoxide-backend"))]
-    pub async fn navigate_to(&mut self, url: &str) -> Result<()> {
-        if let Some(driver) = &self.driver {
-            driver.goto(url).await?;
-        } else {
-            return Err(WebSpecError::Browser("No driver initialized".to_string()));
-        }
-        Ok(())
+    pub fn browser_type(&self) -> BrowserType {
+        self.backend.browser_type()
     }
 
-    #[cfg(feature = "chromiumoxide-backend")]
-    pub async fn wait_for_load(&mut self) -> Result<()> {
-        if self.driver.is_some() || self.chromium_page.is_some() {
-            tokio::time::sleep(tokio::time::Duration::from_millis(1000)).await;
-            Ok(())
-        } else {
-            Err(WebSpecError::Browser("No driver initialized".to_string()))
-        }
+    pub fn backend(&self) -> &dyn BrowserBackend {
+        self.backend.as_ref()
     }
 
-    #[cfg(not(feature = "chromiumoxide-backend"))]
-    pub async fn wait_for_load(&mut self) -> Result<()> {
-        if self.driver.is_some() {
-            tokio::time::sleep(tokio::time::Duration::from_millis(1000)).await;
-            Ok(())
-        } else {
-            Err(WebSpecError::Browser("No driver initialized".to_string()))
-        }
+    /// How long [`Automation`](crate::Automation) actions wait for their element
+    /// to become actionable
+    pub fn auto_wait(&self) -> Duration {
+        self.auto_wait
     }
 
-    #[cfg(feature = "chromiumoxide-backend")]
-    pub async fn get_html(&self) -> Result<String> {
-        if let Some(driver) = &self.driver {
-            let result = driver
-                .execute("return document.documentElement.outerHTML;", Vec::new())
-                .await?;
-            let html = result
-                .json()
-                .as_str()
-                .ok_or_else(|| WebSpecError::Browser("Failed to get HTML".to_string()))?
-                .to_string();
-            Ok(html)
-        } else if let Some(page) = &self.chromium_page {
-            let html = page
-                .evaluate("document.documentElement.outerHTML")
-                .await?
-                .into_value()?;
-            Ok(html)
-        } else {
-            Err(WebSpecError::Browser("No driver initialized".to_string()))
-        }
+    pub fn set_auto_wait(&mut self, auto_wait: Duration) {
+        self.auto_wait = auto_wait;
+    }
+
+    /// Shut the browser down and remove its temporary profile, if it has one
+    pub async fn close(self) -> Result<()> {
+        self.backend.close().await
+    }
+
+    /// Continue in a fresh incognito browser context with its own cookies and storage
+    ///
+    /// The previous context and its pages are disposed. WebDriver sessions have no
+    /// contexts, so only their cookies are deleted.
+    pub async fn new_context(&mut self) -> Result<()> {
+        self.backend.new_context().await
+    }
+
+    pub async fn navigate_to(&mut self, url: &str) -> Result<()> {
+        self.backend.navigate_to(url).await
+    }
+
+    pub async fn wait_for_load(&mut self) -> Result<()> {
+        tokio::time::sleep(tokio::time::Duration::from_millis(1000)).await;
+        Ok(())
     }
 
-    #[cfg(not(feature = "chromiumoxide-backend"))]
     pub async fn get_html(&self) -> Result<String> {
-        if let Some(driver) = &self.driver {
-            let result = driver
-                .execute("return document.documentElement.outerHTML;", Vec::new())
-                .await?;
-            let html = result
-                .json()
-                .as_str()
-                .ok_or_else(|| WebSpecError::Browser("Failed to get HTML".to_string()))?
-                .to_string();
-            Ok(html)
-        } else {
-            Err(WebSpecError::Browser("No driver initialized".to_string()))
-        }
+        self.backend.page_html().await
     }
 
+    /// The WebDriver session, when this browser uses the WebDriver backend
     pub fn driver(&self) -> Option<&WebDriver> {
-        self.driver.as_ref()
+        self.backend
+            .as_any()
+            .downcast_ref::<WebDriverBackend>()
+            .and_then(|backend| backend.driver().ok())
     }
 
     #[cfg(feature = "chromiumoxide-backend")]
     pub fn chromium(&self) -> Option<&ChromiumBrowser> {
-        self.chromium.as_ref()
+        self.cdp().map(CdpBackend::browser)
     }
 
     #[cfg(feature = "chromiumoxide-backend")]
     pub fn chromium_page(&self) -> Option<&Page> {
-        self.chromium_page.as_ref()
+        self.cdp().map(CdpBackend::page)
+    }
+
+    #[cfg(feature = "chromiumoxide-backend")]
+    fn cdp(&self) -> Option<&CdpBackend> {
+        self.backend.as_any().downcast_ref::<CdpBackend>()
     }
 }
 
-impl Drop for Browser {
-    fn drop(&mut self) {
-        if let Some(driver) = self.driver.take() {
-            tokio::spawn(async move {
-                let _ = driver.quit().await;
-            });
-        }
+impl fmt::Debug for Browser {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Browser")
+            .field("backend", &self.browser_type())
+            .field("auto_wait", &self.auto_wait)
+            .finish()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    #[cfg(feature = "chromiumoxide-backend")]
     use super::*;
 
     #[cfg(feature = "chromiumoxide-backend")]
@@ -245,14 +254,54 @@ mod tests {
         let _browser_type = BrowserType::Chromiumoxide;
     }
 
+    #[test]
+    fn test_parse_browser_type() {
+        assert_eq!("webdriver".parse(), Ok(BrowserType::WebDriver));
+        assert_eq!(BrowserType::WebDriver.to_string(), "webdriver");
+        assert!("firefox".parse::<BrowserType>().is_err());
+        #[cfg(feature = "chromiumoxide-backend")]
+        {
+            assert_eq!("cdp".parse(), Ok(BrowserType::Chromiumoxide));
+            assert_eq!(BrowserType::default(), BrowserType::Chromiumoxide);
+            assert_eq!(
+                BrowserType::Chromiumoxide.to_string().parse(),
+                Ok(BrowserType::Chromiumoxide)
+            );
+        }
+    }
+
     #[cfg(feature = "chromiumoxide-backend")]
     #[tokio::test]
     #[ignore]
     async fn test_chromiumoxide_browser_creation() {
-        let result = Browser::new_chromiumoxide().await;
+        let result = Browser::new(BrowserType::Chromiumoxide).await;
         assert!(
             result.is_ok(),
             "Should successfully create Chromiumoxide browser"
         );
+        assert_eq!(result.unwrap().browser_type(), BrowserType::Chromiumoxide);
+    }
+
+    #[cfg(feature = "chromiumoxide-backend")]
+    #[tokio::test]
+    #[ignore]
+    async fn test_new_context_drops_cookies() {
+        let mut browser = Browser::new_chromiumoxide_isolated().await.unwrap();
+        browser.navigate_to("https://example.com").await.unwrap();
+        let page = browser.chromium_page().unwrap();
+        page.evaluate("document.cookie = 'seen=1'").await.unwrap();
+
+        browser.new_context().await.unwrap();
+        browser.navigate_to("https://example.com").await.unwrap();
+        let cookie: String = browser
+            .chromium_page()
+            .unwrap()
+            .evaluate("document.cookie")
+            .await
+            .unwrap()
+            .into_value()
+            .unwrap();
+        assert_eq!(cookie, "");
+        browser.close().await.unwrap();
     }
 }
@@ -1,5 +1,9 @@
 //! Command-line argument definitions using clap
-use clap::{Parser, Subcommand};
+use crate::backend::WindowSize;
+use crate::browser::BrowserType;
+use crate::execution::Shard;
+use crate::runner::ScenarioOrder;
+use clap::{Args as ClapArgs, Parser, Subcommand};
 use std::path::PathBuf;
 
 #[derive(Parser, Debug)]
@@ -17,6 +21,76 @@ pub struct Args {
     /// Legacy: Gherkin feature file to run (kept for backwards compatibility)
     #[arg(short, long)]
     pub feature: Option<PathBuf>,
+
+    /// YAML file of macro step definitions and hooks (defaults to ./steps.yml when present)
+    #[arg(long, global = true)]
+    pub steps: Option<PathBuf>,
+
+    #[command(flatten)]
+    pub browser: BrowserArgs,
+}
+
+/// Browser launch options; each overrides the same setting from the browser config file
+#[derive(ClapArgs, Debug, Default)]
+#[command(next_help_heading = "Browser options")]
+pub struct BrowserArgs {
+    /// YAML file of browser launch options (defaults to ./browser.yml when present)
+    #[arg(long, global = true, value_name = "FILE")]
+    pub browser_config: Option<PathBuf>,
+
+    /// Browser backend: chromiumoxide (Chromium over CDP, also `cdp`; the default) or webdriver
+    #[arg(long, global = true)]
+    pub backend: Option<BrowserType>,
+
+    /// Browser binary to launch instead of the detected one
+    #[arg(long, global = true, value_name = "PATH")]
+    pub browser_executable: Option<PathBuf>,
+
+    /// Show the browser window instead of running headless
+    #[arg(long, global = true, conflicts_with = "headless")]
+    pub headed: bool,
+
+    /// Run the browser headless even if the config file turns it off
+    #[arg(long, global = true)]
+    pub headless: bool,
+
+    /// Window and viewport size, e.g. 1280x720
+    #[arg(long, global = true, value_name = "WIDTHxHEIGHT")]
+    pub window_size: Option<WindowSize>,
+
+    /// Language the pages see, e.g. de-DE
+    #[arg(long, global = true)]
+    pub locale: Option<String>,
+
+    /// Time zone the pages see, e.g. Europe/Berlin
+    #[arg(long, global = true)]
+    pub timezone: Option<String>,
+
+    /// Proxy server for all browser traffic, e.g. http://proxy:3128
+    #[arg(long, global = true, value_name = "URL")]
+    pub proxy: Option<String>,
+
+    /// Extra browser command-line argument; repeat for several
+    #[arg(
+        long = "browser-arg",
+        global = true,
+        value_name = "ARG",
+        allow_hyphen_values = true
+    )]
+    pub browser_args: Vec<String>,
+
+    /// Profile directory for the browser
+    #[arg(long, global = true, value_name = "DIR")]
+    pub user_data_dir: Option<PathBuf>,
+
+    /// WebDriver server to connect to (default: http://localhost:4444)
+    #[arg(long, global = true, value_name = "URL")]
+    pub webdriver_url: Option<String>,
+
+    /// Milliseconds actions wait for their element to be attached, visible, stable,
+    /// enabled and unobscured (default: 5000)
+    #[arg(long, global = true, value_name = "MS")]
+    pub auto_wait: Option<u64>,
 }
 
 #[derive(Subcommand, Debug)]
@@ -24,8 +98,12 @@ pub enum Commands {
     /// Run a Gherkin feature file
     Run {
         /// Path to the feature file
-        #[arg(short, long)]
-        feature: PathBuf,
+        #[arg(short, long, required_unless_present = "rerun_failed")]
+        feature: Option<PathBuf>,
+
+        /// Re-run only the failed scenarios of a JSON result from `run` or `batch`, and report the merged result
+        #[arg(long, value_name = "RESULT_FILE", conflicts_with_all = ["feature", "dry_run"])]
+        rerun_failed: Option<PathBuf>,
 
         /// Output format (text, json, yaml, yml, tap, html)
         #[arg(long, default_value = "text")]
@@ -42,6 +120,38 @@ pub enum Commands {
         /// Dry-run mode: validate without executing
         #[arg(long)]
         dry_run: bool,
+
+        /// Only include scenarios matching a tag expression (e.g. "@smoke and not @wip")
+        #[arg(long)]
+        tags: Option<String>,
+
+        /// Fail steps that match more than one step definition instead of running the first
+        #[arg(long)]
+        strict: bool,
+
+        /// Seconds each step may run before it is cancelled (default: unlimited)
+        #[arg(long)]
+        step_timeout: Option<u64>,
+
+        /// Seconds each scenario's steps may run in total (default: unlimited)
+        #[arg(long)]
+        scenario_timeout: Option<u64>,
+
+        /// Re-run a failed scenario up to N more times; a scenario that then passes is reported as flaky
+        #[arg(long, default_value_t = 0)]
+        retry: usize,
+
+        /// Scenario order: defined, random (a fresh seed) or random:SEED to repeat a run's order
+        #[arg(long, default_value = "defined")]
+        order: ScenarioOrder,
+
+        /// Stop starting scenarios after the first failure; the rest are reported as skipped
+        #[arg(long, conflicts_with = "max_failures")]
+        fail_fast: bool,
+
+        /// Stop starting scenarios after N failures; the rest are reported as skipped
+        #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(1..))]
+        max_failures: Option<u64>,
     },
 
     /// Validate a Gherkin feature file
@@ -57,6 +167,10 @@ pub enum Commands {
         /// Output file path (if not specified, prints to stdout)
         #[arg(short, long)]
         output: Option<PathBuf>,
+
+        /// Only include scenarios matching a tag expression (e.g. "@smoke and not @wip")
+        #[arg(long)]
+        tags: Option<String>,
     },
 
     /// List available steps
@@ -199,9 +313,69 @@ pub enum Commands {
         #[arg(long)]
         workers: Option<usize>,
 
-        /// Continue execution even if some features fail
+        /// Keep starting features after one fails (default: true); with
+        /// --continue-on-failure=false the remaining features are skipped
+        #[arg(long, value_name = "BOOL", num_args = 0..=1, require_equals = true, default_missing_value = "true")]
+        continue_on_failure: Option<bool>,
+
+        /// Seconds each feature may run; scenarios still running then fail with SCENARIO_TIMEOUT
+        #[arg(long)]
+        timeout: Option<u64>,
+
+        /// Seconds each step may run before it is cancelled (default: unlimited)
         #[arg(long)]
-        continue_on_failure: bool,
+        step_timeout: Option<u64>,
+
+        /// Seconds each scenario's steps may run in total (default: unlimited)
+        #[arg(long)]
+        scenario_timeout: Option<u64>,
+
+        /// Re-run a failed scenario up to N more times; a scenario that then passes is reported as flaky
+        #[arg(long, default_value_t = 0)]
+        retry: usize,
+
+        /// Scenario order: defined, random (a fresh seed) or random:SEED to repeat a run's order
+        #[arg(long, default_value = "defined")]
+        order: ScenarioOrder,
+
+        /// Stop starting scenarios after the first failure; the rest are reported as skipped
+        #[arg(long, conflicts_with = "max_failures")]
+        fail_fast: bool,
+
+        /// Stop starting scenarios after N failures; the rest are reported as skipped
+        #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(1..))]
+        max_failures: Option<u64>,
+
+        /// Only run this machine's share of the features, e.g. 2/5 for the second of five
+        #[arg(long, value_name = "INDEX/TOTAL")]
+        shard: Option<Shard>,
+
+        /// JSON result of an earlier run whose feature durations balance the shards
+        #[arg(long, value_name = "RESULT_FILE", requires = "shard")]
+        shard_durations: Option<PathBuf>,
+
+        /// Pretty-print JSON/YAML output
+        #[arg(long)]
+        pretty: bool,
+
+        /// Only include scenarios matching a tag expression (e.g. "@smoke and not @wip")
+        #[arg(long)]
+        tags: Option<String>,
+    },
+
+    /// Merge the JSON results of batch shards into one batch result
+    Merge {
+        /// JSON result files written by `batch` or `run`
+        #[arg(required = true)]
+        results: Vec<PathBuf>,
+
+        /// Output format (text, json, yaml, yml)
+        #[arg(long, default_value = "text")]
+        format: String,
+
+        /// Output file path (if not specified, prints to stdout)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
 
         /// Pretty-print JSON/YAML output
         #[arg(long)]
@@ -1,15 +1,15 @@
 //! Command handlers for CLI operations
-use crate::discovery::catalog::build_step_catalog;
+use crate::discovery::catalog::StepCatalog;
 use crate::discovery::search::filter_by_category;
-use crate::validation::feature::validate_feature;
+use crate::validation::feature::{ValidationOptions, validate_feature_with};
 use std::path::PathBuf;
 
 /// List all available steps
 pub fn handle_list_steps(
+    catalog: &StepCatalog,
     category: Option<String>,
     search: Option<String>,
 ) -> Result<Vec<String>, String> {
-    let catalog = build_step_catalog();
     let all_steps = catalog.all_steps();
 
     // Filter by category first if provided
@@ -55,8 +55,11 @@ pub fn handle_list_steps(
 }
 
 /// Search for steps matching a pattern
-pub fn handle_search_steps(query: &str, category: Option<String>) -> Result<Vec<String>, String> {
-    let catalog = build_step_catalog();
+pub fn handle_search_steps(
+    catalog: &StepCatalog,
+    query: &str,
+    category: Option<String>,
+) -> Result<Vec<String>, String> {
     let all_steps = catalog.all_steps();
 
     // Filter by category first if provided
@@ -109,18 +112,20 @@ fn search_steps_in_refs<'a>(
 }
 
 /// Export step catalog as schema
-pub fn handle_export_schema() -> Result<String, String> {
-    let catalog = build_step_catalog();
-    let schema = crate::discovery::schema::SchemaExport::from_catalog(&catalog);
+pub fn handle_export_schema(catalog: &StepCatalog) -> Result<String, String> {
+    let schema = crate::discovery::schema::SchemaExport::from_catalog(catalog);
     serde_json::to_string_pretty(&schema).map_err(|e| format!("Failed to serialize schema: {}", e))
 }
 
 /// Validate a feature file
-pub fn handle_validate_feature(feature_path: &PathBuf) -> Result<String, String> {
+pub fn handle_validate_feature(
+    feature_path: &PathBuf,
+    options: &ValidationOptions,
+) -> Result<String, String> {
     let path_str = feature_path
         .to_str()
         .ok_or_else(|| "Invalid path".to_string())?;
-    let result = validate_feature(path_str)?;
+    let result = validate_feature_with(path_str, options)?;
 
     let mut output = String::new();
     if result.is_valid() {
@@ -132,6 +137,9 @@ pub fn handle_validate_feature(feature_path: &PathBuf) -> Result<String, String>
         ));
         for error in &result.errors {
             output.push_str(&format!("  - {}: {}\n", error.error_type, error.message));
+            if let Some(location) = &error.location {
+                output.push_str(&format!("    at {}\n", location.describe(Some(path_str))));
+            }
             if !error.suggestions.is_empty() {
                 output.push_str("    Suggestions:\n");
                 for suggestion in &error.suggestions {
@@ -148,6 +156,9 @@ pub fn handle_validate_feature(feature_path: &PathBuf) -> Result<String, String>
                 "  ⚠ {}: {}\n",
                 warning.warning_type, warning.message
             ));
+            if let Some(location) = &warning.location {
+                output.push_str(&format!("    at {}\n", location.describe(Some(path_str))));
+            }
         }
     }
 
@@ -159,11 +170,14 @@ pub fn handle_validate_feature(feature_path: &PathBuf) -> Result<String, String>
 }
 
 /// Export validation result as JSON
-pub fn handle_validate_feature_json(feature_path: &PathBuf) -> Result<String, String> {
+pub fn handle_validate_feature_json(
+    feature_path: &PathBuf,
+    options: &ValidationOptions,
+) -> Result<String, String> {
     let path_str = feature_path
         .to_str()
         .ok_or_else(|| "Invalid path".to_string())?;
-    let result = validate_feature(path_str)?;
+    let result = validate_feature_with(path_str, options)?;
 
     // Create a JSON representation of the validation result
     let json = serde_json::json!({
@@ -179,11 +193,14 @@ pub fn handle_validate_feature_json(feature_path: &PathBuf) -> Result<String, St
 }
 
 /// Export validation result as YAML
-pub fn handle_validate_feature_yaml(feature_path: &PathBuf) -> Result<String, String> {
+pub fn handle_validate_feature_yaml(
+    feature_path: &PathBuf,
+    options: &ValidationOptions,
+) -> Result<String, String> {
     let path_str = feature_path
         .to_str()
         .ok_or_else(|| "Invalid path".to_string())?;
-    let result = validate_feature(path_str)?;
+    let result = validate_feature_with(path_str, options)?;
 
     // Create a YAML representation of the validation result
     let yaml_data = serde_yaml::to_value(&serde_json::json!({
@@ -200,11 +217,14 @@ pub fn handle_validate_feature_yaml(feature_path: &PathBuf) -> Result<String, St
 }
 
 /// Export validation result as TAP (Test Anything Protocol)
-pub fn handle_validate_feature_tap(feature_path: &PathBuf) -> Result<String, String> {
+pub fn handle_validate_feature_tap(
+    feature_path: &PathBuf,
+    options: &ValidationOptions,
+) -> Result<String, String> {
     let path_str = feature_path
         .to_str()
         .ok_or_else(|| "Invalid path".to_string())?;
-    let result = validate_feature(path_str)?;
+    let result = validate_feature_with(path_str, options)?;
 
     // Create a TAP representation of the validation result
     // TAP format: version, plan, test results
@@ -225,7 +245,14 @@ pub fn handle_validate_feature_tap(feature_path: &PathBuf) -> Result<String, Str
         if !result.errors.is_empty() {
             tap_output.push_str("    Error details:\n");
             for error in &result.errors {
-                tap_output.push_str(&format!("      - {:?}\n", error));
+                match &error.location {
+                    Some(location) => tap_output.push_str(&format!(
+                        "      - {}: {:?}\n",
+                        location.describe(Some(path_str)),
+                        error
+                    )),
+                    None => tap_output.push_str(&format!("      - {:?}\n", error)),
+                }
             }
         }
 
@@ -236,11 +263,14 @@ pub fn handle_validate_feature_tap(feature_path: &PathBuf) -> Result<String, Str
 }
 
 /// Handle validate command with HTML output
-pub fn handle_validate_feature_html(feature_path: &PathBuf) -> Result<String, String> {
+pub fn handle_validate_feature_html(
+    feature_path: &PathBuf,
+    options: &ValidationOptions,
+) -> Result<String, String> {
     let path_str = feature_path
         .to_str()
         .ok_or_else(|| "Invalid path".to_string())?;
-    let validation_result = validate_feature(path_str)?;
+    let validation_result = validate_feature_with(path_str, options)?;
 
     // Create an ExecutionResult-like structure for HTML rendering
     // Since validation doesn't execute scenarios, we'll create a simple HTML report
@@ -370,6 +400,12 @@ pub fn handle_validate_feature_html(feature_path: &PathBuf) -> Result<String, St
                 "            <div class=\"error-text\">{}</div>\n",
                 escape_html_for_attr(&error.error_type)
             ));
+            if let Some(location) = &error.location {
+                html.push_str(&format!(
+                    "            <div class=\"error-text\">at {}</div>\n",
+                    escape_html_for_attr(&location.describe(Some(path_str)))
+                ));
+            }
             html.push_str("          </li>\n");
         }
         html.push_str("        </ul>\n");
@@ -448,9 +484,10 @@ pub fn handle_compare_results(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::discovery::catalog::build_step_catalog;
     #[test]
     fn test_list_steps() {
-        let result = handle_list_steps(None, None);
+        let result = handle_list_steps(&build_step_catalog(), None, None);
         assert!(result.is_ok());
         let steps = result.unwrap();
         assert!(!steps.is_empty());
@@ -458,7 +495,7 @@ mod tests {
 
     #[test]
     fn test_search_steps() {
-        let result = handle_search_steps("click", None);
+        let result = handle_search_steps(&build_step_catalog(), "click", None);
         assert!(result.is_ok());
         let results = result.unwrap();
         assert!(!results.is_empty());
@@ -466,11 +503,31 @@ mod tests {
 
     #[test]
     fn test_export_schema() {
-        let result = handle_export_schema();
+        let result = handle_export_schema(&build_step_catalog());
         assert!(result.is_ok());
         let schema_json = result.unwrap();
         assert!(!schema_json.is_empty());
         // Verify it's valid JSON
         assert!(schema_json.contains("\"steps\""));
     }
+
+    #[test]
+    fn test_validate_feature_reports_locations() {
+        let mut file = tempfile::Builder::new()
+            .suffix(".feature")
+            .tempfile()
+            .unwrap();
+        std::io::Write::write_all(
+            &mut file,
+            b"Feature: Broken\n  Scenario: Unknown\n    Given I foobarbaz something\n",
+        )
+        .unwrap();
+        let path = file.path().to_path_buf();
+
+        let text = handle_validate_feature(&path, &ValidationOptions::default()).unwrap_err();
+        assert!(text.contains(&format!("at {}:3:5", path.display())));
+
+        let json = handle_validate_feature_json(&path, &ValidationOptions::default()).unwrap();
+        assert!(json.contains("\"location\":{\"line\":3,\"column\":5}"));
+    }
 }
@@ -0,0 +1,2629 @@
+// Declarative table of built-in step definitions shared by the runner and the catalog
+use super::catalog::{ParameterInfo, StepInfo};
+use super::expression::infer_parameters;
+
+#[derive(Debug, Clone, Copy)]
+pub struct ParameterDefinition {
+    pub name: &'static str,
+    pub param_type: &'static str,
+    pub required: bool,
+    pub description: &'static str,
+}
+
+/// A built-in step: its id doubles as the runner's handler name
+#[derive(Debug, Clone, Copy)]
+pub struct StepDefinition {
+    pub id: &'static str,
+    pub category: &'static str,
+    pub description: &'static str,
+    /// Regex patterns; the first is the primary pattern, the rest are aliases
+    pub patterns: &'static [&'static str],
+    pub parameters: &'static [ParameterDefinition],
+    pub examples: &'static [&'static str],
+    /// Earlier steps that win when a step's text matches both, so it is not ambiguous
+    pub yields_to: &'static [&'static str],
+}
+
+impl StepDefinition {
+    pub const fn new(
+        id: &'static str,
+        category: &'static str,
+        description: &'static str,
+        patterns: &'static [&'static str],
+    ) -> Self {
+        StepDefinition {
+            id,
+            category,
+            description,
+            patterns,
+            parameters: &[],
+            examples: &[],
+            yields_to: &[],
+        }
+    }
+
+    pub const fn with_parameters(mut self, parameters: &'static [ParameterDefinition]) -> Self {
+        self.parameters = parameters;
+        self
+    }
+
+    pub const fn with_examples(mut self, examples: &'static [&'static str]) -> Self {
+        self.examples = examples;
+        self
+    }
+
+    pub const fn yields_to(mut self, ids: &'static [&'static str]) -> Self {
+        self.yields_to = ids;
+        self
+    }
+
+    pub fn to_step_info(&self) -> StepInfo {
+        StepInfo {
+            id: self.id.to_string(),
+            pattern: self.patterns[0].to_string(),
+            aliases: self.patterns[1..].iter().map(|p| p.to_string()).collect(),
+            category: self.category.to_string(),
+            description: self.description.to_string(),
+            parameters: if self.parameters.is_empty() {
+                infer_parameters(self.patterns[0])
+            } else {
+                self.parameters
+                    .iter()
+                    .map(|p| ParameterInfo {
+                        name: p.name.to_string(),
+                        param_type: p.param_type.to_string(),
+                        required: p.required,
+                        description: p.description.to_string(),
+                    })
+                    .collect()
+            },
+            examples: self.examples.iter().map(|e| e.to_string()).collect(),
+        }
+    }
+}
+
+/// Every built-in step, in the order the runner tries their patterns
+pub static STEP_DEFINITIONS: &[StepDefinition] = &[
+    // ===== SETUP =====
+    StepDefinition::new(
+        "browser_available",
+        "Other",
+        "Step: browser_available",
+        &[r"a browser (?:backend )?is available"],
+    ),
+    // ===== NAVIGATION =====
+    StepDefinition::new(
+        "navigate_to",
+        "Navigation",
+        "Navigate to a specified URL",
+        &[
+            r#"I navigate to "([^"]+)""#,
+            r#"I go to "([^"]+)""#,
+            r#"I open "([^"]+)""#,
+            r#"I visit "([^"]+)""#,
+        ],
+    ),
+    StepDefinition::new(
+        "navigate_to_hackernews",
+        "Navigation",
+        "Step: navigate_to_hackernews",
+        &[r"I navigate to Hacker News", r"I go to Hacker News"],
+    ),
+    StepDefinition::new(
+        "go_back",
+        "Navigation",
+        "Step: go_back",
+        &[r"I go back", r"I navigate back"],
+    ),
+    StepDefinition::new(
+        "go_forward",
+        "Navigation",
+        "Step: go_forward",
+        &[r"I go forward", r"I navigate forward"],
+    ),
+    StepDefinition::new(
+        "refresh",
+        "Navigation",
+        "Step: refresh",
+        &[r"I refresh the page", r"I reload the page"],
+    ),
+    StepDefinition::new(
+        "wait_load",
+        "Navigation",
+        "Step: wait_load",
+        &[
+            r"the page loads",
+            r"I wait for the page to load",
+            r"I wait for page to load",
+        ],
+    ),
+    // ===== WAITING =====
+    StepDefinition::new(
+        "wait_seconds",
+        "Waiting",
+        "Step: wait_seconds",
+        &[
+            r"I wait {int} second(s)",
+            r"I wait for {int} second(s)",
+            r"I pause for {int} second(s)",
+            r"I sleep {int} second(s)",
+        ],
+    ),
+    StepDefinition::new(
+        "wait_ms",
+        "Waiting",
+        "Step: wait_ms",
+        &[
+            r"I wait {int} millisecond(s)",
+            r"I wait for {int} millisecond(s)",
+        ],
+    ),
+    StepDefinition::new(
+        "wait_visible",
+        "Waiting",
+        "Wait for an element to become visible",
+        &[
+            r#"I wait for element "([^"]+)" to be visible"#,
+            r#"I wait for "([^"]+)" to be visible"#,
+            r#"I wait until "([^"]+)" is visible"#,
+        ],
+    ),
+    StepDefinition::new(
+        "wait_appear",
+        "Waiting",
+        "Wait for an element to appear on the page",
+        &[
+            r#"I wait for element "([^"]+)" to appear"#,
+            r#"I wait for "([^"]+)" to appear"#,
+            r#"I wait until "([^"]+)" appears"#,
+        ],
+    ),
+    StepDefinition::new(
+        "wait_hidden",
+        "Waiting",
+        "Wait for an element to become hidden",
+        &[
+            r#"I wait for element "([^"]+)" to be hidden"#,
+            r#"I wait for "([^"]+)" to disappear"#,
+        ],
+    ),
+    StepDefinition::new(
+        "wait_for_text",
+        "Waiting",
+        "Wait for text to appear",
+        &[r#"I wait for text "([^"]+)" to appear"#],
+    ),
+    StepDefinition::new(
+        "wait_for_element_text",
+        "Waiting",
+        "Wait for element to contain text",
+        &[r#"I wait for element "([^"]+)" to contain "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "wait_clickable",
+        "Waiting",
+        "Wait for element to be clickable",
+        &[r#"I wait for element "([^"]+)" to be (?:clickable|enabled)"#],
+    ),
+    // ===== CLICKING =====
+    StepDefinition::new(
+        "click",
+        "Interaction",
+        "Click on an element",
+        &[
+            r#"I click on "([^"]+)""#,
+            r#"I click "([^"]+)""#,
+            r#"I press "([^"]+)""#,
+            r#"I tap "([^"]+)""#,
+        ],
+    ),
+    StepDefinition::new(
+        "click_button",
+        "Interaction",
+        "Click a button element",
+        &[r#"I click the "([^"]+)" button"#],
+    ),
+    StepDefinition::new(
+        "click_link",
+        "Interaction",
+        "Click a link element",
+        &[r#"I click the "([^"]+)" link"#],
+    ),
+    StepDefinition::new(
+        "click_button_or_link",
+        "Interaction",
+        "Click a button or link",
+        &[r#"I click the "([^"]+)" (button|link)"#],
+    )
+    .yields_to(&["click_button", "click_link"]),
+    StepDefinition::new(
+        "click_submit",
+        "Interaction",
+        "Step: click_submit",
+        &[r"I click submit button", r"I click the submit button"],
+    ),
+    StepDefinition::new(
+        "click_search",
+        "Interaction",
+        "Step: click_search",
+        &[r"I click search button", r"I click the search button"],
+    ),
+    StepDefinition::new(
+        "click_first_button",
+        "Interaction",
+        "Step: click_first_button",
+        &[r"I click the first ([^ ]+) button"],
+    ),
+    StepDefinition::new(
+        "click_last_button",
+        "Interaction",
+        "Step: click_last_button",
+        &[r"I click the last ([^ ]+) button"],
+    ),
+    StepDefinition::new(
+        "double_click",
+        "Interaction",
+        "Double-click an element",
+        &[
+            r#"I double click on "([^"]+)""#,
+            r#"I double-click "([^"]+)""#,
+        ],
+    ),
+    StepDefinition::new(
+        "right_click",
+        "Interaction",
+        "Right-click an element",
+        &[
+            r#"I right click on "([^"]+)""#,
+            r#"I right-click "([^"]+)""#,
+            r#"I click the right mouse button on "([^"]+)""#,
+        ],
+    ),
+    // ===== MOUSE INTERACTION =====
+    StepDefinition::new(
+        "hover",
+        "Interaction",
+        "Hover over an element",
+        &[
+            r#"I hover over "([^"]+)""#,
+            r#"I hover "([^"]+)""#,
+            r#"I move mouse to "([^"]+)""#,
+        ],
+    ),
+    StepDefinition::new(
+        "drag_and_drop",
+        "Interaction",
+        "Drag an element and drop it on another",
+        &[
+            r#"I drag "([^"]+)" to "([^"]+)""#,
+            r#"I drag element "([^"]+)" and drop it on "([^"]+)""#,
+        ],
+    ),
+    // ===== INPUT =====
+    StepDefinition::new(
+        "type_text",
+        "Input",
+        "Type text into an input field",
+        &[
+            r#"I type "([^"]+)" into "([^"]+)""#,
+            r#"I enter "([^"]+)" into "([^"]+)""#,
+            r#"I type "([^"]+)" (?:in|into) "([^"]+)""#,
+            r#"I enter "([^"]+)" (?:in|into) "([^"]+)""#,
+            r#"I fill "([^"]+)" with "([^"]+)""#,
+            r#"I fill in "([^"]+)" with "([^"]+)""#,
+            r#"I input "([^"]+)" (?:in|into) "([^"]+)""#,
+        ],
+    ),
+    StepDefinition::new(
+        "fill_form",
+        "Input",
+        "Fill several fields from a data table of selectors and values",
+        &[r"I fill the form with:", r"I fill in the form with:"],
+    )
+    .with_parameters(&[ParameterDefinition {
+        name: "table",
+        param_type: "data_table",
+        required: true,
+        description: "Rows of | selector | value |, with an optional header row",
+    }])
+    .with_examples(&["I fill the form with:\n  | #email | user@example.com |"]),
+    StepDefinition::new(
+        "clear_text",
+        "Input",
+        "Clear the contents of an input field",
+        &[
+            r#"I clear "([^"]+)""#,
+            r#"I clear the input "([^"]+)""#,
+            r#"I clear the field "([^"]+)""#,
+        ],
+    ),
+    StepDefinition::new(
+        "select_option",
+        "Input",
+        "Select an option from a dropdown",
+        &[
+            r#"I select "([^"]+)" from "([^"]+)""#,
+            r#"I choose "([^"]+)" from "([^"]+)""#,
+            r#"I pick "([^"]+)" from "([^"]+)""#,
+        ],
+    ),
+    StepDefinition::new(
+        "check",
+        "Input",
+        "Check a checkbox",
+        &[r#"I check "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "uncheck",
+        "Input",
+        "Uncheck a checkbox",
+        &[r#"I uncheck "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "toggle",
+        "Input",
+        "Toggle a checkbox or switch",
+        &[r#"I toggle "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "select_radio",
+        "Input",
+        "Select a radio button option",
+        &[r#"I select the "([^"]+)" radio (?:button|option)"#],
+    ),
+    StepDefinition::new(
+        "select_multiple",
+        "Input",
+        "Select multiple options",
+        &[r#"I select "([^"]+)", "([^"]+)", and "([^"]+)" from "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "select_all",
+        "Input",
+        "Select all options in a multi-select",
+        &[r#"I select multiple options from "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "deselect_all",
+        "Input",
+        "Deselect all options in a multi-select",
+        &[r#"I deselect all from "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "upload_file",
+        "Input",
+        "Upload a file",
+        &[
+            r#"I upload file "([^"]+)" to "([^"]+)""#,
+            r#"I attach "([^"]+)" to "([^"]+)""#,
+        ],
+    ),
+    StepDefinition::new(
+        "press_key",
+        "Input",
+        "Press a keyboard key",
+        &[r#"I press "([^"]+)" key"#],
+    ),
+    StepDefinition::new(
+        "press_enter",
+        "Input",
+        "Press the Enter key",
+        &[r"I press the Enter key", r"I press Enter"],
+    ),
+    StepDefinition::new(
+        "press_escape",
+        "Input",
+        "Press the Escape key",
+        &[r"I press Escape key", r"I press Escape"],
+    ),
+    StepDefinition::new(
+        "press_tab",
+        "Input",
+        "Press the Tab key",
+        &[r"I press Tab key", r"I press Tab"],
+    ),
+    StepDefinition::new(
+        "type_into",
+        "Input",
+        "Type into a specific element",
+        &[r#"I press "([^"]+)" in "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "submit_form",
+        "Input",
+        "Submit a form",
+        &[
+            r#"I submit the form "([^"]+)""#,
+            r#"I submit "([^"]+)""#,
+            r"I submit the form",
+        ],
+    ),
+    // ===== SCROLLING =====
+    StepDefinition::new(
+        "scroll_bottom",
+        "Scrolling",
+        "Step: scroll_bottom",
+        &[r"I scroll to bottom", r"I scroll to the bottom"],
+    ),
+    StepDefinition::new(
+        "scroll_top",
+        "Scrolling",
+        "Step: scroll_top",
+        &[r"I scroll to top", r"I scroll to the top"],
+    ),
+    StepDefinition::new(
+        "scroll_to_element",
+        "Scrolling",
+        "Scroll to a specific element",
+        &[r#"I scroll to "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "scroll_down",
+        "Scrolling",
+        "Step: scroll_down",
+        &[r"I scroll down by {int} pixel(s)"],
+    ),
+    StepDefinition::new(
+        "scroll_up",
+        "Scrolling",
+        "Step: scroll_up",
+        &[r"I scroll up by {int} pixel(s)"],
+    ),
+    StepDefinition::new(
+        "scroll_left",
+        "Scrolling",
+        "Step: scroll_left",
+        &[r"I scroll left by {int} pixel(s)"],
+    ),
+    StepDefinition::new(
+        "scroll_right",
+        "Scrolling",
+        "Step: scroll_right",
+        &[r"I scroll right by {int} pixel(s)"],
+    ),
+    StepDefinition::new(
+        "scroll_pixels_vertical",
+        "Scrolling",
+        "Step: scroll_pixels_vertical",
+        &[r"I scroll (\d+) pixels? (down|up)"],
+    ),
+    StepDefinition::new(
+        "scroll_pixels_horizontal",
+        "Scrolling",
+        "Step: scroll_pixels_horizontal",
+        &[r"I scroll (\d+) pixels? (left|right)"],
+    ),
+    // ===== VISIBILITY =====
+    StepDefinition::new(
+        "should_see",
+        "Verification",
+        "Verify an element is visible",
+        &[
+            r#"I should see "([^"]+)""#,
+            r#"I should see the element "([^"]+)""#,
+        ],
+    ),
+    StepDefinition::new(
+        "should_not_see",
+        "Verification",
+        "Verify an element is not visible",
+        &[
+            r#"I should not see "([^"]+)""#,
+            r#"I should not see the element "([^"]+)""#,
+        ],
+    ),
+    StepDefinition::new(
+        "should_be_visible",
+        "Verification",
+        "Verify element is visible",
+        &[
+            r#"the element "([^"]+)" should be visible"#,
+            r#""([^"]+)" should be visible"#,
+        ],
+    ),
+    StepDefinition::new(
+        "should_not_be_visible",
+        "Verification",
+        "Verify element is not visible",
+        &[
+            r#"the element "([^"]+)" should not be visible"#,
+            r#""([^"]+)" should not be visible"#,
+        ],
+    ),
+    StepDefinition::new(
+        "should_exist",
+        "Verification",
+        "Verify element exists",
+        &[r#"the element "([^"]+)" should exist"#],
+    ),
+    StepDefinition::new(
+        "should_not_exist",
+        "Verification",
+        "Verify element does not exist",
+        &[r#"the element "([^"]+)" should not exist"#],
+    ),
+    StepDefinition::new(
+        "should_be_enabled",
+        "Verification",
+        "Verify element is enabled",
+        &[r#"the element "([^"]+)" should be enabled"#],
+    ),
+    StepDefinition::new(
+        "should_be_disabled",
+        "Verification",
+        "Verify element is disabled",
+        &[r#"the element "([^"]+)" should be disabled"#],
+    ),
+    StepDefinition::new(
+        "should_be_checked",
+        "Verification",
+        "Verify checkbox is checked",
+        &[r#"the element "([^"]+)" should be checked"#],
+    ),
+    StepDefinition::new(
+        "should_not_be_checked",
+        "Verification",
+        "Verify checkbox is not checked",
+        &[r#"the element "([^"]+)" should not be checked"#],
+    ),
+    StepDefinition::new(
+        "should_be_selected",
+        "Verification",
+        "Verify element is selected",
+        &[r#"the element "([^"]+)" should be selected"#],
+    ),
+    StepDefinition::new(
+        "should_be_focused",
+        "Verification",
+        "Verify element has focus",
+        &[r#"the element "([^"]+)" should be focused"#],
+    ),
+    StepDefinition::new(
+        "should_contain_text",
+        "Verification",
+        "Verify element contains text",
+        &[r#"the element "([^"]+)" should contain "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "should_not_contain_text",
+        "Verification",
+        "Verify element does not contain text",
+        &[r#"the element "([^"]+)" should not contain "([^"]+)""#],
+    ),
+    // ===== COUNTING =====
+    StepDefinition::new(
+        "should_see_min_count",
+        "Verification",
+        "Step: should_see_min_count",
+        &[
+            r"I should see at least (\d+) ([^ ]+)",
+            r"I should see minimum (\d+) ([^ ]+)",
+        ],
+    ),
+    StepDefinition::new(
+        "should_see_max_count",
+        "Verification",
+        "Step: should_see_max_count",
+        &[r"I should see at most (\d+) ([^ ]+)"],
+    ),
+    StepDefinition::new(
+        "should_see_exact_count",
+        "Verification",
+        "Step: should_see_exact_count",
+        &[
+            r"I should see exactly (\d+) ([^ ]+)",
+            r"I should see exactly (\d+) ([^ ]+) elements?",
+            r#"there should be (\d+) ([^ "]+)"#,
+        ],
+    ),
+    StepDefinition::new(
+        "should_see_exact_count_elements",
+        "Verification",
+        "Verify exact count of specific elements",
+        &[r#"there should be (\d+) "([^"]+)""#],
+    ),
+    // ===== TEXT =====
+    StepDefinition::new(
+        "should_see_text",
+        "Verification",
+        "Verify text is present on page",
+        &[
+            r#"I should see text "([^"]+)""#,
+            r#"the page should contain "([^"]+)""#,
+            r"the page should contain:",
+        ],
+    ),
+    StepDefinition::new(
+        "should_not_see_text",
+        "Verification",
+        "Verify text is not on page",
+        &[
+            r#"I should not see text "([^"]+)""#,
+            r#"the page should not contain "([^"]+)""#,
+        ],
+    ),
+    StepDefinition::new(
+        "text_should_be",
+        "Verification",
+        "Verify element text equals value",
+        &[
+            r#"the text of "([^"]+)" should be "([^"]+)""#,
+            r#"the text of "([^"]+)" should equal "([^"]+)""#,
+        ],
+    ),
+    StepDefinition::new(
+        "text_should_contain",
+        "Verification",
+        "Verify element text contains value",
+        &[r#"the text of "([^"]+)" should contain "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "text_should_match",
+        "Verification",
+        "Verify element text matches pattern",
+        &[r#"the text of "([^"]+)" should match "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "text_should_start",
+        "Verification",
+        "Verify element text starts with value",
+        &[r#"the text of "([^"]+)" should start with "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "text_should_end",
+        "Verification",
+        "Verify element text ends with value",
+        &[r#"the text of "([^"]+)" should end with "([^"]+)""#],
+    ),
+    // ===== ATTRIBUTE =====
+    StepDefinition::new(
+        "attribute_should_be",
+        "Verification",
+        "Verify element attribute value",
+        &[
+            r#"the "([^"]+)" attribute of "([^"]+)" should be "([^"]+)""#,
+            r#"the element "([^"]+)" should have "([^"]+)" attribute set to "([^"]+)""#,
+        ],
+    ),
+    StepDefinition::new(
+        "attribute_should_contain",
+        "Verification",
+        "Verify element attribute contains value",
+        &[r#"the "([^"]+)" attribute of "([^"]+)" should contain "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "attribute_should_exist",
+        "Verification",
+        "Verify element attribute exists",
+        &[
+            r#"the "([^"]+)" attribute of "([^"]+)" should exist"#,
+            r#"the element "([^"]+)" should have "([^"]+)" attribute"#,
+        ],
+    ),
+    // ===== CSS =====
+    StepDefinition::new(
+        "css_should_be",
+        "Verification",
+        "Verify CSS property value",
+        &[
+            r#"the "([^"]+)" CSS property of "([^"]+)" should be "([^"]+)""#,
+            r#"the element "([^"]+)" should have "([^"]+)" CSS value of "([^"]+)""#,
+        ],
+    ),
+    StepDefinition::new(
+        "color_should_be",
+        "Verification",
+        "Verify element color",
+        &[r#"the element "([^"]+)" should have color "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "background_should_be",
+        "Verification",
+        "Verify element background",
+        &[r#"the element "([^"]+)" should have background "([^"]+)""#],
+    ),
+    // ===== URL/PATH =====
+    StepDefinition::new(
+        "url_should_be",
+        "Verification",
+        "Verify current URL",
+        &[
+            r#"the URL should be "([^"]+)""#,
+            r#"the current URL should be "([^"]+)""#,
+        ],
+    ),
+    StepDefinition::new(
+        "url_should_contain",
+        "Verification",
+        "Verify URL contains value",
+        &[r#"the URL should contain "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "path_should_be",
+        "Verification",
+        "Verify current path",
+        &[r#"the path should be "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "path_should_contain",
+        "Verification",
+        "Verify path contains value",
+        &[r#"the path should contain "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "title_should_be",
+        "Verification",
+        "Verify page title",
+        &[r#"the title should be "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "title_should_contain",
+        "Verification",
+        "Verify page title contains value",
+        &[r#"the title should contain "([^"]+)""#],
+    ),
+    // ===== SCREENSHOT =====
+    StepDefinition::new(
+        "screenshot",
+        "Extraction",
+        "Take a screenshot",
+        &[
+            r#"I take a screenshot "([^"]+)""#,
+            r#"I capture screenshot "([^"]+)""#,
+        ],
+    ),
+    StepDefinition::new(
+        "screenshot_auto",
+        "Extraction",
+        "Step: screenshot_auto",
+        &[r"I take a screenshot", r"I capture a screenshot"],
+    ),
+    StepDefinition::new(
+        "screenshot_full",
+        "Extraction",
+        "Step: screenshot_full",
+        &[r"I take a full page screenshot"],
+    ),
+    StepDefinition::new(
+        "screenshot_element",
+        "Other",
+        "Step: screenshot_element",
+        &[r#"I take screenshot of "([^"]+)""#],
+    ),
+    // ===== JAVASCRIPT =====
+    StepDefinition::new(
+        "execute_script",
+        "Other",
+        "Step: execute_script",
+        &[
+            r#"I execute JavaScript "([^"]+)""#,
+            r#"I execute script "([^"]+)""#,
+            r#"I run JavaScript "([^"]+)""#,
+            r#"I run script "([^"]+)""#,
+            r#"I evaluate "([^"]+)""#,
+            r"I execute JavaScript:",
+            r"I execute script:",
+        ],
+    ),
+    // ===== STORAGE =====
+    StepDefinition::new(
+        "store_value",
+        "State",
+        "Store value in variable",
+        &[
+            r#"I set "([^"]+)" to "([^"]+)""#,
+            r#"I save "([^"]+)" as "([^"]+)""#,
+            r#"I store "([^"]+)" as "([^"]+)""#,
+        ],
+    ),
+    StepDefinition::new(
+        "value_should_be",
+        "Other",
+        "Step: value_should_be",
+        &[r#"the value "([^"]+)" should be "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "use_stored_value",
+        "Other",
+        "Step: use_stored_value",
+        &[r#"I use the stored value "([^"]+)""#],
+    ),
+    // ===== BROWSER CONTROL =====
+    StepDefinition::new(
+        "maximize_window",
+        "Other",
+        "Step: maximize_window",
+        &[r"I maximize the window"],
+    ),
+    StepDefinition::new(
+        "fullscreen_window",
+        "Other",
+        "Step: fullscreen_window",
+        &[r"I fullscreen the window"],
+    ),
+    StepDefinition::new(
+        "minimize_window",
+        "Other",
+        "Step: minimize_window",
+        &[r"I minimize the window"],
+    ),
+    StepDefinition::new(
+        "resize_window",
+        "Other",
+        "Step: resize_window",
+        &[
+            r"I resize the window to {int}x{int}",
+            r"I set viewport to {int}x{int}",
+        ],
+    ),
+    StepDefinition::new(
+        "set_user_agent",
+        "Other",
+        "Step: set_user_agent",
+        &[r#"I set user agent to "([^"]+)""#],
+    ),
+    // ===== ALERT/MODAL =====
+    StepDefinition::new(
+        "accept_alert",
+        "Other",
+        "Step: accept_alert",
+        &[r"I accept the alert", r"I accept the confirmation"],
+    ),
+    StepDefinition::new(
+        "dismiss_alert",
+        "Other",
+        "Step: dismiss_alert",
+        &[r"I dismiss the alert", r"I dismiss the confirmation"],
+    ),
+    StepDefinition::new(
+        "accept_prompt",
+        "Other",
+        "Step: accept_prompt",
+        &[r"I accept the prompt"],
+    ),
+    StepDefinition::new(
+        "dismiss_prompt",
+        "Other",
+        "Step: dismiss_prompt",
+        &[r"I dismiss the prompt"],
+    ),
+    StepDefinition::new(
+        "type_into_prompt",
+        "Other",
+        "Step: type_into_prompt",
+        &[r#"I type "([^"]+)" into the prompt"#],
+    ),
+    StepDefinition::new(
+        "alert_text_should_be",
+        "Other",
+        "Step: alert_text_should_be",
+        &[r#"the alert text should be "([^"]+)""#],
+    ),
+    // ===== FRAME/IFRAME =====
+    StepDefinition::new(
+        "switch_to_frame",
+        "Navigation",
+        "Switch to an iframe",
+        &[r#"I switch to frame "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "switch_to_default",
+        "Navigation",
+        "Step: switch_to_default",
+        &[r"I switch to default content"],
+    ),
+    StepDefinition::new(
+        "switch_to_parent_frame",
+        "Navigation",
+        "Step: switch_to_parent_frame",
+        &[r"I switch to parent frame"],
+    ),
+    // ===== WINDOW/TAB =====
+    StepDefinition::new(
+        "open_new_tab",
+        "Other",
+        "Step: open_new_tab",
+        &[r"I open a new tab", r"I open new window"],
+    ),
+    StepDefinition::new(
+        "switch_to_tab",
+        "Other",
+        "Step: switch_to_tab",
+        &[r"I switch to tab {int}"],
+    ),
+    StepDefinition::new(
+        "switch_to_window",
+        "Other",
+        "Step: switch_to_window",
+        &[r#"I switch to window "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "close_tab",
+        "Other",
+        "Step: close_tab",
+        &[r"I close the current tab", r"I close the current window"],
+    ),
+    // ===== CONTENT EXTRACTION =====
+    StepDefinition::new(
+        "extract_html",
+        "Other",
+        "Step: extract_html",
+        &[
+            r"I extract page HTML",
+            r"I extract the page HTML",
+            r"I extract the full page HTML",
+        ],
+    ),
+    StepDefinition::new(
+        "extract_links",
+        "Other",
+        "Step: extract_links",
+        &[
+            r"I extract all links from the page",
+            r"I extract all links",
+            r"I extract all hrefs",
+        ],
+    ),
+    StepDefinition::new(
+        "extract_images",
+        "Other",
+        "Step: extract_images",
+        &[
+            r"I extract all images from the page",
+            r"I extract all images",
+            r"I extract all image sources",
+        ],
+    ),
+    StepDefinition::new(
+        "extract_h1",
+        "Other",
+        "Step: extract_h1",
+        &[r"I extract all h1 headings"],
+    ),
+    StepDefinition::new(
+        "extract_h2",
+        "Other",
+        "Step: extract_h2",
+        &[r"I extract all h2 headings"],
+    ),
+    StepDefinition::new(
+        "extract_h3",
+        "Other",
+        "Step: extract_h3",
+        &[r"I extract all h3 headings"],
+    ),
+    StepDefinition::new(
+        "extract_h4",
+        "Other",
+        "Step: extract_h4",
+        &[r"I extract all h4 headings"],
+    ),
+    StepDefinition::new(
+        "extract_headings_level",
+        "Extraction",
+        "Extract headings by level",
+        &[r"I extract all h{int} headings"],
+    )
+    .yields_to(&["extract_h1", "extract_h2", "extract_h3", "extract_h4"]),
+    StepDefinition::new(
+        "extract_all_headings",
+        "Other",
+        "Step: extract_all_headings",
+        &[r"I extract all headings"],
+    ),
+    StepDefinition::new(
+        "extract_text_from_element",
+        "Other",
+        "Step: extract_text_from_element",
+        &[r#"I extract text from "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "extract_attribute_from_element",
+        "Other",
+        "Step: extract_attribute_from_element",
+        &[
+            r#"I extract "([^"]+)" from "([^"]+)""#,
+            r#"I extract the "([^"]+)" attribute from "([^"]+)""#,
+        ],
+    ),
+    StepDefinition::new(
+        "extract_all_by_selector",
+        "Other",
+        "Step: extract_all_by_selector",
+        &[r#"I extract all "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "extract_all_elements",
+        "Other",
+        "Step: extract_all_elements",
+        &[r#"I extract all "([^"]+)""#],
+    )
+    .yields_to(&["extract_all_by_selector"]),
+    // ===== SPECIALIZED EXTRACTION =====
+    StepDefinition::new(
+        "extract_titles",
+        "Other",
+        "Step: extract_titles",
+        &[
+            r"I extract post titles from the page",
+            r"I extract Hacker News titles",
+            r"I extract all article titles",
+        ],
+    ),
+    StepDefinition::new(
+        "extract_table",
+        "Other",
+        "Step: extract_table",
+        &[
+            r#"I extract table data from "([^"]+)""#,
+            r#"I extract the table "([^"]+)""#,
+        ],
+    ),
+    // ===== CONDITIONAL =====
+    StepDefinition::new(
+        "conditional_click_if_visible",
+        "Other",
+        "Step: conditional_click_if_visible",
+        &[r#"if "([^"]+)" is visible, I click it"#],
+    ),
+    StepDefinition::new(
+        "conditional_type_if_exists",
+        "Other",
+        "Step: conditional_type_if_exists",
+        &[r#"if "([^"]+)" exists, I type "([^"]+)" into it"#],
+    ),
+    StepDefinition::new(
+        "conditional_navigate",
+        "Other",
+        "Step: conditional_navigate",
+        &[r#"if the page contains "([^"]+)", I navigate to "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "skip_if_visible",
+        "Other",
+        "Step: skip_if_visible",
+        &[r#"skip the rest of the scenario if "([^"]+)" is (visible|present)"#],
+    ),
+    StepDefinition::new(
+        "continue_if_visible",
+        "Other",
+        "Step: continue_if_visible",
+        &[r#"continue only if "([^"]+)" is (visible|present)"#],
+    ),
+    // ===== LOOP =====
+    StepDefinition::new(
+        "loop_click_each",
+        "Other",
+        "Step: loop_click_each",
+        &[r#"for each "([^"]+)", I click it"#],
+    ),
+    StepDefinition::new(
+        "click_all",
+        "Other",
+        "Step: click_all",
+        &[r#"I click all "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "extract_and_store_all",
+        "Other",
+        "Step: extract_and_store_all",
+        &[r#"I extract all "([^"]+)" and store them as "([^"]+)""#],
+    ),
+    // ===== CLIPBOARD =====
+    StepDefinition::new(
+        "copy_to_clipboard",
+        "Other",
+        "Step: copy_to_clipboard",
+        &[r#"I copy "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "paste_from_clipboard",
+        "Other",
+        "Step: paste_from_clipboard",
+        &[r"I paste from clipboard"],
+    ),
+    StepDefinition::new(
+        "copy_element_text",
+        "Other",
+        "Step: copy_element_text",
+        &[r#"I copy the text of "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "paste_into",
+        "Other",
+        "Step: paste_into",
+        &[r#"I paste "([^"]+)" into "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "clipboard_should_contain",
+        "Other",
+        "Step: clipboard_should_contain",
+        &[r#"the clipboard should contain "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "clipboard_should_be_empty",
+        "Other",
+        "Step: clipboard_should_be_empty",
+        &[r"the clipboard should be empty"],
+    ),
+    // ===== MOUSE EVENTS =====
+    StepDefinition::new(
+        "mouse_down",
+        "Other",
+        "Step: mouse_down",
+        &[r#"I mouse down on "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "mouse_up",
+        "Other",
+        "Step: mouse_up",
+        &[r#"I mouse up on "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "mouse_move_to",
+        "Other",
+        "Step: mouse_move_to",
+        &[r#"I mouse move to "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "mouse_over",
+        "Other",
+        "Step: mouse_over",
+        &[r#"I mouse over "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "mouse_out",
+        "Other",
+        "Step: mouse_out",
+        &[r#"I mouse out of "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "drag_by_offset",
+        "Other",
+        "Step: drag_by_offset",
+        &[r#"I drag "([^"]+)" by offset (-?\d+),(-?\d+)""#],
+    ),
+    StepDefinition::new(
+        "drop_at",
+        "Other",
+        "Step: drop_at",
+        &[r#"I drop "([^"]+)" at "([^"]+)""#],
+    ),
+    // ===== TOUCH EVENTS =====
+    StepDefinition::new(
+        "touch_element",
+        "Other",
+        "Step: touch_element",
+        &[r#"I touch "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "swipe_elements",
+        "Other",
+        "Step: swipe_elements",
+        &[r#"I swipe "([^"]+)" to "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "pinch_zoom",
+        "Other",
+        "Step: pinch_zoom",
+        &[r#"I pinch to zoom in on "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "rotate_element",
+        "Other",
+        "Step: rotate_element",
+        &[r"I rotate {selector} by {int} degree(s)"],
+    ),
+    StepDefinition::new(
+        "multi_touch",
+        "Other",
+        "Step: multi_touch",
+        &[r#"I perform multi-touch gesture on "([^"]+)""#],
+    ),
+    // ===== FILE OPERATIONS =====
+    StepDefinition::new(
+        "download_file",
+        "Other",
+        "Step: download_file",
+        &[r#"I download file from "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "verify_download",
+        "Other",
+        "Step: verify_download",
+        &[r#"I verify file "([^"]+)" was downloaded"#],
+    ),
+    StepDefinition::new(
+        "wait_download_complete",
+        "Other",
+        "Step: wait_download_complete",
+        &[r"I wait for download to complete"],
+    ),
+    StepDefinition::new(
+        "download_filename_should_be",
+        "Other",
+        "Step: download_filename_should_be",
+        &[r#"the downloaded file should be named "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "save_file_as",
+        "Other",
+        "Step: save_file_as",
+        &[r#"I save file as "([^"]+)""#],
+    ),
+    // ===== AUDIO/VIDEO =====
+    StepDefinition::new(
+        "play_video",
+        "Other",
+        "Step: play_video",
+        &[r"I play video"],
+    ),
+    StepDefinition::new(
+        "pause_video",
+        "Other",
+        "Step: pause_video",
+        &[r"I pause video"],
+    ),
+    StepDefinition::new(
+        "stop_video",
+        "Other",
+        "Step: stop_video",
+        &[r"I stop video"],
+    ),
+    StepDefinition::new(
+        "mute_video",
+        "Other",
+        "Step: mute_video",
+        &[r"I mute video"],
+    ),
+    StepDefinition::new(
+        "unmute_video",
+        "Other",
+        "Step: unmute_video",
+        &[r"I unmute video"],
+    ),
+    StepDefinition::new(
+        "seek_video",
+        "Other",
+        "Step: seek_video",
+        &[r"I seek video to {float} second(s)"],
+    ),
+    StepDefinition::new(
+        "set_video_volume",
+        "Other",
+        "Step: set_video_volume",
+        &[r"I set video volume to {int}%"],
+    ),
+    StepDefinition::new(
+        "video_should_be_playing",
+        "Other",
+        "Step: video_should_be_playing",
+        &[r"the video should be playing"],
+    ),
+    StepDefinition::new(
+        "video_should_be_paused",
+        "Other",
+        "Step: video_should_be_paused",
+        &[r"the video should be paused"],
+    ),
+    StepDefinition::new(
+        "video_duration_check",
+        "Other",
+        "Step: video_duration_check",
+        &[r"the video duration should be at least {float} second(s)"],
+    ),
+    // ===== CANVAS =====
+    StepDefinition::new(
+        "get_canvas_data",
+        "Other",
+        "Step: get_canvas_data",
+        &[r#"I get canvas data from "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "draw_on_canvas",
+        "Other",
+        "Step: draw_on_canvas",
+        &[r#"I draw on canvas "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "clear_canvas",
+        "Other",
+        "Step: clear_canvas",
+        &[r#"I clear canvas "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "verify_canvas_pixel",
+        "Other",
+        "Step: verify_canvas_pixel",
+        &[r#"I verify canvas pixel at "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "canvas_width_check",
+        "Other",
+        "Step: canvas_width_check",
+        &[r"the canvas should have width {int}"],
+    ),
+    StepDefinition::new(
+        "canvas_height_check",
+        "Other",
+        "Step: canvas_height_check",
+        &[r"the canvas should have height {int}"],
+    ),
+    // ===== CONSOLE =====
+    StepDefinition::new(
+        "console_should_contain",
+        "Other",
+        "Step: console_should_contain",
+        &[r#"I should see console message "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "console_should_not_contain",
+        "Other",
+        "Step: console_should_not_contain",
+        &[r#"I should not see console message "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "console_should_have_error",
+        "Other",
+        "Step: console_should_have_error",
+        &[r"I should see console error"],
+    ),
+    StepDefinition::new(
+        "console_should_not_have_errors",
+        "Other",
+        "Step: console_should_not_have_errors",
+        &[r"I should not see console errors"],
+    ),
+    StepDefinition::new(
+        "clear_console",
+        "Other",
+        "Step: clear_console",
+        &[r"I clear console"],
+    ),
+    StepDefinition::new(
+        "get_console_log",
+        "Other",
+        "Step: get_console_log",
+        &[r"I get console log"],
+    ),
+    // ===== PERFORMANCE METRICS =====
+    StepDefinition::new(
+        "check_performance_metrics",
+        "Other",
+        "Step: check_performance_metrics",
+        &[r"I check performance metrics"],
+    ),
+    StepDefinition::new(
+        "lcp_should_be",
+        "Other",
+        "Step: lcp_should_be",
+        &[r"the LCP should be less than {int}ms"],
+    ),
+    StepDefinition::new(
+        "cls_should_be",
+        "Other",
+        "Step: cls_should_be",
+        &[r"the CLS should be less than {int}ms"],
+    ),
+    StepDefinition::new(
+        "fid_should_be",
+        "Other",
+        "Step: fid_should_be",
+        &[r"the FID should be less than {int}ms"],
+    ),
+    StepDefinition::new(
+        "tti_should_be",
+        "Other",
+        "Step: tti_should_be",
+        &[r"the TTI should be less than {int}ms"],
+    ),
+    StepDefinition::new(
+        "wait_stable_layout",
+        "Other",
+        "Step: wait_stable_layout",
+        &[r"I wait for stable layout"],
+    ),
+    // ===== NETWORK CONDITIONS =====
+    StepDefinition::new(
+        "simulate_slow_network",
+        "Other",
+        "Step: simulate_slow_network",
+        &[r"I simulate slow network"],
+    ),
+    StepDefinition::new(
+        "simulate_offline",
+        "Other",
+        "Step: simulate_offline",
+        &[r"I simulate offline mode"],
+    ),
+    StepDefinition::new(
+        "simulate_fast_network",
+        "Other",
+        "Step: simulate_fast_network",
+        &[r"I simulate fast network"],
+    ),
+    StepDefinition::new(
+        "disable_network",
+        "Other",
+        "Step: disable_network",
+        &[r"I disable network"],
+    ),
+    StepDefinition::new(
+        "enable_network",
+        "Other",
+        "Step: enable_network",
+        &[r"I enable network"],
+    ),
+    StepDefinition::new(
+        "network_should_be",
+        "Other",
+        "Step: network_should_be",
+        &[r"the network should be (online|offline)"],
+    ),
+    // ===== DEVICE EMULATION =====
+    StepDefinition::new(
+        "emulate_device",
+        "Other",
+        "Step: emulate_device",
+        &[r"I emulate device (?:iPhone|iPad|Pixel|Android)"],
+    ),
+    StepDefinition::new(
+        "emulate_mobile",
+        "Other",
+        "Step: emulate_mobile",
+        &[r"I emulate mobile viewport"],
+    ),
+    StepDefinition::new(
+        "emulate_tablet",
+        "Other",
+        "Step: emulate_tablet",
+        &[r"I emulate tablet viewport"],
+    ),
+    StepDefinition::new(
+        "emulate_desktop",
+        "Other",
+        "Step: emulate_desktop",
+        &[r"I emulate desktop viewport"],
+    ),
+    StepDefinition::new(
+        "set_device_pixel_ratio",
+        "Other",
+        "Step: set_device_pixel_ratio",
+        &[r"I set device pixel ratio (\d+)"],
+    ),
+    StepDefinition::new(
+        "set_viewport_size",
+        "Other",
+        "Step: set_viewport_size",
+        &[r"I set viewport to {int}x{int}"],
+    )
+    .yields_to(&["resize_window"]),
+    StepDefinition::new(
+        "rotate_landscape",
+        "Other",
+        "Step: rotate_landscape",
+        &[r"I rotate to landscape"],
+    ),
+    StepDefinition::new(
+        "rotate_portrait",
+        "Other",
+        "Step: rotate_portrait",
+        &[r"I rotate to portrait"],
+    ),
+    // ===== LOCAL STORAGE =====
+    StepDefinition::new(
+        "clear_local_storage",
+        "State",
+        "Step: clear_local_storage",
+        &[r"I clear local storage"],
+    ),
+    StepDefinition::new(
+        "set_local_storage",
+        "State",
+        "Set local storage value",
+        &[r#"I set local storage item "([^"]+)" to "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "get_local_storage",
+        "State",
+        "Get local storage value",
+        &[r#"I get local storage item "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "local_storage_should_contain",
+        "Other",
+        "Step: local_storage_should_contain",
+        &[r#"the local storage should contain "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "remove_local_storage",
+        "Other",
+        "Step: remove_local_storage",
+        &[r#"I remove local storage item "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "local_storage_should_be_empty",
+        "State",
+        "Step: local_storage_should_be_empty",
+        &[r"the local storage should be empty"],
+    ),
+    StepDefinition::new(
+        "local_storage_count_check",
+        "State",
+        "Step: local_storage_count_check",
+        &[r"the local storage should have {int} items"],
+    ),
+    // ===== SESSION STORAGE =====
+    StepDefinition::new(
+        "clear_session_storage",
+        "State",
+        "Step: clear_session_storage",
+        &[r"I clear session storage"],
+    ),
+    StepDefinition::new(
+        "set_session_storage",
+        "State",
+        "Set session storage value",
+        &[r#"I set session storage item "([^"]+)" to "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "get_session_storage",
+        "State",
+        "Get session storage value",
+        &[r#"I get session storage item "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "remove_session_storage",
+        "Other",
+        "Step: remove_session_storage",
+        &[r#"I remove session storage item "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "session_storage_should_be_empty",
+        "State",
+        "Step: session_storage_should_be_empty",
+        &[r"the session storage should be empty"],
+    ),
+    // ===== INDEXEDDB =====
+    StepDefinition::new(
+        "check_indexeddb_exists",
+        "Other",
+        "Step: check_indexeddb_exists",
+        &[r"I check IndexedDB exists"],
+    ),
+    StepDefinition::new(
+        "get_indexeddb_count",
+        "Other",
+        "Step: get_indexeddb_count",
+        &[r"I get IndexedDB entry count"],
+    ),
+    StepDefinition::new(
+        "clear_indexeddb",
+        "Other",
+        "Step: clear_indexeddb",
+        &[r"I clear IndexedDB"],
+    ),
+    StepDefinition::new(
+        "indexeddb_count_check",
+        "Other",
+        "Step: indexeddb_count_check",
+        &[r"the IndexedDB should have {int} entries"],
+    ),
+    // ===== SERVICE WORKER =====
+    StepDefinition::new(
+        "wait_service_worker",
+        "Other",
+        "Step: wait_service_worker",
+        &[r"I wait for Service Worker to activate"],
+    ),
+    StepDefinition::new(
+        "service_worker_should_be_active",
+        "Other",
+        "Step: service_worker_should_be_active",
+        &[r"the Service Worker should be active"],
+    ),
+    StepDefinition::new(
+        "unregister_service_worker",
+        "Other",
+        "Step: unregister_service_worker",
+        &[r"I unregister Service Worker"],
+    ),
+    StepDefinition::new(
+        "clear_service_worker_cache",
+        "Other",
+        "Step: clear_service_worker_cache",
+        &[r"I clear Service Worker cache"],
+    ),
+    // ===== WEB MANIFEST =====
+    StepDefinition::new(
+        "check_web_manifest",
+        "Other",
+        "Step: check_web_manifest",
+        &[r"I check web manifest"],
+    ),
+    StepDefinition::new(
+        "manifest_name_check",
+        "Other",
+        "Step: manifest_name_check",
+        &[r#"the manifest should have name "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "manifest_short_name_check",
+        "Other",
+        "Step: manifest_short_name_check",
+        &[r#"the manifest should have short name "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "verify_manifest_theme",
+        "Other",
+        "Step: verify_manifest_theme",
+        &[r"I verify manifest color theme"],
+    ),
+    // ===== SECURITY HEADERS =====
+    StepDefinition::new(
+        "check_csp_headers",
+        "Other",
+        "Step: check_csp_headers",
+        &[r"I check CSP headers"],
+    ),
+    StepDefinition::new(
+        "check_hsts_header",
+        "Other",
+        "Step: check_hsts_header",
+        &[r"I check HSTS header"],
+    ),
+    StepDefinition::new(
+        "security_headers_check",
+        "Other",
+        "Step: security_headers_check",
+        &[r"the response should have security headers"],
+    ),
+    StepDefinition::new(
+        "verify_https_certificate",
+        "Other",
+        "Step: verify_https_certificate",
+        &[r"I verify HTTPS certificate"],
+    ),
+    // ===== COOKIES ADVANCED =====
+    StepDefinition::new(
+        "check_secure_cookies",
+        "Other",
+        "Step: check_secure_cookies",
+        &[r"I check for secure cookies"],
+    ),
+    StepDefinition::new(
+        "all_cookies_should_be_secure",
+        "Other",
+        "Step: all_cookies_should_be_secure",
+        &[r"all cookies should be secure"],
+    ),
+    StepDefinition::new(
+        "check_same_site_cookies",
+        "Other",
+        "Step: check_same_site_cookies",
+        &[r"I check for same-site cookies"],
+    ),
+    StepDefinition::new(
+        "set_cookie_samesite",
+        "Other",
+        "Step: set_cookie_samesite",
+        &[r"I set cookie with SameSite"],
+    ),
+    StepDefinition::new(
+        "set_cookie_httponly",
+        "Other",
+        "Step: set_cookie_httponly",
+        &[r"I set cookie with HttpOnly flag"],
+    ),
+    StepDefinition::new(
+        "set_cookie_secure",
+        "Other",
+        "Step: set_cookie_secure",
+        &[r"I set cookie with Secure flag"],
+    ),
+    // ===== GEOLOCATION =====
+    StepDefinition::new(
+        "mock_geolocation",
+        "Other",
+        "Step: mock_geolocation",
+        &[r#"I mock geolocation to "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "set_geolocation_coords",
+        "Other",
+        "Step: set_geolocation_coords",
+        &[r"I set geolocation to latitude {float} longitude {float}"],
+    ),
+    StepDefinition::new(
+        "clear_geolocation_mock",
+        "Other",
+        "Step: clear_geolocation_mock",
+        &[r"I clear geolocation mock"],
+    ),
+    StepDefinition::new(
+        "check_geolocation_permission",
+        "Other",
+        "Step: check_geolocation_permission",
+        &[r"I check geolocation permission"],
+    ),
+    // ===== NOTIFICATIONS =====
+    StepDefinition::new(
+        "request_notification_permission",
+        "Other",
+        "Step: request_notification_permission",
+        &[r"I request notification permission"],
+    ),
+    StepDefinition::new(
+        "grant_notification_permission",
+        "Other",
+        "Step: grant_notification_permission",
+        &[r"I grant notification permission"],
+    ),
+    StepDefinition::new(
+        "deny_notification_permission",
+        "Other",
+        "Step: deny_notification_permission",
+        &[r"I deny notification permission"],
+    ),
+    StepDefinition::new(
+        "should_see_notification",
+        "Other",
+        "Step: should_see_notification",
+        &[r#"I should see notification "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "notification_visibility_check",
+        "Other",
+        "Step: notification_visibility_check",
+        &[r"the notification should be (visible|hidden)"],
+    ),
+    // ===== WEBSOCKET =====
+    StepDefinition::new(
+        "connect_websocket",
+        "Other",
+        "Step: connect_websocket",
+        &[r#"I connect to WebSocket at "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "disconnect_websocket",
+        "Other",
+        "Step: disconnect_websocket",
+        &[r"I disconnect WebSocket"],
+    ),
+    StepDefinition::new(
+        "send_websocket_message",
+        "Other",
+        "Step: send_websocket_message",
+        &[r#"I send WebSocket message "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "should_receive_websocket_message",
+        "Other",
+        "Step: should_receive_websocket_message",
+        &[r#"I should receive WebSocket message "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "websocket_should_be_connected",
+        "Other",
+        "Step: websocket_should_be_connected",
+        &[r"the WebSocket should be connected"],
+    ),
+    // ===== MEDIA STREAM =====
+    StepDefinition::new(
+        "start_camera",
+        "Other",
+        "Step: start_camera",
+        &[r"I start camera"],
+    ),
+    StepDefinition::new(
+        "stop_camera",
+        "Other",
+        "Step: stop_camera",
+        &[r"I stop camera"],
+    ),
+    StepDefinition::new(
+        "start_microphone",
+        "Other",
+        "Step: start_microphone",
+        &[r"I start microphone"],
+    ),
+    StepDefinition::new(
+        "stop_microphone",
+        "Other",
+        "Step: stop_microphone",
+        &[r"I stop microphone"],
+    ),
+    StepDefinition::new(
+        "check_camera_permission",
+        "Other",
+        "Step: check_camera_permission",
+        &[r"I check camera permission"],
+    ),
+    StepDefinition::new(
+        "check_microphone_permission",
+        "Other",
+        "Step: check_microphone_permission",
+        &[r"I check microphone permission"],
+    ),
+    StepDefinition::new(
+        "should_see_camera_stream",
+        "Other",
+        "Step: should_see_camera_stream",
+        &[r"I should see camera stream"],
+    ),
+    StepDefinition::new(
+        "should_see_microphone_stream",
+        "Other",
+        "Step: should_see_microphone_stream",
+        &[r"I should see microphone stream"],
+    ),
+    // ===== WEBGL =====
+    StepDefinition::new(
+        "check_webgl_support",
+        "Other",
+        "Step: check_webgl_support",
+        &[r"I check WebGL support"],
+    ),
+    StepDefinition::new(
+        "get_webgl_renderer",
+        "Other",
+        "Step: get_webgl_renderer",
+        &[r"I get WebGL renderer"],
+    ),
+    StepDefinition::new(
+        "set_webgl_context",
+        "Other",
+        "Step: set_webgl_context",
+        &[r#"I set WebGL context to "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "webgl_context_check",
+        "Other",
+        "Step: webgl_context_check",
+        &[r#"the WebGL should have context "([^"]+)""#],
+    ),
+    // ===== ANIMATION =====
+    StepDefinition::new(
+        "wait_animation_complete",
+        "Other",
+        "Step: wait_animation_complete",
+        &[r"I wait for animation to complete"],
+    ),
+    StepDefinition::new(
+        "should_see_animation",
+        "Other",
+        "Step: should_see_animation",
+        &[r#"I should see animation "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "pause_animation",
+        "Other",
+        "Step: pause_animation",
+        &[r#"I pause animation "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "resume_animation",
+        "Other",
+        "Step: resume_animation",
+        &[r#"I resume animation "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "cancel_animation",
+        "Other",
+        "Step: cancel_animation",
+        &[r#"I cancel animation "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "animation_should_be_running",
+        "Other",
+        "Step: animation_should_be_running",
+        &[r"the animation should be running"],
+    ),
+    // ===== PRINT =====
+    StepDefinition::new(
+        "print_page",
+        "Other",
+        "Step: print_page",
+        &[r"I print page"],
+    ),
+    StepDefinition::new(
+        "print_to_pdf",
+        "Other",
+        "Step: print_to_pdf",
+        &[r"I print to PDF"],
+    ),
+    StepDefinition::new(
+        "set_print_layout",
+        "Other",
+        "Step: set_print_layout",
+        &[r#"I set print layout to "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "print_preview_check",
+        "Other",
+        "Step: print_preview_check",
+        &[r"the print preview should be visible"],
+    ),
+    // ===== SELECTION RANGES =====
+    StepDefinition::new(
+        "select_text_range",
+        "Other",
+        "Step: select_text_range",
+        &[r#"I select text from "([^"]+)" to "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "select_all_text_in_element",
+        "Other",
+        "Step: select_all_text_in_element",
+        &[r#"I select all text in "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "clear_selection_in_element",
+        "Other",
+        "Step: clear_selection_in_element",
+        &[r#"I clear selection in "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "copy_selection_to_clipboard",
+        "Other",
+        "Step: copy_selection_to_clipboard",
+        &[r"I copy selection to clipboard"],
+    ),
+    // ===== DRAG AND DROP ENHANCED =====
+    StepDefinition::new(
+        "drag_to_coordinates",
+        "Other",
+        "Step: drag_to_coordinates",
+        &[r#"I drag "([^"]+)" to coordinates (\d+),(\d+)""#],
+    ),
+    StepDefinition::new(
+        "hold_drag",
+        "Other",
+        "Step: hold_drag",
+        &[r#"I hold drag on "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "release_drag",
+        "Other",
+        "Step: release_drag",
+        &[r#"I release drag on "([^"]+)""#],
+    ),
+    // ===== SPELL CHECK =====
+    StepDefinition::new(
+        "check_spelling",
+        "Other",
+        "Step: check_spelling",
+        &[r#"I check spelling of "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "no_spelling_errors",
+        "Other",
+        "Step: no_spelling_errors",
+        &[r"the text should have no spelling errors"],
+    ),
+    StepDefinition::new(
+        "enable_spell_check",
+        "Other",
+        "Step: enable_spell_check",
+        &[r"I enable spell checking"],
+    ),
+    StepDefinition::new(
+        "disable_spell_check",
+        "Other",
+        "Step: disable_spell_check",
+        &[r"I disable spell checking"],
+    ),
+    // ===== AUTO-COMPLETE =====
+    StepDefinition::new(
+        "should_see_autocomplete",
+        "Other",
+        "Step: should_see_autocomplete",
+        &[r#"I should see autocomplete suggestions for "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "select_autocomplete_suggestion",
+        "Other",
+        "Step: select_autocomplete_suggestion",
+        &[r#"I select autocomplete suggestion "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "close_autocomplete",
+        "Other",
+        "Step: close_autocomplete",
+        &[r"I close autocomplete"],
+    ),
+    StepDefinition::new(
+        "autocomplete_should_be_visible",
+        "Other",
+        "Step: autocomplete_should_be_visible",
+        &[r"the autocomplete should be visible"],
+    ),
+    // ===== MODAL =====
+    StepDefinition::new(
+        "wait_modal_appear",
+        "Other",
+        "Step: wait_modal_appear",
+        &[r"I wait for modal to appear"],
+    ),
+    StepDefinition::new(
+        "should_see_modal",
+        "Other",
+        "Step: should_see_modal",
+        &[r"I should see modal"],
+    ),
+    StepDefinition::new(
+        "should_not_see_modal",
+        "Other",
+        "Step: should_not_see_modal",
+        &[r"I should not see modal"],
+    ),
+    StepDefinition::new(
+        "close_modal",
+        "Other",
+        "Step: close_modal",
+        &[r"I close modal"],
+    ),
+    StepDefinition::new(
+        "close_all_modals",
+        "Other",
+        "Step: close_all_modals",
+        &[r"I close all modals"],
+    ),
+    StepDefinition::new(
+        "modal_should_be_dismissible",
+        "Other",
+        "Step: modal_should_be_dismissible",
+        &[r"the modal should be dismissible"],
+    ),
+    // ===== TOOLTIP =====
+    StepDefinition::new(
+        "hover_show_tooltip",
+        "Other",
+        "Step: hover_show_tooltip",
+        &[r#"I hover over "([^"]+)" to show tooltip"#],
+    ),
+    StepDefinition::new(
+        "should_see_tooltip",
+        "Other",
+        "Step: should_see_tooltip",
+        &[r#"I should see tooltip "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "tooltip_should_contain",
+        "Other",
+        "Step: tooltip_should_contain",
+        &[r#"the tooltip should contain "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "verify_tooltip_position",
+        "Other",
+        "Step: verify_tooltip_position",
+        &[r"I verify tooltip position"],
+    ),
+    // ===== PROGRESS BAR =====
+    StepDefinition::new(
+        "wait_progress_complete",
+        "Other",
+        "Step: wait_progress_complete",
+        &[r"I wait for progress bar to complete"],
+    ),
+    StepDefinition::new(
+        "progress_at_least",
+        "Other",
+        "Step: progress_at_least",
+        &[r"the progress bar should be at least {int}%"],
+    ),
+    StepDefinition::new(
+        "progress_at_most",
+        "Other",
+        "Step: progress_at_most",
+        &[r"the progress bar should be at most {int}%"],
+    ),
+    StepDefinition::new(
+        "progress_state_check",
+        "Other",
+        "Step: progress_state_check",
+        &[r"the progress should be (?:indeterminate|determinate)"],
+    ),
+    // ===== TABS/ACCORDION =====
+    StepDefinition::new(
+        "activate_tab",
+        "Other",
+        "Step: activate_tab",
+        &[r#"I activate tab "{int}""#],
+    ),
+    StepDefinition::new(
+        "deactivate_tab",
+        "Other",
+        "Step: deactivate_tab",
+        &[r#"I deactivate tab "{int}""#],
+    ),
+    StepDefinition::new(
+        "reorder_tabs",
+        "Other",
+        "Step: reorder_tabs",
+        &[r"I reorder tabs"],
+    ),
+    StepDefinition::new("pin_tab", "Other", "Step: pin_tab", &[r"I pin tab"]),
+    StepDefinition::new("unpin_tab", "Other", "Step: unpin_tab", &[r"I unpin tab"]),
+    StepDefinition::new(
+        "should_see_active_tab",
+        "Other",
+        "Step: should_see_active_tab",
+        &[r#"I should see active tab "{int}""#],
+    ),
+    // ===== SIDEBAR =====
+    StepDefinition::new(
+        "open_sidebar",
+        "Other",
+        "Step: open_sidebar",
+        &[r"I open sidebar"],
+    ),
+    StepDefinition::new(
+        "close_sidebar",
+        "Other",
+        "Step: close_sidebar",
+        &[r"I close sidebar"],
+    ),
+    StepDefinition::new(
+        "toggle_sidebar",
+        "Other",
+        "Step: toggle_sidebar",
+        &[r"I toggle sidebar"],
+    ),
+    StepDefinition::new(
+        "sidebar_should_be_visible",
+        "Other",
+        "Step: sidebar_should_be_visible",
+        &[r"the sidebar should be visible"],
+    ),
+    StepDefinition::new(
+        "sidebar_should_be_collapsed",
+        "Other",
+        "Step: sidebar_should_be_collapsed",
+        &[r"the sidebar should be collapsed"],
+    ),
+    // ===== BREADCRUMB =====
+    StepDefinition::new(
+        "should_see_breadcrumb",
+        "Other",
+        "Step: should_see_breadcrumb",
+        &[r#"I should see breadcrumb "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "click_breadcrumb",
+        "Other",
+        "Step: click_breadcrumb",
+        &[r#"I click breadcrumb "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "breadcrumb_should_be_clickable",
+        "Other",
+        "Step: breadcrumb_should_be_clickable",
+        &[r"the breadcrumb should be clickable"],
+    ),
+    StepDefinition::new(
+        "breadcrumb_count_check",
+        "Other",
+        "Step: breadcrumb_count_check",
+        &[r"the breadcrumb should contain {int} items"],
+    ),
+    // ===== SEARCH =====
+    StepDefinition::new(
+        "focus_search_box",
+        "Other",
+        "Step: focus_search_box",
+        &[r"I focus search box"],
+    ),
+    StepDefinition::new(
+        "type_in_search_box",
+        "Other",
+        "Step: type_in_search_box",
+        &[r#"I type in search box "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "clear_search_box",
+        "Other",
+        "Step: clear_search_box",
+        &[r"I clear search box"],
+    ),
+    StepDefinition::new(
+        "submit_search",
+        "Other",
+        "Step: submit_search",
+        &[r"I submit search"],
+    ),
+    StepDefinition::new(
+        "should_see_search_results",
+        "Other",
+        "Step: should_see_search_results",
+        &[r"I should see search results"],
+    ),
+    StepDefinition::new(
+        "should_see_search_result_count",
+        "Other",
+        "Step: should_see_search_result_count",
+        &[r"I should see {int} search results"],
+    ),
+    // ===== PAGINATION =====
+    StepDefinition::new(
+        "click_next_page",
+        "Other",
+        "Step: click_next_page",
+        &[r"I click next page"],
+    ),
+    StepDefinition::new(
+        "click_previous_page",
+        "Other",
+        "Step: click_previous_page",
+        &[r"I click previous page"],
+    ),
+    StepDefinition::new(
+        "go_to_page_number",
+        "Other",
+        "Step: go_to_page_number",
+        &[r"I go to page {int}"],
+    ),
+    StepDefinition::new(
+        "should_see_page_indicator",
+        "Other",
+        "Step: should_see_page_indicator",
+        &[r"I should see page indicator"],
+    ),
+    StepDefinition::new(
+        "page_indicator_should_show",
+        "Other",
+        "Step: page_indicator_should_show",
+        &[r"the page indicator should show page {int}"],
+    ),
+    // ===== FILTER =====
+    StepDefinition::new(
+        "apply_filter",
+        "Other",
+        "Step: apply_filter",
+        &[r#"I apply filter "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "clear_all_filters",
+        "Other",
+        "Step: clear_all_filters",
+        &[r"I clear all filters"],
+    ),
+    StepDefinition::new(
+        "select_filter_option",
+        "Other",
+        "Step: select_filter_option",
+        &[r#"I select filter option "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "should_see_active_filter",
+        "Other",
+        "Step: should_see_active_filter",
+        &[r#"I should see active filter "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "active_filter_count_check",
+        "Other",
+        "Step: active_filter_count_check",
+        &[r"I should see {int} active filters"],
+    ),
+    // ===== SORTING =====
+    StepDefinition::new(
+        "sort_by",
+        "Other",
+        "Step: sort_by",
+        &[r#"I sort by "([^"]+)" in (?:ascending|descending) order"#],
+    ),
+    StepDefinition::new(
+        "reverse_sort_order",
+        "Other",
+        "Step: reverse_sort_order",
+        &[r"I reverse sort order"],
+    ),
+    StepDefinition::new(
+        "click_sort_by",
+        "Other",
+        "Step: click_sort_by",
+        &[r#"I click sort by "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "should_be_sorted_by",
+        "Other",
+        "Step: should_be_sorted_by",
+        &[r#"the items should be sorted by "([^"]+)""#],
+    ),
+    // ===== INFINITE SCROLL =====
+    StepDefinition::new(
+        "scroll_to_bottom",
+        "Scrolling",
+        "Step: scroll_to_bottom",
+        &[r"I scroll to bottom"],
+    )
+    .yields_to(&["scroll_bottom"]),
+    StepDefinition::new(
+        "scroll_to_top",
+        "Scrolling",
+        "Step: scroll_to_top",
+        &[r"I scroll to top"],
+    )
+    .yields_to(&["scroll_top"]),
+    StepDefinition::new(
+        "scroll_indefinitely",
+        "Scrolling",
+        "Step: scroll_indefinitely",
+        &[r"I scroll indefinitely"],
+    ),
+    StepDefinition::new(
+        "stop_scrolling",
+        "Scrolling",
+        "Step: stop_scrolling",
+        &[r"I stop scrolling"],
+    ),
+    // ===== LAZY LOADING =====
+    StepDefinition::new(
+        "scroll_trigger_lazy_load",
+        "Scrolling",
+        "Step: scroll_trigger_lazy_load",
+        &[r"I scroll to trigger lazy load"],
+    ),
+    StepDefinition::new(
+        "wait_lazy_loaded",
+        "Scrolling",
+        "Step: wait_lazy_loaded",
+        &[r"I wait for lazy loaded items to appear"],
+    ),
+    StepDefinition::new(
+        "lazy_loaded_item_count",
+        "Scrolling",
+        "Step: lazy_loaded_item_count",
+        &[r"I should see {int} lazy loaded items"],
+    ),
+    // ===== VIRTUAL SCROLL =====
+    StepDefinition::new(
+        "scroll_to_percentage",
+        "Scrolling",
+        "Scroll to percentage",
+        &[r"I scroll to position {int}%"],
+    ),
+    StepDefinition::new(
+        "scroll_position_check",
+        "Scrolling",
+        "Check scroll position",
+        &[r"I should see scroll position {int}%"],
+    ),
+    // ===== HREFLANG =====
+    StepDefinition::new(
+        "set_document_lang",
+        "Other",
+        "Step: set_document_lang",
+        &[r#"I set document language to "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "check_document_lang",
+        "Other",
+        "Step: check_document_lang",
+        &[r"I check document language"],
+    ),
+    StepDefinition::new(
+        "document_lang_attribute_check",
+        "Other",
+        "Step: document_lang_attribute_check",
+        &[r"the document should have language attribute"],
+    ),
+    // ===== METATAG =====
+    StepDefinition::new(
+        "check_meta_tag",
+        "Other",
+        "Step: check_meta_tag",
+        &[r#"I check for meta "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "meta_description_check",
+        "Other",
+        "Step: meta_description_check",
+        &[r#"the meta description should be "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "meta_keywords_check",
+        "Other",
+        "Step: meta_keywords_check",
+        &[r#"the meta keywords should contain "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "meta_robots_check",
+        "Other",
+        "Step: meta_robots_check",
+        &[r#"the meta robots should be "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "meta_viewport_check",
+        "Other",
+        "Step: meta_viewport_check",
+        &[r#"the meta viewport should be "([^"]+)""#],
+    ),
+    // ===== LINK RELATIONS =====
+    StepDefinition::new(
+        "check_canonical_url",
+        "Other",
+        "Step: check_canonical_url",
+        &[r"I check for canonical URL"],
+    ),
+    StepDefinition::new(
+        "canonical_url_check",
+        "Other",
+        "Step: canonical_url_check",
+        &[r#"the canonical URL should be "([^"]+)""#],
+    ),
+    StepDefinition::new(
+        "check_alternate_urls",
+        "Other",
+        "Step: check_alternate_urls",
+        &[r"I check for alternate URLs"],
+    ),
+    StepDefinition::new(
+        "check_next_prev_links",
+        "Other",
+        "Step: check_next_prev_links",
+        &[r"I check for next/prev links"],
+    ),
+    // ===== OPENSEARCH =====
+    StepDefinition::new(
+        "enable_opensearch",
+        "Other",
+        "Step: enable_opensearch",
+        &[r"I enable open search"],
+    ),
+    StepDefinition::new(
+        "disable_opensearch",
+        "Other",
+        "Step: disable_opensearch",
+        &[r"I disable open search"],
+    ),
+    StepDefinition::new(
+        "check_opensearch",
+        "Other",
+        "Step: check_opensearch",
+        &[r"I check for open search"],
+    ),
+    // ===== RSS/FEED =====
+    StepDefinition::new(
+        "check_rss_feed",
+        "Other",
+        "Step: check_rss_feed",
+        &[r"I check for RSS feed"],
+    ),
+    StepDefinition::new(
+        "should_see_rss_link",
+        "Other",
+        "Step: should_see_rss_link",
+        &[r"I should see RSS feed link"],
+    ),
+    StepDefinition::new(
+        "verify_rss_feed_valid",
+        "Other",
+        "Step: verify_rss_feed_valid",
+        &[r"I verify RSS feed is valid"],
+    ),
+    // ===== PWA =====
+    StepDefinition::new(
+        "check_pwa_installable",
+        "Other",
+        "Step: check_pwa_installable",
+        &[r"I check PWA is installable"],
+    ),
+    StepDefinition::new(
+        "install_pwa",
+        "Other",
+        "Step: install_pwa",
+        &[r"I install PWA"],
+    ),
+    StepDefinition::new(
+        "uninstall_pwa",
+        "Other",
+        "Step: uninstall_pwa",
+        &[r"I uninstall PWA"],
+    ),
+    StepDefinition::new(
+        "pwa_should_be_installed",
+        "Other",
+        "Step: pwa_should_be_installed",
+        &[r"the PWA should be installed"],
+    ),
+    // ===== WORKER =====
+    StepDefinition::new(
+        "check_web_worker",
+        "Other",
+        "Step: check_web_worker",
+        &[r"I check for Web Worker"],
+    ),
+    StepDefinition::new(
+        "web_worker_should_be_active",
+        "Other",
+        "Step: web_worker_should_be_active",
+        &[r"the Web Worker should be active"],
+    ),
+    // ===== CONDITIONAL =====
+    StepDefinition::new(
+        "retry_click",
+        "Other",
+        "Step: retry_click",
+        &[
+            r"I retry clicking {selector} up to {int} times",
+            r"I retry {selector} {int} times",
+        ],
+    ),
+    StepDefinition::new(
+        "wait_with_timeout",
+        "Other",
+        "Step: wait_with_timeout",
+        &[r"I wait for {selector} with timeout of {int} seconds"],
+    ),
+];
+
+/// Look up a built-in step definition by id
+pub fn find_definition(id: &str) -> Option<&'static StepDefinition> {
+    STEP_DEFINITIONS.iter().find(|d| d.id == id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::discovery::catalog::build_step_catalog;
+    use crate::runner::build_step_registry;
+    use std::collections::{BTreeSet, HashMap, HashSet};
+
+    #[test]
+    fn test_definitions_are_unique_and_compile() {
+        let mut ids = HashSet::new();
+        for definition in STEP_DEFINITIONS {
+            assert!(
+                ids.insert(definition.id),
+                "duplicate step id {}",
+                definition.id
+            );
+            assert!(
+                !definition.patterns.is_empty(),
+                "{} has no patterns",
+                definition.id
+            );
+            for pattern in definition.patterns {
+                assert!(
+                    crate::discovery::expression::StepPattern::compile(pattern).is_ok(),
+                    "bad pattern {}",
+                    pattern
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_registry_and_catalog_do_not_drift() {
+        let mut registered: HashMap<String, BTreeSet<String>> = HashMap::new();
+        for (pattern, name) in build_step_registry().patterns() {
+            registered
+                .entry(name.to_string())
+                .or_default()
+                .insert(pattern.to_string());
+        }
+
+        let catalog = build_step_catalog();
+        let mut cataloged: HashMap<String, BTreeSet<String>> = HashMap::new();
+        for step in catalog.all_steps() {
+            let patterns = cataloged.entry(step.id.clone()).or_default();
+            patterns.insert(step.pattern.clone());
+            patterns.extend(step.aliases.iter().cloned());
+        }
+
+        assert_eq!(registered, cataloged);
+        assert_eq!(catalog.total_steps(), STEP_DEFINITIONS.len());
+    }
+
+    #[test]
+    fn test_fill_form_metadata() {
+        let info = find_definition("fill_form").unwrap().to_step_info();
+        assert_eq!(info.pattern, "I fill the form with:");
+        assert_eq!(info.aliases, vec!["I fill in the form with:".to_string()]);
+        assert_eq!(info.parameters[0].param_type, "data_table");
+        assert_eq!(info.examples.len(), 1);
+    }
+}
@@ -0,0 +1,502 @@
+// Cucumber Expressions and typed parameters for step patterns
+use super::catalog::ParameterInfo;
+use super::definitions::find_definition;
+use regex::Regex;
+
+/// Type of a step parameter, from a Cucumber Expression or inferred from a regex group
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParameterType {
+    Int,
+    Float,
+    Word,
+    String,
+    /// A quoted CSS selector
+    Selector,
+    /// `{}`: any text
+    Anonymous,
+}
+
+impl ParameterType {
+    /// Parameter type for a `{name}` in an expression
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "int" => Some(ParameterType::Int),
+            "float" => Some(ParameterType::Float),
+            "word" => Some(ParameterType::Word),
+            "string" => Some(ParameterType::String),
+            "selector" => Some(ParameterType::Selector),
+            "" => Some(ParameterType::Anonymous),
+            _ => None,
+        }
+    }
+
+    /// Name reported as `ParameterInfo.param_type`
+    pub fn name(self) -> &'static str {
+        match self {
+            ParameterType::Int => "int",
+            ParameterType::Float => "float",
+            ParameterType::Word => "word",
+            ParameterType::String => "string",
+            ParameterType::Selector => "selector",
+            ParameterType::Anonymous => "any",
+        }
+    }
+
+    fn regex(self) -> &'static str {
+        match self {
+            ParameterType::Int => r"(-?\d+)",
+            ParameterType::Float => r"(-?(?:\d+\.?\d*|\.\d+))",
+            ParameterType::Word => r"([^\s]+)",
+            ParameterType::String | ParameterType::Selector => r#"(?:"([^"]*)"|'([^']*)')"#,
+            ParameterType::Anonymous => r"(.*)",
+        }
+    }
+
+    /// Regex accepting any value in this position, used to explain type mismatches
+    fn loose_regex(self) -> &'static str {
+        match self {
+            ParameterType::Int | ParameterType::Float | ParameterType::Word => r"(.+?)",
+            _ => self.regex(),
+        }
+    }
+
+    /// Check that a captured value converts to this type
+    pub fn check(self, value: &str) -> Result<(), String> {
+        let ok = match self {
+            ParameterType::Int => value.parse::<i64>().is_ok(),
+            ParameterType::Float => value.parse::<f64>().is_ok(),
+            ParameterType::Word => !value.is_empty() && !value.contains(char::is_whitespace),
+            _ => true,
+        };
+        if ok {
+            Ok(())
+        } else {
+            Err(format!("expected {}, got \"{}\"", self.describe(), value))
+        }
+    }
+
+    fn describe(self) -> &'static str {
+        match self {
+            ParameterType::Int => "an integer",
+            ParameterType::Float => "a number",
+            ParameterType::Word => "a single word",
+            ParameterType::String => "a quoted string",
+            ParameterType::Selector => "a quoted CSS selector",
+            ParameterType::Anonymous => "any text",
+        }
+    }
+}
+
+/// A step pattern compiled from either a Cucumber Expression or a raw regex
+#[derive(Debug, Clone)]
+pub struct StepPattern {
+    pub source: String,
+    pub regex: Regex,
+    /// Pattern with typed parameters relaxed, set for expressions with typed parameters
+    loose: Option<Regex>,
+    pub parameters: Vec<ParameterType>,
+    /// Whether each parameter must be present
+    required: Vec<bool>,
+}
+
+impl StepPattern {
+    /// Compile a pattern; it is a Cucumber Expression when it contains `{type}` placeholders
+    pub fn compile(pattern: &str) -> Result<Self, String> {
+        if is_cucumber_expression(pattern) {
+            compile_expression(pattern)
+        } else {
+            let regex = Regex::new(pattern).map_err(|e| e.to_string())?;
+            let (parameters, required) = infer_regex_parameters(pattern).into_iter().unzip();
+            Ok(StepPattern {
+                source: pattern.to_string(),
+                regex,
+                loose: None,
+                parameters,
+                required,
+            })
+        }
+    }
+
+    /// Captured parameter values, or `None` if the text does not match
+    pub fn captures(&self, text: &str) -> Option<Vec<String>> {
+        self.regex.captures(text).map(|caps| {
+            caps.iter()
+                .skip(1)
+                .filter_map(|c| c.map(|m| m.as_str().to_string()))
+                .collect()
+        })
+    }
+
+    /// Whether the pattern matches the whole text rather than part of it
+    pub fn is_exact_match(&self, text: &str) -> bool {
+        self.regex
+            .find(text)
+            .is_some_and(|m| m.start() == 0 && m.end() == text.len())
+    }
+
+    /// Explain why text that fits the pattern's shape has badly typed parameters
+    pub fn explain_mismatch(&self, text: &str) -> Option<String> {
+        let values = self.loose.as_ref()?.captures(text).map(|caps| {
+            caps.iter()
+                .skip(1)
+                .filter_map(|c| c.map(|m| m.as_str().to_string()))
+                .collect::<Vec<_>>()
+        })?;
+        self.parameters
+            .iter()
+            .zip(&values)
+            .enumerate()
+            .find_map(|(idx, (param, value))| {
+                param
+                    .check(value)
+                    .err()
+                    .map(|e| format!("parameter {} ({{{}}}) {}", idx + 1, param.name(), e))
+            })
+    }
+
+    /// Catalog parameter descriptions for this pattern
+    pub fn parameter_infos(&self) -> Vec<ParameterInfo> {
+        self.parameters
+            .iter()
+            .zip(&self.required)
+            .enumerate()
+            .map(|(idx, (param, required))| ParameterInfo {
+                name: format!("param{}", idx + 1),
+                param_type: param.name().to_string(),
+                required: *required,
+                description: capitalize(param.describe()),
+            })
+            .collect()
+    }
+}
+
+/// Distinct ids whose patterns match the text, with their captured parameters
+///
+/// Patterns matching the whole text win over patterns matching only part of it,
+/// so `I take a screenshot` does not compete with `I take a screenshot "([^"]+)"`,
+/// and a built-in step is dropped when another match is one it yields to.
+/// More than one result means the step is ambiguous.
+pub fn closest_matches<'a>(
+    patterns: impl IntoIterator<Item = (&'a StepPattern, &'a str)>,
+    text: &str,
+) -> Vec<(&'a str, Vec<String>)> {
+    let mut exact: Vec<(&str, Vec<String>)> = Vec::new();
+    let mut partial: Vec<(&str, Vec<String>)> = Vec::new();
+    for (pattern, id) in patterns {
+        if exact.iter().any(|(matched, _)| *matched == id) {
+            continue;
+        }
+        let Some(params) = pattern.captures(text) else {
+            continue;
+        };
+        if pattern.is_exact_match(text) {
+            partial.retain(|(matched, _)| *matched != id);
+            exact.push((id, params));
+        } else if !partial.iter().any(|(matched, _)| *matched == id) {
+            partial.push((id, params));
+        }
+    }
+    let matches = if exact.is_empty() { partial } else { exact };
+    let yielding: Vec<&str> = matches
+        .iter()
+        .filter(|(id, _)| {
+            find_definition(id).is_some_and(|definition| {
+                matches
+                    .iter()
+                    .any(|(other, _)| definition.yields_to.contains(other))
+            })
+        })
+        .map(|(id, _)| *id)
+        .collect();
+    matches
+        .into_iter()
+        .filter(|(id, _)| !yielding.contains(id))
+        .collect()
+}
+
+/// Catalog parameters inferred from a pattern; empty if it does not compile
+pub fn infer_parameters(pattern: &str) -> Vec<ParameterInfo> {
+    StepPattern::compile(pattern)
+        .map(|p| p.parameter_infos())
+        .unwrap_or_default()
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Whether a pattern uses Cucumber Expression syntax rather than a raw regex
+pub fn is_cucumber_expression(pattern: &str) -> bool {
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '{' => {
+                let name: String = chars.by_ref().take_while(|&c| c != '}').collect();
+                if name.chars().all(|c| c.is_ascii_lowercase() || c == '_') {
+                    return true;
+                }
+            }
+            _ => {}
+        }
+    }
+    false
+}
+
+fn compile_expression(expression: &str) -> Result<StepPattern, String> {
+    let mut strict = String::from("^");
+    let mut loose = String::from("^");
+    let mut parameters = Vec::new();
+
+    let mut chars = expression.chars();
+    let mut word = String::new();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' => {
+                push_literal_word(&mut word, &mut strict, &mut loose)?;
+                let name: String = chars.by_ref().take_while(|&c| c != '}').collect();
+                let param = ParameterType::from_name(&name)
+                    .ok_or_else(|| format!("unknown parameter type {{{}}}", name))?;
+                strict.push_str(param.regex());
+                loose.push_str(param.loose_regex());
+                parameters.push(param);
+            }
+            c if c.is_whitespace() => {
+                push_literal_word(&mut word, &mut strict, &mut loose)?;
+                strict.push_str(r"\s");
+                loose.push_str(r"\s");
+            }
+            '\\' => {
+                word.push('\\');
+                if let Some(next) = chars.next() {
+                    word.push(next);
+                }
+            }
+            c => word.push(c),
+        }
+    }
+    push_literal_word(&mut word, &mut strict, &mut loose)?;
+    strict.push('$');
+    loose.push('$');
+
+    let regex = Regex::new(&strict).map_err(|e| e.to_string())?;
+    let has_typed = parameters.iter().any(|p| {
+        matches!(
+            p,
+            ParameterType::Int | ParameterType::Float | ParameterType::Word
+        )
+    });
+    let loose = if has_typed {
+        Some(Regex::new(&loose).map_err(|e| e.to_string())?)
+    } else {
+        None
+    };
+    Ok(StepPattern {
+        source: expression.to_string(),
+        regex,
+        loose,
+        required: vec![true; parameters.len()],
+        parameters,
+    })
+}
+
+/// Translate literal text with `(optional)` parts and `a/b` alternation
+fn push_literal_word(
+    word: &mut String,
+    strict: &mut String,
+    loose: &mut String,
+) -> Result<(), String> {
+    if word.is_empty() {
+        return Ok(());
+    }
+    let alternatives = split_unescaped(word, '/');
+    let translated: Vec<String> = alternatives
+        .iter()
+        .map(|alt| translate_optional(alt))
+        .collect::<Result<_, _>>()?;
+    let regex = if translated.len() > 1 {
+        format!("(?:{})", translated.join("|"))
+    } else {
+        translated.into_iter().next().unwrap_or_default()
+    };
+    strict.push_str(&regex);
+    loose.push_str(&regex);
+    word.clear();
+    Ok(())
+}
+
+fn split_unescaped(text: &str, separator: char) -> Vec<String> {
+    let mut parts = vec![String::new()];
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            parts.last_mut().unwrap().push(c);
+            if let Some(next) = chars.next() {
+                parts.last_mut().unwrap().push(next);
+            }
+        } else if c == separator {
+            parts.push(String::new());
+        } else {
+            parts.last_mut().unwrap().push(c);
+        }
+    }
+    parts
+}
+
+fn translate_optional(text: &str) -> Result<String, String> {
+    let mut out = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some(next) = chars.next() {
+                    out.push_str(&regex::escape(&next.to_string()));
+                }
+            }
+            '(' => {
+                let optional: String = chars.by_ref().take_while(|&c| c != ')').collect();
+                if optional.is_empty() {
+                    return Err("empty optional text ()".to_string());
+                }
+                out.push_str(&format!("(?:{})?", regex::escape(&optional)));
+            }
+            c => out.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    Ok(out)
+}
+
+/// Infer the type of each capturing group in a raw regex
+fn infer_regex_parameters(pattern: &str) -> Vec<(ParameterType, bool)> {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut params = Vec::new();
+    let mut idx = 0;
+    while idx < chars.len() {
+        match chars[idx] {
+            '\\' => idx += 2,
+            '(' if chars.get(idx + 1) != Some(&'?') => {
+                let mut depth = 0;
+                let mut end = idx;
+                while end < chars.len() {
+                    match chars[end] {
+                        '\\' => end += 1,
+                        '(' => depth += 1,
+                        ')' => {
+                            depth -= 1;
+                            if depth == 0 {
+                                break;
+                            }
+                        }
+                        _ => {}
+                    }
+                    end += 1;
+                }
+                let body: String = chars[idx + 1..end.min(chars.len())].iter().collect();
+                let optional = matches!(chars.get(end + 1), Some('?') | Some('*'));
+                let param = match body.as_str() {
+                    r"\d+" | r"-?\d+" | r"\d" => ParameterType::Int,
+                    r"\d+\.\d+" | r"-?\d+(?:\.\d+)?" | r"\d+(?:\.\d+)?" => ParameterType::Float,
+                    r"[^ ]+" | r"\S+" | r"\w+" => ParameterType::Word,
+                    _ => ParameterType::String,
+                };
+                params.push((param, !optional));
+                idx += 1;
+            }
+            _ => idx += 1,
+        }
+    }
+    params
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expression_detection() {
+        assert!(is_cucumber_expression("I wait {int} second(s)"));
+        assert!(is_cucumber_expression("I type {}"));
+        assert!(!is_cucumber_expression(r"I wait (\d+) seconds?"));
+        assert!(!is_cucumber_expression(r"I extract h(\d{1,2}) headings"));
+        assert!(!is_cucumber_expression(r"I see \{int\}"));
+    }
+
+    #[test]
+    fn test_expression_matches_typed_parameters() {
+        let pattern = StepPattern::compile("I wait {int} second(s)").unwrap();
+        assert_eq!(
+            pattern.captures("I wait 5 seconds"),
+            Some(vec!["5".to_string()])
+        );
+        assert_eq!(
+            pattern.captures("I wait 1 second"),
+            Some(vec!["1".to_string()])
+        );
+        assert_eq!(pattern.captures("I wait five seconds"), None);
+        assert_eq!(pattern.captures("I wait 5 seconds please"), None);
+        assert_eq!(pattern.parameters, vec![ParameterType::Int]);
+
+        let pattern = StepPattern::compile("I click/press {selector} at {float},{float}").unwrap();
+        assert_eq!(
+            pattern.captures("I press '#go' at 1.5,-2"),
+            Some(vec!["#go".to_string(), "1.5".to_string(), "-2".to_string()])
+        );
+        assert_eq!(pattern.parameter_infos()[0].param_type, "selector");
+    }
+
+    #[test]
+    fn test_explain_mismatch() {
+        let pattern = StepPattern::compile(r#"I activate tab "{int}""#).unwrap();
+        assert!(pattern.captures(r#"I activate tab "two""#).is_none());
+        let message = pattern.explain_mismatch(r#"I activate tab "two""#).unwrap();
+        assert!(message.contains("{int}"));
+        assert!(message.contains("an integer"));
+        assert!(pattern.explain_mismatch(r#"I close tab "two""#).is_none());
+    }
+
+    #[test]
+    fn test_regex_parameters_are_inferred() {
+        let pattern =
+            StepPattern::compile(r#"I scroll (\d+) pixels? on "([^"]+)"(?: slowly)?"#).unwrap();
+        let infos = pattern.parameter_infos();
+        assert_eq!(infos.len(), 2);
+        assert_eq!(infos[0].param_type, "int");
+        assert_eq!(infos[1].param_type, "string");
+        assert!(pattern.explain_mismatch("I scroll lots").is_none());
+    }
+
+    #[test]
+    fn test_closest_matches_prefer_exact_matches() {
+        let patterns: Vec<(StepPattern, &str)> = [
+            (r"I take a screenshot", "screenshot_auto"),
+            (r#"I take a screenshot "([^"]+)""#, "screenshot"),
+            (r#"I save a screenshot "([^"]+)""#, "screenshot"),
+            (r#"I take a screenshot "{word}""#, "screenshot_named"),
+        ]
+        .into_iter()
+        .map(|(p, id)| (StepPattern::compile(p).unwrap(), id))
+        .collect();
+        let candidates = || patterns.iter().map(|(p, id)| (p, *id));
+
+        let matches = closest_matches(candidates(), r#"I take a screenshot "a.png""#);
+        let ids: Vec<&str> = matches.iter().map(|(id, _)| *id).collect();
+        assert_eq!(ids, vec!["screenshot", "screenshot_named"]);
+        assert_eq!(matches[0].1, vec!["a.png"]);
+
+        let matches = closest_matches(candidates(), "I take a screenshot now");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].0, "screenshot_auto");
+        assert!(closest_matches(candidates(), "I go home").is_empty());
+    }
+
+    #[test]
+    fn test_unknown_parameter_type_is_an_error() {
+        assert!(StepPattern::compile("I pick {color}").is_err());
+    }
+}
@@ -1,6 +1,9 @@
 // Discovery module: Step catalog and search functionality
 pub mod catalog;
+pub mod definitions;
+pub mod expression;
 pub mod schema;
 pub mod search;
 
 pub use catalog::StepCatalog;
+pub use definitions::{STEP_DEFINITIONS, StepDefinition};
@@ -1,3 +1,4 @@
+use crate::backend::Check;
 use thiserror::Error;
 
 pub type Result<T> = std::result::Result<T, WebSpecError>;
@@ -22,14 +23,34 @@ pub enum WebSpecError {
     #[error("URL parse error: {0}")]
     UrlParse(#[from] url::ParseError),
 
-    #[error("Timeout waiting for element")]
-    Timeout,
+    #[error("Timeout: {0}")]
+    Timeout(String),
+
+    #[error("'{selector}' is not {check} after {waited_ms} ms: {detail}")]
+    NotActionable {
+        selector: String,
+        check: Check,
+        waited_ms: u64,
+        detail: String,
+    },
 
     #[error("Element not found")]
     NotFound,
 
     #[error("Script execution error: {0}")]
     Script(String),
+
+    #[error("Parse error at line {line}: {message}")]
+    Parse { line: usize, message: String },
+
+    #[error("Invalid tag expression: {0}")]
+    TagExpression(String),
+
+    #[error("Invalid step pattern: {0}")]
+    StepPattern(String),
+
+    #[error("Unknown step: {0}")]
+    UnknownStep(String),
 }
 
 impl From<String> for WebSpecError {
@@ -52,7 +73,7 @@ impl From<serde_json::Error> for WebSpecError {
 }
 
 impl From<tokio::time::error::Elapsed> for WebSpecError {
-    fn from(_: tokio::time::error::Elapsed) -> Self {
-        WebSpecError::Timeout
+    fn from(e: tokio::time::error::Elapsed) -> Self {
+        WebSpecError::Timeout(e.to_string())
     }
 }
@@ -497,9 +497,13 @@ mod tests {
 
         let scenario = ScenarioResult {
             name: "Test".to_string(),
+            tags: vec![],
             status: "passed".to_string(),
             duration_ms: 5000,
             steps: vec![],
+            location: None,
+            attempts: Vec::new(),
+            aborted: false,
         };
 
         monitor.record_scenario(&scenario);
@@ -513,16 +517,24 @@ mod tests {
 
         let passed = ScenarioResult {
             name: "Passed".to_string(),
+            tags: vec![],
             status: "passed".to_string(),
             duration_ms: 1000,
             steps: vec![],
+            location: None,
+            attempts: Vec::new(),
+            aborted: false,
         };
 
         let failed = ScenarioResult {
             name: "Failed".to_string(),
+            tags: vec![],
             status: "failed".to_string(),
             duration_ms: 2000,
             steps: vec![],
+            location: None,
+            attempts: Vec::new(),
+            aborted: false,
         };
 
         monitor.record_scenario(&passed);
@@ -610,9 +622,13 @@ mod tests {
         // Add some scenarios to trigger thresholds
         let scenario = ScenarioResult {
             name: "Slow".to_string(),
+            tags: vec![],
             status: "passed".to_string(),
             duration_ms: 45000,
             steps: vec![],
+            location: None,
+            attempts: Vec::new(),
+            aborted: false,
         };
         monitor.record_scenario(&scenario);
 
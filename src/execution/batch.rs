@@ -1,10 +1,18 @@
 // Batch execution support for running multiple features
-use crate::execution::result::ExecutionResult;
+use crate::backend::BrowserConfig;
+use crate::execution::result::{ErrorInfo, ExecutionResult};
 use rayon::prelude::*;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
+use crate::runner::{
+    BrowserPool, HookPoint, RunState, Runner, add_after_all_failure, blocked_by_hook,
+    scenario_not_started,
+};
+use std::sync::atomic::AtomicUsize;
+
 #[derive(Debug, Clone)]
 pub struct BatchConfig {
     pub parallel: bool,
@@ -12,6 +20,8 @@ pub struct BatchConfig {
     pub timeout_seconds: u64,
     pub continue_on_failure: bool,
     pub output_format: String,
+    /// How the batch's browsers are launched
+    pub browser: BrowserConfig,
 }
 
 impl Default for BatchConfig {
@@ -22,6 +32,7 @@ impl Default for BatchConfig {
             timeout_seconds: 300,
             continue_on_failure: true,
             output_format: "text".to_string(),
+            browser: BrowserConfig::default(),
         }
     }
 }
@@ -37,6 +48,8 @@ pub struct BatchResult {
     pub total_duration_ms: u64,
     pub results: Vec<FeatureResult>,
     pub errors: Vec<BatchError>,
+    /// Why features or scenarios were skipped without running
+    pub aborted: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -50,6 +63,48 @@ pub struct FeatureResult {
     pub result: Option<ExecutionResult>,
 }
 
+impl BatchResult {
+    /// Aggregate per-feature results; every result counts as one feature
+    pub fn new(results: Vec<FeatureResult>, errors: Vec<BatchError>, duration_ms: u64) -> Self {
+        let passed_features = results.iter().filter(|r| r.status == "passed").count();
+        let failed_features = results.iter().filter(|r| r.status == "failed").count();
+        let total_scenarios: usize = results
+            .iter()
+            .map(|r| r.scenarios_passed + r.scenarios_failed)
+            .sum();
+        let passed_scenarios: usize = results.iter().map(|r| r.scenarios_passed).sum();
+        let failed_scenarios: usize = results.iter().map(|r| r.scenarios_failed).sum();
+
+        BatchResult {
+            total_features: results.len(),
+            passed_features,
+            failed_features,
+            total_scenarios,
+            passed_scenarios,
+            failed_scenarios,
+            total_duration_ms: duration_ms,
+            results,
+            errors,
+            aborted: None,
+        }
+    }
+}
+
+impl FeatureResult {
+    /// Result of a feature file that ran; flaky scenarios count as passed
+    pub fn from_execution(path: &Path, result: ExecutionResult, duration_ms: u64) -> Self {
+        FeatureResult {
+            name: feature_name(path),
+            path: path.to_path_buf(),
+            status: result.status.clone(),
+            scenarios_passed: result.summary.passed_scenarios + result.summary.flaky_scenarios,
+            scenarios_failed: result.summary.failed_scenarios,
+            duration_ms,
+            result: Some(result),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct BatchError {
     pub path: PathBuf,
@@ -141,6 +196,23 @@ impl BatchExecutor {
         self.progress = Some(BatchProgress::new(total));
 
         let start_time = Instant::now();
+        let stop = AtomicBool::new(false);
+
+        let run = |path: &PathBuf| {
+            if stop.load(Ordering::SeqCst) {
+                return self.skipped_feature(path);
+            }
+            let start_time = Instant::now();
+            let result = self.feature_result(
+                path,
+                executor(path),
+                start_time.elapsed().as_millis() as u64,
+            );
+            if result.status == "failed" && !self.config.continue_on_failure {
+                stop.store(true, Ordering::SeqCst);
+            }
+            result
+        };
 
         let results: Vec<FeatureResult> = if self.config.parallel && total > 1 {
             // Optimize parallel execution with work-stealing configuration
@@ -153,96 +225,79 @@ impl BatchExecutor {
                 paths
                     .par_iter()
                     .with_max_len(1) // Process one file per thread to maximize parallelism
-                    .map(|path| self.execute_feature(path, executor))
+                    .map(run)
                     .collect()
             })
         } else {
-            paths
-                .iter()
-                .map(|path| self.execute_feature(path, executor))
-                .collect()
+            paths.iter().map(run).collect()
         };
 
-        let duration_ms = start_time.elapsed().as_millis() as u64;
-
-        // Calculate aggregates
-        let passed_features = results.iter().filter(|r| r.status == "passed").count();
-        let failed_features = results.iter().filter(|r| r.status == "failed").count();
-        let total_scenarios: usize = results
-            .iter()
-            .map(|r| r.scenarios_passed + r.scenarios_failed)
-            .sum();
-        let passed_scenarios: usize = results.iter().map(|r| r.scenarios_passed).sum();
-        let failed_scenarios: usize = results.iter().map(|r| r.scenarios_failed).sum();
-
-        // Collect errors
-        let errors: Vec<BatchError> = results
-            .iter()
-            .filter(|r| r.result.is_none())
-            .map(|r| BatchError {
-                path: r.path.clone(),
-                error: format!("Feature execution failed"),
-                timestamp: chrono::Local::now().to_rfc3339(),
-            })
-            .collect();
-
-        BatchResult {
-            total_features: total,
-            passed_features,
-            failed_features,
-            total_scenarios,
-            passed_scenarios,
-            failed_scenarios,
-            total_duration_ms: duration_ms,
+        self.summarize(
+            paths,
             results,
-            errors,
+            start_time.elapsed().as_millis() as u64,
+            None,
+        )
+    }
+
+    /// Aggregate per-feature results, with errors in the order of `paths`
+    ///
+    /// When anything was skipped, the result is marked aborted with `limit_reason`,
+    /// or as stopped by a failed feature when no failure limit was reached.
+    fn summarize(
+        &self,
+        paths: &[PathBuf],
+        results: Vec<FeatureResult>,
+        duration_ms: u64,
+        limit_reason: Option<String>,
+    ) -> BatchResult {
+        let mut errors = self
+            .progress
+            .as_ref()
+            .map(|p| p.collect_errors())
+            .unwrap_or_default();
+        errors.sort_by_key(|e| paths.iter().position(|p| *p == e.path));
+
+        let skipped = results.iter().any(|r| {
+            r.status == "skipped"
+                || r.result
+                    .as_ref()
+                    .is_some_and(|r| r.summary.aborted.is_some())
+        });
+        let mut result = BatchResult::new(results, errors, duration_ms);
+        if skipped {
+            result.aborted = Some(limit_reason.unwrap_or_else(|| {
+                "Stopped after a feature failed (continue on failure is off)".to_string()
+            }));
         }
+        result
     }
 
-    fn execute_feature(
+    /// Result for one feature; an `Err` is recorded as a [`BatchError`]
+    fn feature_result(
         &self,
-        path: &PathBuf,
-        executor: &impl Fn(&PathBuf) -> Result<ExecutionResult, String>,
+        path: &Path,
+        result: Result<ExecutionResult, String>,
+        duration_ms: u64,
     ) -> FeatureResult {
-        let name = path
-            .file_stem()
-            .and_then(|n| n.to_str())
-            .unwrap_or("Unknown")
-            .to_string();
-
-        let start_time = Instant::now();
-
-        let result = executor(path);
-
-        let duration_ms = start_time.elapsed().as_millis() as u64;
+        if let Some(progress) = &self.progress {
+            progress.increment_completed();
+        }
 
         match result {
-            Ok(exec_result) => {
-                let scenarios_passed = exec_result.summary.passed_scenarios;
-                let scenarios_failed = exec_result.summary.failed_scenarios;
-
-                if let Some(progress) = &self.progress {
-                    progress.increment_completed();
-                }
-
-                FeatureResult {
-                    name,
-                    path: path.clone(),
-                    status: exec_result.status.clone(),
-                    scenarios_passed,
-                    scenarios_failed,
-                    duration_ms,
-                    result: Some(exec_result),
-                }
-            }
-            Err(_e) => {
+            Ok(exec_result) => FeatureResult::from_execution(path, exec_result, duration_ms),
+            Err(error) => {
                 if let Some(progress) = &self.progress {
-                    progress.increment_completed();
+                    progress.add_error(BatchError {
+                        path: path.to_path_buf(),
+                        error,
+                        timestamp: chrono::Local::now().to_rfc3339(),
+                    });
                 }
 
                 FeatureResult {
-                    name,
-                    path: path.clone(),
+                    name: feature_name(path),
+                    path: path.to_path_buf(),
                     status: "failed".to_string(),
                     scenarios_passed: 0,
                     scenarios_failed: 0,
@@ -253,6 +308,23 @@ impl BatchExecutor {
         }
     }
 
+    /// Placeholder for a feature not run because an earlier one failed
+    fn skipped_feature(&self, path: &Path) -> FeatureResult {
+        if let Some(progress) = &self.progress {
+            progress.increment_completed();
+        }
+
+        FeatureResult {
+            name: feature_name(path),
+            path: path.to_path_buf(),
+            status: "skipped".to_string(),
+            scenarios_passed: 0,
+            scenarios_failed: 0,
+            duration_ms: 0,
+            result: None,
+        }
+    }
+
     pub fn discover_features(path: &str, _pattern: &str) -> Result<Vec<PathBuf>, String> {
         let mut paths = Vec::new();
 
@@ -309,7 +381,11 @@ impl BatchExecutor {
             "Scenarios: {} total, {} passed, {} failed\n",
             result.total_scenarios, result.passed_scenarios, result.failed_scenarios
         ));
-        output.push_str(&format!("Duration:  {}ms\n\n", result.total_duration_ms));
+        output.push_str(&format!("Duration:  {}ms\n", result.total_duration_ms));
+        if let Some(reason) = &result.aborted {
+            output.push_str(&format!("Aborted:   {}\n", reason));
+        }
+        output.push('\n');
 
         output.push_str("=== Feature Results ===\n");
         for feature in &result.results {
@@ -351,6 +427,7 @@ impl BatchExecutor {
                 "passed_scenarios": result.passed_scenarios,
                 "failed_scenarios": result.failed_scenarios,
                 "duration_ms": result.total_duration_ms,
+                "aborted": result.aborted,
             },
             "features": result.results.iter().map(|f| serde_json::json!({
                 "name": f.name,
@@ -359,6 +436,7 @@ impl BatchExecutor {
                 "scenarios_passed": f.scenarios_passed,
                 "scenarios_failed": f.scenarios_failed,
                 "duration_ms": f.duration_ms,
+                "result": f.result,
             })).collect::<Vec<_>>(),
             "errors": result.errors.iter().map(|e| serde_json::json!({
                 "path": e.path.to_string_lossy(),
@@ -378,6 +456,7 @@ impl BatchExecutor {
                 "passed_scenarios": result.passed_scenarios,
                 "failed_scenarios": result.failed_scenarios,
                 "duration_ms": result.total_duration_ms,
+                "aborted": result.aborted,
             },
             "features": result.results.iter().map(|f| serde_yaml::to_value(&serde_json::json!({
                 "name": f.name,
@@ -386,6 +465,7 @@ impl BatchExecutor {
                 "scenarios_passed": f.scenarios_passed,
                 "scenarios_failed": f.scenarios_failed,
                 "duration_ms": f.duration_ms,
+                "result": f.result,
             })).unwrap()).collect::<Vec<_>>(),
         }))
         .unwrap();
@@ -393,6 +473,174 @@ impl BatchExecutor {
     }
 }
 
+impl BatchExecutor {
+    /// Run every feature with the runner, scheduling scenarios on a browser pool
+    ///
+    /// The pool holds up to `max_workers` isolated browsers (one when not parallel)
+    /// and the scenarios of a feature run concurrently on it, so a single large
+    /// feature can use every browser. Results keep the order of `paths` and of the
+    /// scenarios within each feature. Each feature gets `timeout_seconds` to finish;
+    /// scenarios still waiting or running then fail with `SCENARIO_TIMEOUT`, after
+    /// their `After` hooks, and their busy browsers are replaced; `BeforeAll` and
+    /// `AfterAll` hooks still running fail with `HOOK_FAILED`. With
+    /// `continue_on_failure` off, features not yet started after a failure are skipped.
+    pub async fn run(&mut self, paths: &[PathBuf], runner: &Runner) -> BatchResult {
+        let total = paths.len();
+        self.progress = Some(BatchProgress::new(total));
+        runner.reset_failures();
+
+        let start_time = Instant::now();
+        let workers = if self.config.parallel {
+            self.config.max_workers.clamp(1, total.max(1))
+        } else {
+            1
+        };
+        let pool = BrowserPool::new(workers).with_config(self.config.browser.clone());
+        let next = AtomicUsize::new(0);
+        let stop = AtomicBool::new(false);
+        let results: Mutex<Vec<Option<FeatureResult>>> = Mutex::new(vec![None; total]);
+
+        let worker = || async {
+            loop {
+                let idx = next.fetch_add(1, Ordering::SeqCst);
+                let Some(path) = paths.get(idx) else {
+                    break;
+                };
+                let result = if stop.load(Ordering::SeqCst) || runner.aborted().is_some() {
+                    self.skipped_feature(path)
+                } else {
+                    let start_time = Instant::now();
+                    let outcome = self.run_feature(path, runner, &pool).await;
+                    self.feature_result(path, outcome, start_time.elapsed().as_millis() as u64)
+                };
+                if result.status == "failed" && !self.config.continue_on_failure {
+                    stop.store(true, Ordering::SeqCst);
+                }
+                results.lock().unwrap()[idx] = Some(result);
+            }
+        };
+        futures_util::future::join_all((0..workers).map(|_| worker())).await;
+        pool.close().await;
+
+        let results = results
+            .into_inner()
+            .unwrap()
+            .into_iter()
+            .flatten()
+            .collect();
+        self.summarize(
+            paths,
+            results,
+            start_time.elapsed().as_millis() as u64,
+            runner.aborted(),
+        )
+    }
+
+    /// Run one feature's scenarios concurrently on browsers from the pool
+    async fn run_feature(
+        &self,
+        path: &Path,
+        runner: &Runner,
+        pool: &BrowserPool,
+    ) -> Result<ExecutionResult, String> {
+        let feature = crate::gherkin::parse_feature_file(path).map_err(|e| e.to_string())?;
+        let start_time = Instant::now();
+        let deadline =
+            tokio::time::Instant::now() + Duration::from_secs(self.config.timeout_seconds);
+
+        let scenarios = runner.scenarios(&feature);
+        if scenarios.is_empty() {
+            return Ok(file_result(
+                path,
+                runner.feature_result(&feature, Vec::new(), start_time),
+            ));
+        }
+
+        let timed_out = || {
+            ErrorInfo::new(
+                "SCENARIO_TIMEOUT",
+                format!(
+                    "Feature timed out after {} seconds",
+                    self.config.timeout_seconds
+                ),
+            )
+            .with_suggestion("Raise --timeout or split the feature")
+        };
+        let mut scenario_results = match runner
+            .run_pool_hooks(HookPoint::BeforeAll, pool, Some(deadline))
+            .await
+        {
+            Some(failure) => scenarios
+                .iter()
+                .map(|scenario| blocked_by_hook(scenario, &failure))
+                .collect(),
+            None => {
+                futures_util::future::join_all(scenarios.iter().map(|scenario| async {
+                    if let Some(skipped) = runner.skip_if_aborted(scenario) {
+                        return skipped;
+                    }
+                    let mut browser = match tokio::time::timeout_at(deadline, pool.acquire()).await
+                    {
+                        Ok(Ok(browser)) => browser,
+                        Ok(Err(e)) => {
+                            runner.count_failure();
+                            return scenario_not_started(
+                                scenario,
+                                ErrorInfo::new(
+                                    "BROWSER_UNAVAILABLE",
+                                    format!("Failed to launch browser: {}", e),
+                                ),
+                            );
+                        }
+                        Err(_) => {
+                            runner.count_failure();
+                            return scenario_not_started(scenario, timed_out());
+                        }
+                    };
+                    let state = RunState::default();
+                    let run = runner.run_scenario(scenario, &mut browser, &state);
+                    match tokio::time::timeout_at(deadline, run).await {
+                        Ok(result) => result,
+                        Err(_) => {
+                            let result = runner
+                                .cancelled_scenario(scenario, timed_out(), &mut browser, &state)
+                                .await;
+                            // The page may still be busy with the abandoned scenario
+                            browser.discard().await;
+                            result
+                        }
+                    }
+                }))
+                .await
+            }
+        };
+        // Cleanup runs even when a scenario timed out; only the feature's deadline cuts it short
+        if let Some(failure) = runner
+            .run_pool_hooks(HookPoint::AfterAll, pool, Some(deadline))
+            .await
+        {
+            add_after_all_failure(&mut scenario_results, failure);
+        }
+        Ok(file_result(
+            path,
+            runner.feature_result(&feature, scenario_results, start_time),
+        ))
+    }
+}
+
+/// Feature result with the path it was read from
+fn file_result(path: &Path, mut result: ExecutionResult) -> ExecutionResult {
+    result.feature.file = Some(path.to_string_lossy().to_string());
+    result
+}
+
+pub(crate) fn feature_name(path: &Path) -> String {
+    path.file_stem()
+        .and_then(|n| n.to_str())
+        .unwrap_or("Unknown")
+        .to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -402,6 +650,7 @@ mod tests {
         ExecutionResult {
             status: status.to_string(),
             timestamp: chrono::Local::now().to_rfc3339(),
+            seed: None,
             duration_ms: 100,
             feature: crate::execution::FeatureInfo {
                 name: path.to_string(),
@@ -414,10 +663,12 @@ mod tests {
                 passed_scenarios: if status == "passed" { 5 } else { 3 },
                 failed_scenarios: if status == "failed" { 2 } else { 0 },
                 skipped_scenarios: 0,
+                flaky_scenarios: 0,
                 total_steps: 20,
                 passed_steps: if status == "passed" { 20 } else { 18 },
                 failed_steps: if status == "failed" { 2 } else { 0 },
                 skipped_steps: 0,
+                aborted: None,
             },
         }
     }
@@ -439,6 +690,7 @@ mod tests {
             timeout_seconds: 600,
             continue_on_failure: true,
             output_format: "json".to_string(),
+            browser: BrowserConfig::default(),
         };
 
         assert!(!config.parallel);
@@ -540,6 +792,7 @@ mod tests {
                 },
             ],
             errors: vec![],
+            aborted: None,
         };
 
         let output = executor.format_result(&result, "text");
@@ -574,6 +827,7 @@ mod tests {
                 result: None,
             }],
             errors: vec![],
+            aborted: None,
         };
 
         let output = executor.format_result(&result, "json");
@@ -618,6 +872,61 @@ mod tests {
         assert_eq!(result.errors.len(), 1);
     }
 
+    #[test]
+    fn test_batch_errors_keep_the_feature_error() {
+        let mut executor = BatchExecutor::new();
+        let paths = vec![
+            PathBuf::from("feature1.feature"),
+            PathBuf::from("feature2.feature"),
+        ];
+
+        let result = executor.execute(&paths, &|path| {
+            Err(format!("Failed to parse {}", path.display()))
+        });
+
+        let messages: Vec<&str> = result.errors.iter().map(|e| e.error.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Failed to parse feature1.feature",
+                "Failed to parse feature2.feature"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_batch_stops_after_failure_unless_continuing() {
+        let paths = vec![
+            PathBuf::from("feature1.feature"),
+            PathBuf::from("feature2.feature"),
+            PathBuf::from("feature3.feature"),
+        ];
+        let run = |continue_on_failure: bool| {
+            let mut executor = BatchExecutor::with_config(BatchConfig {
+                parallel: false,
+                continue_on_failure,
+                ..Default::default()
+            });
+            executor.execute(&paths, &|path| {
+                let status = if path.ends_with("feature2.feature") {
+                    "failed"
+                } else {
+                    "passed"
+                };
+                Ok(create_mock_result(path.to_str().unwrap(), status))
+            })
+        };
+
+        let stopped = run(false);
+        let statuses: Vec<&str> = stopped.results.iter().map(|r| r.status.as_str()).collect();
+        assert_eq!(statuses, vec!["passed", "failed", "skipped"]);
+        assert_eq!(stopped.total_features, 3);
+
+        let continued = run(true);
+        assert_eq!(continued.passed_features, 2);
+        assert_eq!(continued.failed_features, 1);
+    }
+
     #[test]
     fn test_discover_features_from_file() {
         let temp_dir = std::env::temp_dir();
@@ -632,4 +941,37 @@ mod tests {
 
         let _ = fs::remove_file(test_feature);
     }
+    #[tokio::test(start_paused = true)]
+    async fn test_feature_deadline_bounds_before_all_hooks() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hooked.feature");
+        fs::write(
+            &path,
+            "Feature: Hooked\n  Scenario: Blocked\n    Given I navigate to \"https://example.com\"\n",
+        )
+        .unwrap();
+
+        let runner =
+            Runner::new().with_hook(crate::runner::Hook::from_fn(HookPoint::BeforeAll, |_| {
+                Box::pin(async {
+                    tokio::time::sleep(Duration::from_secs(3600)).await;
+                    Ok(())
+                })
+            }));
+        let pool = BrowserPool::from_browsers(vec![crate::Browser::from_backend(
+            crate::backend::no_page::NoPage,
+        )]);
+        let executor = BatchExecutor::with_config(BatchConfig {
+            timeout_seconds: 1,
+            ..BatchConfig::default()
+        });
+
+        let result = executor.run_feature(&path, &runner, &pool).await.unwrap();
+        let hook = &result.scenarios[0].steps[0];
+        assert_eq!(result.scenarios[0].status, "failed");
+        assert_eq!(hook.keyword, "BeforeAll");
+        let error = hook.error.as_ref().unwrap();
+        assert_eq!(error.code, "HOOK_FAILED");
+        assert!(error.message.contains("the feature timed out"));
+    }
 }
@@ -176,9 +176,13 @@ mod tests {
 
         let mut scenario = ScenarioResult {
             name: "Test Scenario".to_string(),
+            tags: vec![],
             status: status.to_string(),
             duration_ms,
             steps: Vec::new(),
+            location: None,
+            attempts: Vec::new(),
+            aborted: false,
         };
 
         let step = StepResult {
@@ -188,6 +192,7 @@ mod tests {
             duration_ms: duration_ms / 2,
             output: None,
             error: None,
+            location: None,
         };
 
         scenario.steps.push(step);
@@ -197,10 +202,12 @@ mod tests {
             passed_scenarios: if status == "passed" { 1 } else { 0 },
             failed_scenarios: if status == "failed" { 1 } else { 0 },
             skipped_scenarios: if status == "skipped" { 1 } else { 0 },
+            flaky_scenarios: 0,
             total_steps: 1,
             passed_steps: if status == "passed" { 1 } else { 0 },
             failed_steps: if status == "failed" { 1 } else { 0 },
             skipped_steps: if status == "skipped" { 1 } else { 0 },
+            aborted: None,
         };
         result
     }
@@ -123,6 +123,7 @@ fn generate_css() -> String {
     css.push_str("    .status-passed { color: #27ae60; }\n");
     css.push_str("    .status-failed { color: #e74c3c; }\n");
     css.push_str("    .status-skipped { color: #f39c12; }\n");
+    css.push_str("    .status-flaky { color: #8e44ad; }\n");
     css.push_str("    .status-pending { color: #95a5a6; }\n");
     css.push_str("\n");
     css.push_str("    .feature-section {\n");
@@ -216,6 +217,11 @@ fn generate_css() -> String {
     css.push_str("      background-color: #fef2f2;\n");
     css.push_str("    }\n");
     css.push_str("\n");
+    css.push_str("    .scenario-item.flaky {\n");
+    css.push_str("      border-left-color: #8e44ad;\n");
+    css.push_str("      background-color: #f8f0fc;\n");
+    css.push_str("    }\n");
+    css.push('\n');
     css.push_str("    .scenario-item.skipped {\n");
     css.push_str("      border-left-color: #f39c12;\n");
     css.push_str("      background-color: #fffbf0;\n");
@@ -238,6 +244,11 @@ fn generate_css() -> String {
     css.push_str("      color: #7f8c8d;\n");
     css.push_str("    }\n");
     css.push_str("\n");
+    css.push_str("    .scenario-attempts {\n");
+    css.push_str("      font-size: 0.85em;\n");
+    css.push_str("      color: #8e44ad;\n");
+    css.push_str("    }\n");
+    css.push('\n');
     css.push_str("    .steps-list {\n");
     css.push_str("      margin-top: 12px;\n");
     css.push_str("      padding-left: 20px;\n");
@@ -267,6 +278,11 @@ fn generate_css() -> String {
     css.push_str("      color: #2c3e50;\n");
     css.push_str("    }\n");
     css.push_str("\n");
+    css.push_str("    .location {\n");
+    css.push_str("      color: #7f8c8d;\n");
+    css.push_str("      font-family: monospace;\n");
+    css.push_str("      font-size: 0.85em;\n");
+    css.push_str("    }\n\n");
     css.push_str("    .step-error {\n");
     css.push_str("      background-color: #fee;\n");
     css.push_str("      border-left: 3px solid #e74c3c;\n");
@@ -358,12 +374,12 @@ fn generate_summary_panel(result: &ExecutionResult) -> String {
     html.push_str("        <div class=\"summary-value\">");
     html.push_str(&format!(
         "{}/{}",
-        summary.passed_scenarios + summary.failed_scenarios,
+        summary.passed_scenarios + summary.flaky_scenarios + summary.failed_scenarios,
         summary.total_scenarios
     ));
     html.push_str("</div>\n");
-    html.push_str(&format!("        <p><span class=\"status-passed\">✓ {}</span> | <span class=\"status-failed\">✗ {}</span> | <span class=\"status-skipped\">⊘ {}</span></p>\n",
-        summary.passed_scenarios, summary.failed_scenarios, summary.skipped_scenarios));
+    html.push_str(&format!("        <p><span class=\"status-passed\">✓ {}</span> | <span class=\"status-flaky\">↻ {}</span> | <span class=\"status-failed\">✗ {}</span> | <span class=\"status-skipped\">⊘ {}</span></p>\n",
+        summary.passed_scenarios, summary.flaky_scenarios, summary.failed_scenarios, summary.skipped_scenarios));
     html.push_str("      </div>\n");
 
     // Steps
@@ -444,6 +460,16 @@ fn generate_feature_info(result: &ExecutionResult) -> String {
     ));
     html.push_str("        </div>\n");
 
+    if let Some(seed) = result.seed {
+        html.push_str("        <div class=\"meta-item\">\n");
+        html.push_str("          <div class=\"meta-label\">SEED</div>\n");
+        html.push_str(&format!(
+            "          <div class=\"meta-value\">{}</div>\n",
+            seed
+        ));
+        html.push_str("        </div>\n");
+    }
+
     html.push_str("      </div>\n");
 
     if let Some(desc) = &result.feature.description {
@@ -481,10 +507,28 @@ fn generate_scenarios(result: &ExecutionResult) -> String {
             idx + 1,
             scenario.name
         ));
+        if let Some(location) = &scenario.location {
+            html.push_str(&format!(
+                "            <div class=\"location\">{}</div>\n",
+                escape_html(&location.describe(result.feature.file.as_deref()))
+            ));
+        }
         html.push_str(&format!(
             "            <div class=\"scenario-duration\">{}ms</div>\n",
             scenario.duration_ms
         ));
+        if !scenario.attempts.is_empty() {
+            let statuses: Vec<&str> = scenario
+                .attempts
+                .iter()
+                .map(|attempt| attempt.status.as_str())
+                .collect();
+            html.push_str(&format!(
+                "            <div class=\"scenario-attempts\">{} attempts: {}</div>\n",
+                scenario.attempts.len(),
+                statuses.join(" → ")
+            ));
+        }
         html.push_str("          </div>\n");
 
         // Steps
@@ -493,6 +537,7 @@ fn generate_scenarios(result: &ExecutionResult) -> String {
             let symbol = match step.status.as_str() {
                 "passed" => "✓",
                 "failed" => "✗",
+                "timeout" => "⏱",
                 "skipped" => "⊘",
                 _ => "?",
             };
@@ -515,6 +560,12 @@ fn generate_scenarios(result: &ExecutionResult) -> String {
                     "                  <div class=\"error-message\">Error: {}</div>\n",
                     escape_html(&error.message)
                 ));
+                if let Some(location) = &step.location {
+                    html.push_str(&format!(
+                        "                  <div class=\"location\">at {}</div>\n",
+                        escape_html(&location.describe(result.feature.file.as_deref()))
+                    ));
+                }
                 if !error.suggestions.is_empty() {
                     html.push_str("                  <ul class=\"error-suggestions\">\n");
                     for suggestion in &error.suggestions {
@@ -593,9 +644,13 @@ mod tests {
 
         let mut scenario = ScenarioResult {
             name: "Test Scenario".to_string(),
+            tags: vec![],
             status: "passed".to_string(),
             duration_ms: 1000,
             steps: Vec::new(),
+            location: None,
+            attempts: Vec::new(),
+            aborted: false,
         };
 
         let step = StepResult {
@@ -605,6 +660,7 @@ mod tests {
             duration_ms: 500,
             output: None,
             error: None,
+            location: None,
         };
 
         scenario.steps.push(step);
@@ -643,9 +699,13 @@ mod tests {
 
         let mut scenario = ScenarioResult {
             name: "Test Scenario".to_string(),
+            tags: vec![],
             status: "failed".to_string(),
             duration_ms: 1500,
             steps: Vec::new(),
+            location: None,
+            attempts: Vec::new(),
+            aborted: false,
         };
 
         let step = StepResult {
@@ -659,6 +719,7 @@ mod tests {
                 message: "Element not found".to_string(),
                 suggestions: vec!["Try using a different selector".to_string()],
             }),
+            location: Some(crate::gherkin::Location::new(12, 5)),
         };
 
         scenario.steps.push(step);
@@ -671,6 +732,7 @@ mod tests {
         assert!(html.contains("Element not found"));
         assert!(html.contains("Try using a different selector"));
         assert!(html.contains("Browser timeout"));
+        assert!(html.contains("at 12:5"));
     }
 
     #[test]
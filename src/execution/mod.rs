@@ -7,7 +7,9 @@ pub mod debug;
 pub mod html_output;
 pub mod json_output;
 pub mod profiling;
+pub mod rerun;
 pub mod result;
+pub mod shard;
 pub mod tap_output;
 pub mod text_output;
 pub mod webhook;
@@ -26,9 +28,12 @@ pub use debug::{DebugCommand, Debugger, ExecutionSnapshot, ExecutionState};
 pub use html_output::to_html_output;
 pub use json_output::{to_json_output, to_json_output_pretty};
 pub use profiling::{ProfilingMetrics, analyze_execution};
+pub use rerun::{PreviousRun, RerunTarget, merge_results};
 pub use result::{
-    ErrorInfo, ExecutionResult, ExecutionSummary, FeatureInfo, ScenarioResult, StepResult,
+    AttemptResult, ErrorInfo, ExecutionResult, ExecutionSummary, FeatureInfo, ScenarioResult,
+    StepResult,
 };
+pub use shard::{Shard, merge_shards};
 pub use tap_output::{TapSummary, parse_tap_output, to_tap_output};
 pub use text_output::to_text_output;
 pub use webhook::{WebhookConfig, WebhookError, WebhookEvent, WebhookManager};
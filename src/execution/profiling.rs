@@ -123,7 +123,7 @@ fn analyze_scenario(scenario: &ScenarioResult, _total_duration: u64) -> Scenario
         name: scenario.name.clone(),
         duration_ms: duration,
         step_count: scenario.steps.len(),
-        passed: scenario.status == "passed",
+        passed: scenario.status == "passed" || scenario.status == "flaky",
         steps,
         slowest_step,
     }
@@ -217,6 +217,7 @@ mod tests {
         ExecutionResult {
             status: "passed".to_string(),
             timestamp: "2024-01-01T00:00:00Z".to_string(),
+            seed: None,
             duration_ms: 1000,
             feature: FeatureInfo {
                 name: "Test".to_string(),
@@ -226,6 +227,7 @@ mod tests {
             scenarios: vec![
                 ScenarioResult {
                     name: "Scenario 1".to_string(),
+                    tags: vec![],
                     status: "passed".to_string(),
                     duration_ms: 600,
                     steps: vec![
@@ -236,6 +238,7 @@ mod tests {
                             duration_ms: 400,
                             output: None,
                             error: None,
+                            location: None,
                         },
                         StepResult {
                             text: "I click on button".to_string(),
@@ -244,11 +247,16 @@ mod tests {
                             duration_ms: 200,
                             output: None,
                             error: None,
+                            location: None,
                         },
                     ],
+                    location: None,
+                    attempts: Vec::new(),
+                    aborted: false,
                 },
                 ScenarioResult {
                     name: "Scenario 2".to_string(),
+                    tags: vec![],
                     status: "passed".to_string(),
                     duration_ms: 400,
                     steps: vec![
@@ -259,6 +267,7 @@ mod tests {
                             duration_ms: 300,
                             output: None,
                             error: None,
+                            location: None,
                         },
                         StepResult {
                             text: "I type text".to_string(),
@@ -267,8 +276,12 @@ mod tests {
                             duration_ms: 100,
                             output: None,
                             error: None,
+                            location: None,
                         },
                     ],
+                    location: None,
+                    attempts: Vec::new(),
+                    aborted: false,
                 },
             ],
             summary: ExecutionSummary {
@@ -276,10 +289,12 @@ mod tests {
                 passed_scenarios: 2,
                 failed_scenarios: 0,
                 skipped_scenarios: 0,
+                flaky_scenarios: 0,
                 total_steps: 4,
                 passed_steps: 4,
                 failed_steps: 0,
                 skipped_steps: 0,
+                aborted: None,
             },
         }
     }
@@ -0,0 +1,380 @@
+// Re-running the failed scenarios of an earlier run from its saved JSON result
+use crate::error::{Result, WebSpecError};
+use crate::execution::batch::{BatchError, BatchResult, FeatureResult, feature_name};
+use crate::execution::result::{ExecutionResult, ExecutionSummary, ScenarioResult};
+use crate::gherkin::Location;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Result of an earlier `run` or `batch`, as written with `--format json`
+#[derive(Debug, Clone)]
+pub enum PreviousRun {
+    Feature(ExecutionResult),
+    Batch(BatchResult),
+}
+
+/// Scenarios of one feature file to run again
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RerunTarget {
+    pub file: PathBuf,
+    /// Locations of the failed scenarios, or `None` to run the whole feature
+    pub locations: Option<Vec<Location>>,
+}
+
+/// Batch JSON output, reduced to what a rerun needs
+#[derive(Deserialize)]
+struct BatchFile {
+    batch_summary: BatchFileSummary,
+    #[serde(default)]
+    features: Vec<BatchFileFeature>,
+    #[serde(default)]
+    errors: Vec<BatchFileError>,
+}
+
+#[derive(Deserialize)]
+struct BatchFileSummary {
+    #[serde(default)]
+    duration_ms: u64,
+    #[serde(default)]
+    aborted: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct BatchFileFeature {
+    path: PathBuf,
+    status: String,
+    #[serde(default)]
+    duration_ms: u64,
+    #[serde(default)]
+    result: Option<ExecutionResult>,
+}
+
+#[derive(Deserialize)]
+struct BatchFileError {
+    path: PathBuf,
+    error: String,
+}
+
+impl PreviousRun {
+    /// Read a result file written by `run` or `batch` with `--format json`
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        Self::from_json(&content)
+    }
+
+    pub fn from_json(content: &str) -> Result<Self> {
+        let value: serde_json::Value = serde_json::from_str(content)?;
+        if value.get("batch_summary").is_none() {
+            return Ok(PreviousRun::Feature(serde_json::from_value(value)?));
+        }
+
+        let batch: BatchFile = serde_json::from_value(value)?;
+        let results = batch
+            .features
+            .into_iter()
+            .map(|feature| match feature.result {
+                Some(result) => {
+                    FeatureResult::from_execution(&feature.path, result, feature.duration_ms)
+                }
+                None => FeatureResult {
+                    name: feature_name(&feature.path),
+                    path: feature.path,
+                    status: feature.status,
+                    scenarios_passed: 0,
+                    scenarios_failed: 0,
+                    duration_ms: feature.duration_ms,
+                    result: None,
+                },
+            })
+            .collect::<Vec<_>>();
+        let errors = batch
+            .errors
+            .into_iter()
+            .map(|error| BatchError {
+                path: error.path,
+                error: error.error,
+                timestamp: String::new(),
+            })
+            .collect();
+        let mut result = BatchResult::new(results, errors, batch.batch_summary.duration_ms);
+        result.aborted = batch.batch_summary.aborted;
+        Ok(PreviousRun::Batch(result))
+    }
+
+    /// Feature files with failed scenarios, in the order they were run
+    ///
+    /// A feature that failed without scenario results (it could not be parsed or
+    /// timed out), or whose failed scenarios have no recorded location, is run whole.
+    pub fn failed(&self) -> Result<Vec<RerunTarget>> {
+        match self {
+            PreviousRun::Feature(result) => {
+                let file = result.feature.file.as_ref().ok_or_else(|| {
+                    WebSpecError::Conversion("Result has no feature file path".to_string())
+                })?;
+                Ok(failed_target(Path::new(file), Some(result))
+                    .into_iter()
+                    .collect())
+            }
+            PreviousRun::Batch(batch) => Ok(batch
+                .results
+                .iter()
+                .filter(|feature| feature.status == "failed")
+                .filter_map(|feature| failed_target(&feature.path, feature.result.as_ref()))
+                .collect()),
+        }
+    }
+
+    /// Duration of each feature file in this run, for balancing shards
+    pub fn durations(&self) -> HashMap<PathBuf, u64> {
+        match self {
+            PreviousRun::Feature(result) => result
+                .feature
+                .file
+                .iter()
+                .map(|file| (PathBuf::from(file), result.duration_ms))
+                .collect(),
+            PreviousRun::Batch(batch) => batch
+                .results
+                .iter()
+                .map(|feature| (feature.path.clone(), feature.duration_ms))
+                .collect(),
+        }
+    }
+
+    /// Replace the outcomes of re-run scenarios with their new results
+    pub fn merge(self, reruns: Vec<ExecutionResult>) -> Self {
+        match self {
+            PreviousRun::Feature(previous) => {
+                let merged = reruns
+                    .into_iter()
+                    .fold(previous, |previous, rerun| merge_results(&previous, rerun));
+                PreviousRun::Feature(merged)
+            }
+            PreviousRun::Batch(batch) => {
+                let mut results = batch.results;
+                let mut errors = batch.errors;
+                let mut duration_ms = batch.total_duration_ms;
+                for rerun in reruns {
+                    let Some(file) = rerun.feature.file.clone().map(PathBuf::from) else {
+                        continue;
+                    };
+                    let Some(feature) = results.iter_mut().find(|f| f.path == file) else {
+                        continue;
+                    };
+                    duration_ms += rerun.duration_ms;
+                    let duration = feature.duration_ms + rerun.duration_ms;
+                    let merged = match &feature.result {
+                        Some(previous) => merge_results(previous, rerun),
+                        None => rerun,
+                    };
+                    *feature = FeatureResult::from_execution(&file, merged, duration);
+                    errors.retain(|e| e.path != file);
+                }
+                // Skipped features are not re-run, so the run stays aborted
+                let aborted = batch.aborted.filter(|_| {
+                    results.iter().any(|r| {
+                        r.status == "skipped"
+                            || r.result
+                                .as_ref()
+                                .is_some_and(|r| r.summary.aborted.is_some())
+                    })
+                });
+                let mut result = BatchResult::new(results, errors, duration_ms);
+                result.aborted = aborted;
+                PreviousRun::Batch(result)
+            }
+        }
+    }
+}
+
+/// Previous feature result with the re-run scenarios' outcomes swapped in
+///
+/// Scenarios are matched by location, or by name when either has none; new
+/// scenarios are appended. Summary and status are recomputed from the scenarios.
+pub fn merge_results(previous: &ExecutionResult, rerun: ExecutionResult) -> ExecutionResult {
+    let mut scenarios = previous.scenarios.clone();
+    for scenario in rerun.scenarios {
+        match scenarios.iter_mut().find(|s| same_scenario(s, &scenario)) {
+            Some(existing) => *existing = scenario,
+            None => scenarios.push(scenario),
+        }
+    }
+
+    let mut merged = ExecutionResult::new(previous.feature.clone());
+    merged.timestamp = rerun.timestamp;
+    merged.duration_ms = previous.duration_ms + rerun.duration_ms;
+    merged.summary = ExecutionSummary::new();
+    for scenario in scenarios {
+        merged.summary.add_scenario_result(&scenario);
+        merged.add_scenario(scenario);
+    }
+    merged.update_status();
+    // Aborted scenarios are not re-run, so the result stays aborted while any remain
+    if merged.scenarios.iter().any(|s| s.aborted) {
+        merged.summary.aborted = previous.summary.aborted.clone();
+    }
+    merged
+}
+
+fn failed_target(file: &Path, result: Option<&ExecutionResult>) -> Option<RerunTarget> {
+    let Some(result) = result else {
+        return Some(RerunTarget {
+            file: file.to_path_buf(),
+            locations: None,
+        });
+    };
+    let failed: Vec<&ScenarioResult> = result
+        .scenarios
+        .iter()
+        .filter(|s| s.status == "failed")
+        .collect();
+    if failed.is_empty() {
+        return None;
+    }
+    Some(RerunTarget {
+        file: file.to_path_buf(),
+        locations: failed.iter().map(|s| s.location).collect(),
+    })
+}
+
+fn same_scenario(a: &ScenarioResult, b: &ScenarioResult) -> bool {
+    match (a.location, b.location) {
+        (Some(a), Some(b)) => a == b,
+        _ => a.name == b.name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::execution::{FeatureInfo, StepResult};
+
+    fn scenario(name: &str, line: usize, status: &str) -> ScenarioResult {
+        let mut scenario =
+            ScenarioResult::new(name.to_string()).with_location(Location::new(line, 3));
+        scenario.add_step(
+            StepResult::new("Step 1".to_string(), "Given".to_string()).with_status(status),
+        );
+        scenario.update_status();
+        scenario
+    }
+
+    fn feature_result(file: &str, scenarios: Vec<ScenarioResult>) -> ExecutionResult {
+        let mut result = ExecutionResult::new(FeatureInfo {
+            name: "Checkout".to_string(),
+            file: Some(file.to_string()),
+            description: None,
+        });
+        for scenario in scenarios {
+            result.summary.add_scenario_result(&scenario);
+            result.add_scenario(scenario);
+        }
+        result.update_status();
+        result
+    }
+
+    #[test]
+    fn test_failed_scenarios_of_a_feature_result() {
+        let previous = feature_result(
+            "features/checkout.feature",
+            vec![
+                scenario("Pay", 3, "passed"),
+                scenario("Refund", 7, "failed"),
+            ],
+        );
+        let json = serde_json::to_string(&previous).unwrap();
+        let run = PreviousRun::from_json(&json).unwrap();
+
+        assert_eq!(
+            run.failed().unwrap(),
+            vec![RerunTarget {
+                file: PathBuf::from("features/checkout.feature"),
+                locations: Some(vec![Location::new(7, 3)]),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_failed_features_of_a_batch_result() {
+        let json = serde_json::json!({
+            "batch_summary": { "total_features": 3 },
+            "features": [
+                {
+                    "path": "a.feature",
+                    "status": "passed",
+                    "duration_ms": 10,
+                    "result": feature_result("a.feature", vec![scenario("Pay", 3, "passed")]),
+                },
+                {
+                    "path": "b.feature",
+                    "status": "failed",
+                    "duration_ms": 10,
+                    "result": feature_result("b.feature", vec![scenario("Refund", 5, "failed")]),
+                },
+                { "path": "c.feature", "status": "failed", "duration_ms": 10, "result": null },
+            ],
+            "errors": [{ "path": "c.feature", "error": "Timed out after 300 seconds" }],
+        });
+        let run = PreviousRun::from_json(&json.to_string()).unwrap();
+
+        let targets = run.failed().unwrap();
+        assert_eq!(targets.len(), 2);
+        assert_eq!(targets[0].locations, Some(vec![Location::new(5, 3)]));
+        assert_eq!(targets[1].file, PathBuf::from("c.feature"));
+        assert_eq!(targets[1].locations, None);
+
+        let rerun = feature_result("c.feature", vec![scenario("Ship", 3, "passed")]);
+        let PreviousRun::Batch(merged) = run.merge(vec![rerun]) else {
+            panic!("expected a batch result");
+        };
+        assert_eq!(merged.failed_features, 1);
+        assert_eq!(merged.passed_features, 2);
+        assert!(merged.errors.is_empty());
+    }
+
+    #[test]
+    fn test_merge_replaces_rerun_scenarios() {
+        let previous = feature_result(
+            "checkout.feature",
+            vec![
+                scenario("Pay", 3, "passed"),
+                scenario("Refund", 7, "failed"),
+            ],
+        );
+        let rerun = feature_result("checkout.feature", vec![scenario("Refund", 7, "passed")]);
+
+        let merged = merge_results(&previous, rerun);
+        assert_eq!(merged.status, "passed");
+        assert_eq!(merged.scenarios.len(), 2);
+        assert_eq!(merged.scenarios[1].status, "passed");
+        assert_eq!(merged.summary.passed_scenarios, 2);
+        assert_eq!(merged.summary.failed_scenarios, 0);
+    }
+    #[test]
+    fn test_merge_stays_aborted_while_skipped_scenarios_remain() {
+        let reason = "Stopped after 1 failed scenario (max failures: 1)";
+        let mut skipped = scenario("Ship", 9, "skipped");
+        skipped.aborted = true;
+        let mut previous = feature_result(
+            "checkout.feature",
+            vec![scenario("Refund", 7, "failed"), skipped],
+        );
+        previous.summary.aborted = Some(reason.to_string());
+
+        let json = serde_json::to_string(&previous).unwrap();
+        let PreviousRun::Feature(read_back) = PreviousRun::from_json(&json).unwrap() else {
+            panic!("expected a feature result");
+        };
+        assert_eq!(read_back.summary.aborted.as_deref(), Some(reason));
+
+        let rerun = feature_result("checkout.feature", vec![scenario("Refund", 7, "passed")]);
+        let merged = merge_results(&read_back, rerun);
+        assert_eq!(merged.summary.aborted.as_deref(), Some(reason));
+
+        let mut completed = read_back.clone();
+        completed.scenarios[1].aborted = false;
+        let rerun = feature_result("checkout.feature", vec![scenario("Refund", 7, "passed")]);
+        assert_eq!(merge_results(&completed, rerun).summary.aborted, None);
+    }
+}
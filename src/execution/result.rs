@@ -1,4 +1,5 @@
 // Execution result types
+use crate::gherkin::Location;
 use serde::{Deserialize, Serialize};
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -10,6 +11,9 @@ pub struct ExecutionResult {
     pub feature: FeatureInfo,
     pub scenarios: Vec<ScenarioResult>,
     pub summary: ExecutionSummary,
+    /// Seed of a random scenario order; `--order random:SEED` runs the same order again
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,7 +26,25 @@ pub struct FeatureInfo {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScenarioResult {
     pub name: String,
-    pub status: String, // "passed", "failed", "skipped"
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub status: String, // "passed", "failed", "flaky", "skipped"
+    pub duration_ms: u64,
+    pub steps: Vec<StepResult>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+    /// Every attempt of a retried scenario, in order; empty when it ran once
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attempts: Vec<AttemptResult>,
+    /// Skipped without running because the run's failure limit was reached
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub aborted: bool,
+}
+
+/// One run of a retried scenario
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttemptResult {
+    pub status: String,
     pub duration_ms: u64,
     pub steps: Vec<StepResult>,
 }
@@ -31,10 +53,12 @@ pub struct ScenarioResult {
 pub struct StepResult {
     pub text: String,
     pub keyword: String,
-    pub status: String, // "passed", "failed", "skipped"
+    pub status: String, // "passed", "failed", "timeout", "skipped"
     pub duration_ms: u64,
     pub output: Option<String>,
     pub error: Option<ErrorInfo>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -50,10 +74,16 @@ pub struct ExecutionSummary {
     pub passed_scenarios: usize,
     pub failed_scenarios: usize,
     pub skipped_scenarios: usize,
+    /// Scenarios that passed only after being retried
+    #[serde(default)]
+    pub flaky_scenarios: usize,
     pub total_steps: usize,
     pub passed_steps: usize,
     pub failed_steps: usize,
     pub skipped_steps: usize,
+    /// Why scenarios were skipped without running, e.g. the failure limit was reached
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aborted: Option<String>,
 }
 
 impl ExecutionResult {
@@ -65,6 +95,7 @@ impl ExecutionResult {
             feature,
             scenarios: Vec::new(),
             summary: ExecutionSummary::new(),
+            seed: None,
         }
     }
 
@@ -91,10 +122,12 @@ impl ExecutionSummary {
             passed_scenarios: 0,
             failed_scenarios: 0,
             skipped_scenarios: 0,
+            flaky_scenarios: 0,
             total_steps: 0,
             passed_steps: 0,
             failed_steps: 0,
             skipped_steps: 0,
+            aborted: None,
         }
     }
 
@@ -104,6 +137,7 @@ impl ExecutionSummary {
             "passed" => self.passed_scenarios += 1,
             "failed" => self.failed_scenarios += 1,
             "skipped" => self.skipped_scenarios += 1,
+            "flaky" => self.flaky_scenarios += 1,
             _ => {}
         }
 
@@ -111,7 +145,7 @@ impl ExecutionSummary {
             self.total_steps += 1;
             match step.status.as_str() {
                 "passed" => self.passed_steps += 1,
-                "failed" => self.failed_steps += 1,
+                "failed" | "timeout" => self.failed_steps += 1,
                 "skipped" => self.skipped_steps += 1,
                 _ => {}
             }
@@ -129,19 +163,33 @@ impl ScenarioResult {
     pub fn new(name: String) -> Self {
         ScenarioResult {
             name,
+            tags: Vec::new(),
             status: "pending".to_string(),
             duration_ms: 0,
             steps: Vec::new(),
+            location: None,
+            attempts: Vec::new(),
+            aborted: false,
         }
     }
 
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    pub fn with_location(mut self, location: Location) -> Self {
+        self.location = Some(location);
+        self
+    }
+
     pub fn add_step(&mut self, step: StepResult) {
         self.steps.push(step);
     }
 
     pub fn update_status(&mut self) {
         // Determine status based on steps
-        if self.steps.iter().any(|s| s.status == "failed") {
+        if self.steps.iter().any(StepResult::is_failure) {
             self.status = "failed".to_string();
         } else if self.steps.iter().all(|s| s.status == "skipped") {
             self.status = "skipped".to_string();
@@ -149,6 +197,29 @@ impl ScenarioResult {
             self.status = "passed".to_string();
         }
     }
+
+    /// Final attempt of a retried scenario, with `earlier` attempts kept as history
+    ///
+    /// A scenario that passes after failing is reported as "flaky".
+    pub fn with_earlier_attempts(mut self, earlier: Vec<ScenarioResult>) -> Self {
+        if earlier.is_empty() {
+            return self;
+        }
+        self.attempts = earlier
+            .into_iter()
+            .chain(std::iter::once(self.clone()))
+            .map(|attempt| AttemptResult {
+                status: attempt.status,
+                duration_ms: attempt.duration_ms,
+                steps: attempt.steps,
+            })
+            .collect();
+        self.duration_ms = self.attempts.iter().map(|a| a.duration_ms).sum();
+        if self.status == "passed" {
+            self.status = "flaky".to_string();
+        }
+        self
+    }
 }
 
 impl StepResult {
@@ -160,6 +231,7 @@ impl StepResult {
             duration_ms: 0,
             output: None,
             error: None,
+            location: None,
         }
     }
 
@@ -177,6 +249,16 @@ impl StepResult {
         self.error = Some(error);
         self
     }
+
+    pub fn with_location(mut self, location: Location) -> Self {
+        self.location = Some(location);
+        self
+    }
+
+    /// Whether the step failed, including by timing out
+    pub fn is_failure(&self) -> bool {
+        self.status == "failed" || self.status == "timeout"
+    }
 }
 
 impl ErrorInfo {
@@ -231,11 +313,29 @@ mod tests {
         assert_eq!(scenario.status, "passed");
     }
 
+    #[test]
+    fn test_timed_out_step_fails_scenario() {
+        let mut scenario = ScenarioResult::new("Test Scenario".to_string());
+        scenario.add_step(
+            StepResult::new("Step 1".to_string(), "Given".to_string()).with_status("passed"),
+        );
+        scenario.add_step(
+            StepResult::new("Step 2".to_string(), "When".to_string()).with_status("timeout"),
+        );
+        scenario.update_status();
+        assert_eq!(scenario.status, "failed");
+
+        let mut summary = ExecutionSummary::new();
+        summary.add_scenario_result(&scenario);
+        assert_eq!(summary.failed_steps, 1);
+    }
+
     #[test]
     fn test_summary_calculation() {
         let mut summary = ExecutionSummary::new();
         let scenario = ScenarioResult {
             name: "Test".to_string(),
+            tags: vec![],
             status: "passed".to_string(),
             duration_ms: 100,
             steps: vec![StepResult {
@@ -245,11 +345,49 @@ mod tests {
                 duration_ms: 50,
                 output: None,
                 error: None,
+                location: None,
             }],
+            location: None,
+            attempts: Vec::new(),
+            aborted: false,
         };
         summary.add_scenario_result(&scenario);
         assert_eq!(summary.total_scenarios, 1);
         assert_eq!(summary.total_steps, 1);
         assert_eq!(summary.passed_steps, 1);
     }
+
+    #[test]
+    fn test_retried_scenario_that_passes_is_flaky() {
+        let attempt = |status: &str, duration_ms: u64| {
+            let mut scenario = ScenarioResult::new("Checkout".to_string());
+            scenario.add_step(
+                StepResult::new("Step 1".to_string(), "Given".to_string()).with_status(status),
+            );
+            scenario.update_status();
+            scenario.duration_ms = duration_ms;
+            scenario
+        };
+
+        let scenario = attempt("passed", 30).with_earlier_attempts(vec![attempt("failed", 100)]);
+        assert_eq!(scenario.status, "flaky");
+        assert_eq!(scenario.duration_ms, 130);
+        let durations: Vec<u64> = scenario.attempts.iter().map(|a| a.duration_ms).collect();
+        assert_eq!(durations, vec![100, 30]);
+        let statuses: Vec<&str> = scenario
+            .attempts
+            .iter()
+            .map(|a| a.status.as_str())
+            .collect();
+        assert_eq!(statuses, vec!["failed", "passed"]);
+
+        let mut summary = ExecutionSummary::new();
+        summary.add_scenario_result(&scenario);
+        assert_eq!(summary.flaky_scenarios, 1);
+        assert_eq!(summary.failed_steps, 0);
+
+        let scenario = attempt("failed", 50).with_earlier_attempts(vec![attempt("failed", 100)]);
+        assert_eq!(scenario.status, "failed");
+        assert_eq!(scenario.attempts.len(), 2);
+    }
 }
@@ -0,0 +1,263 @@
+// Splitting batch features across CI machines and merging their results
+use crate::execution::batch::{BatchResult, FeatureResult};
+use crate::execution::rerun::PreviousRun;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// One of `total` shards, numbered from 1, written as `2/5`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shard {
+    pub index: usize,
+    pub total: usize,
+}
+
+impl Shard {
+    pub fn new(index: usize, total: usize) -> Result<Self, String> {
+        if total == 0 || index == 0 || index > total {
+            return Err(format!(
+                "Invalid shard {}/{}: expected INDEX/TOTAL with 1 <= INDEX <= TOTAL",
+                index, total
+            ));
+        }
+        Ok(Shard { index, total })
+    }
+
+    /// Features of this shard, chosen by a stable hash of each path
+    ///
+    /// Every machine given the same paths picks disjoint shards that together
+    /// cover all of them, whatever order the paths are in.
+    pub fn select(&self, paths: &[PathBuf]) -> Vec<PathBuf> {
+        paths
+            .iter()
+            .filter(|path| stable_hash(path) % self.total as u64 == (self.index - 1) as u64)
+            .cloned()
+            .collect()
+    }
+
+    /// Features of this shard, balanced by their durations in an earlier run
+    ///
+    /// The longest features are handed out first, each to the shard with the least
+    /// work so far. Features missing from `durations` count as the average one.
+    pub fn select_balanced(
+        &self,
+        paths: &[PathBuf],
+        durations: &HashMap<PathBuf, u64>,
+    ) -> Vec<PathBuf> {
+        let known: Vec<u64> = paths
+            .iter()
+            .filter_map(|p| durations.get(p))
+            .copied()
+            .collect();
+        let average = match known.len() {
+            0 => 1,
+            n => known.iter().sum::<u64>() / n as u64,
+        };
+
+        let mut ordered: Vec<(&PathBuf, u64)> = paths
+            .iter()
+            .map(|p| (p, durations.get(p).copied().unwrap_or(average)))
+            .collect();
+        ordered.sort_by(|(a, a_ms), (b, b_ms)| b_ms.cmp(a_ms).then_with(|| a.cmp(b)));
+
+        let mut loads = vec![0u64; self.total];
+        let mut assigned = Vec::new();
+        for (path, duration_ms) in ordered {
+            let shard = (0..self.total).min_by_key(|&s| (loads[s], s)).unwrap_or(0);
+            loads[shard] += duration_ms;
+            if shard == self.index - 1 {
+                assigned.push(path.clone());
+            }
+        }
+        // Keep the discovery order within the shard
+        assigned.sort_by_key(|p| paths.iter().position(|q| q == p));
+        assigned
+    }
+}
+
+impl FromStr for Shard {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (index, total) = s
+            .split_once('/')
+            .ok_or_else(|| format!("Invalid shard '{}': expected INDEX/TOTAL, e.g. 2/5", s))?;
+        let parse = |n: &str| {
+            n.trim()
+                .parse::<usize>()
+                .map_err(|_| format!("Invalid shard '{}': expected INDEX/TOTAL, e.g. 2/5", s))
+        };
+        Shard::new(parse(index)?, parse(total)?)
+    }
+}
+
+impl fmt::Display for Shard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.index, self.total)
+    }
+}
+
+/// Combine the results of several shards into one batch result
+///
+/// Features are ordered by path, like `discover_features`; the duration is that of
+/// the slowest shard, since shards run side by side. The merge is aborted if any
+/// shard was, with the first shard's reason.
+pub fn merge_shards(runs: Vec<PreviousRun>) -> BatchResult {
+    let mut results = Vec::new();
+    let mut errors = Vec::new();
+    let mut duration_ms = 0;
+    let mut aborted = None;
+    for run in runs {
+        match run {
+            PreviousRun::Feature(result) => {
+                let path = PathBuf::from(result.feature.file.clone().unwrap_or_default());
+                duration_ms = duration_ms.max(result.duration_ms);
+                aborted = aborted.or_else(|| result.summary.aborted.clone());
+                let duration = result.duration_ms;
+                results.push(FeatureResult::from_execution(&path, result, duration));
+            }
+            PreviousRun::Batch(batch) => {
+                duration_ms = duration_ms.max(batch.total_duration_ms);
+                aborted = aborted.or(batch.aborted);
+                results.extend(batch.results);
+                errors.extend(batch.errors);
+            }
+        }
+    }
+    results.sort_by(|a, b| a.path.cmp(&b.path));
+    errors.sort_by(|a, b| a.path.cmp(&b.path));
+    let mut result = BatchResult::new(results, errors, duration_ms);
+    result.aborted = aborted;
+    result
+}
+
+/// FNV-1a over the path with `/` separators, identical on every machine and platform
+fn stable_hash(path: &Path) -> u64 {
+    let text = path.to_string_lossy().replace('\\', "/");
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paths(n: usize) -> Vec<PathBuf> {
+        (0..n)
+            .map(|i| PathBuf::from(format!("features/f{:02}.feature", i)))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_shard() {
+        assert_eq!("2/5".parse::<Shard>(), Ok(Shard { index: 2, total: 5 }));
+        assert!("0/5".parse::<Shard>().is_err());
+        assert!("6/5".parse::<Shard>().is_err());
+        assert!("2".parse::<Shard>().is_err());
+        assert_eq!(Shard::new(1, 3).unwrap().to_string(), "1/3");
+    }
+
+    #[test]
+    fn test_hashed_shards_partition_the_features() {
+        let all = paths(40);
+        let mut reversed = all.clone();
+        reversed.reverse();
+
+        let mut covered = Vec::new();
+        for index in 1..=4 {
+            let shard = Shard::new(index, 4).unwrap();
+            let mut selected = shard.select(&all);
+            let mut from_reversed = shard.select(&reversed);
+            selected.sort();
+            from_reversed.sort();
+            assert_eq!(selected, from_reversed);
+            covered.extend(selected);
+        }
+        covered.sort();
+        assert_eq!(covered, all);
+    }
+
+    #[test]
+    fn test_balanced_shards_split_by_duration() {
+        let all = paths(4);
+        let durations: HashMap<PathBuf, u64> = [
+            (all[0].clone(), 900),
+            (all[1].clone(), 100),
+            (all[2].clone(), 500),
+            (all[3].clone(), 400),
+        ]
+        .into_iter()
+        .collect();
+
+        let first = Shard::new(1, 2).unwrap().select_balanced(&all, &durations);
+        let second = Shard::new(2, 2).unwrap().select_balanced(&all, &durations);
+        assert_eq!(first, vec![all[0].clone(), all[1].clone()]);
+        assert_eq!(second, vec![all[2].clone(), all[3].clone()]);
+    }
+
+    #[test]
+    fn test_merge_shards_orders_features_by_path() {
+        let feature = |path: &str, status: &str| FeatureResult {
+            name: path.to_string(),
+            path: PathBuf::from(path),
+            status: status.to_string(),
+            scenarios_passed: usize::from(status == "passed"),
+            scenarios_failed: usize::from(status == "failed"),
+            duration_ms: 100,
+            result: None,
+        };
+        let shard = |results: Vec<FeatureResult>, duration_ms: u64| {
+            PreviousRun::Batch(BatchResult::new(results, Vec::new(), duration_ms))
+        };
+
+        let merged = merge_shards(vec![
+            shard(vec![feature("b.feature", "failed")], 300),
+            shard(vec![feature("a.feature", "passed")], 200),
+        ]);
+        assert_eq!(merged.total_features, 2);
+        assert_eq!(merged.passed_features, 1);
+        assert_eq!(merged.failed_features, 1);
+        assert_eq!(merged.total_duration_ms, 300);
+        assert_eq!(merged.results[0].path, PathBuf::from("a.feature"));
+    }
+    #[test]
+    fn test_merge_shards_keeps_an_aborted_shard() {
+        let feature = |path: &str, status: &str| FeatureResult {
+            name: path.to_string(),
+            path: PathBuf::from(path),
+            status: status.to_string(),
+            scenarios_passed: 0,
+            scenarios_failed: usize::from(status == "failed"),
+            duration_ms: 100,
+            result: None,
+        };
+        let reason = "Stopped after a feature failed (continue on failure is off)";
+        let mut aborted = BatchResult::new(
+            vec![
+                feature("a.feature", "failed"),
+                feature("b.feature", "skipped"),
+            ],
+            Vec::new(),
+            200,
+        );
+        aborted.aborted = Some(reason.to_string());
+        let completed = BatchResult::new(vec![feature("c.feature", "failed")], Vec::new(), 100);
+
+        // Shards are merged from their JSON output
+        let written = |result: &BatchResult| {
+            let json = crate::execution::BatchExecutor::new().format_result(result, "json");
+            PreviousRun::from_json(&json).unwrap()
+        };
+        let PreviousRun::Batch(read_back) = written(&aborted) else {
+            panic!("expected a batch result");
+        };
+        assert_eq!(read_back.aborted.as_deref(), Some(reason));
+
+        let merged = merge_shards(vec![written(&completed), written(&aborted)]);
+        assert_eq!(merged.aborted.as_deref(), Some(reason));
+        let merged = merge_shards(vec![written(&completed), written(&completed)]);
+        assert_eq!(merged.aborted, None);
+    }
+}
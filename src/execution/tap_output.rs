@@ -31,13 +31,36 @@ pub fn to_tap_output(result: &ExecutionResult) -> String {
     if let Some(ref file) = result.feature.file {
         output.push_str(&format!("# File: {}\n", file));
     }
+    if let Some(seed) = result.seed {
+        output.push_str(&format!("# Seed: {}\n", seed));
+    }
 
     // Test lines (one per scenario)
     let mut test_number = 1;
     for scenario in &result.scenarios {
-        let is_passed = scenario.status == "passed";
+        if scenario.aborted {
+            let reason = result.summary.aborted.as_deref().unwrap_or("run aborted");
+            output.push_str(&format!(
+                "ok {} {} # SKIP {}\n",
+                test_number, scenario.name, reason
+            ));
+            test_number += 1;
+            continue;
+        }
+        let is_passed = scenario.status == "passed" || scenario.status == "flaky";
         let status = if is_passed { "ok" } else { "not ok" };
         output.push_str(&format!("{} {} {}\n", status, test_number, scenario.name));
+        if scenario.status == "flaky" {
+            output.push_str(&format!(
+                "# flaky: passed on attempt {}\n",
+                scenario.attempts.len()
+            ));
+        }
+        if scenario.attempts.len() > 1 {
+            for (idx, attempt) in scenario.attempts.iter().enumerate() {
+                output.push_str(&format!("# attempt {}: {}\n", idx + 1, attempt.status));
+            }
+        }
 
         // Add diagnostic info for failures
         if !is_passed && !scenario.steps.is_empty() {
@@ -48,6 +71,13 @@ pub fn to_tap_output(result: &ExecutionResult) -> String {
                     "  message: |\n    Step failed: {}\n",
                     failed_step.text
                 ));
+                if let Some(location) = failed_step.location.or(scenario.location) {
+                    if let Some(ref file) = result.feature.file {
+                        output.push_str(&format!("  file: {}\n", file));
+                    }
+                    output.push_str(&format!("  line: {}\n", location.line));
+                    output.push_str(&format!("  column: {}\n", location.column));
+                }
                 output.push_str("  ...\n");
             }
         }
@@ -119,13 +149,16 @@ impl TapSummary {
 mod tests {
     use super::*;
     use crate::execution::{
-        ExecutionResult, ExecutionSummary, FeatureInfo, ScenarioResult, StepResult,
+        AttemptResult, ErrorInfo, ExecutionResult, ExecutionSummary, FeatureInfo, ScenarioResult,
+        StepResult,
     };
+    use crate::gherkin::Location;
 
     fn create_test_result() -> ExecutionResult {
         ExecutionResult {
             status: "failed".to_string(),
             timestamp: "2024-01-01T00:00:00Z".to_string(),
+            seed: None,
             duration_ms: 150,
             feature: FeatureInfo {
                 name: "Test Feature".to_string(),
@@ -135,12 +168,17 @@ mod tests {
             scenarios: vec![
                 ScenarioResult {
                     name: "Scenario 1".to_string(),
+                    tags: vec![],
                     status: "passed".to_string(),
                     duration_ms: 100,
                     steps: vec![],
+                    location: None,
+                    attempts: Vec::new(),
+                    aborted: false,
                 },
                 ScenarioResult {
                     name: "Scenario 2".to_string(),
+                    tags: vec![],
                     status: "failed".to_string(),
                     duration_ms: 50,
                     steps: vec![StepResult {
@@ -150,7 +188,11 @@ mod tests {
                         duration_ms: 50,
                         output: None,
                         error: None,
+                        location: Some(Location::new(7, 5)),
                     }],
+                    location: None,
+                    attempts: Vec::new(),
+                    aborted: false,
                 },
             ],
             summary: ExecutionSummary {
@@ -158,10 +200,12 @@ mod tests {
                 passed_scenarios: 1,
                 failed_scenarios: 1,
                 skipped_scenarios: 0,
+                flaky_scenarios: 0,
                 total_steps: 1,
                 passed_steps: 0,
                 failed_steps: 1,
                 skipped_steps: 0,
+                aborted: None,
             },
         }
     }
@@ -185,6 +229,7 @@ mod tests {
         // Should include error diagnostic for failed scenario
         assert!(tap.contains("---"));
         assert!(tap.contains("message:"));
+        assert!(tap.contains("  file: test.feature\n  line: 7\n  column: 5\n"));
     }
 
     #[test]
@@ -223,4 +268,53 @@ not ok 2 Scenario 2
         };
         assert!(!failure.success());
     }
+
+    #[test]
+    fn test_tap_output_reports_flaky_scenarios_as_ok() {
+        let mut result = create_test_result();
+        result.scenarios[0].status = "flaky".to_string();
+        result.scenarios[0].attempts = vec![
+            AttemptResult {
+                status: "failed".to_string(),
+                duration_ms: 50,
+                steps: vec![],
+            },
+            AttemptResult {
+                status: "passed".to_string(),
+                duration_ms: 50,
+                steps: vec![],
+            },
+        ];
+        let tap = to_tap_output(&result);
+
+        assert!(tap.contains(
+            "ok 1 Scenario 1\n# flaky: passed on attempt 2\n# attempt 1: failed\n# attempt 2: passed\n"
+        ));
+
+        result.scenarios[0].status = "failed".to_string();
+        result.scenarios[0].attempts[1].status = "failed".to_string();
+        let tap = to_tap_output(&result);
+
+        assert!(tap.contains("not ok 1 Scenario 1\n# attempt 1: failed\n# attempt 2: failed\n"));
+    }
+
+    #[test]
+    fn test_tap_output_skips_aborted_scenarios() {
+        let mut result = create_test_result();
+        result.summary.aborted =
+            Some("Stopped after 1 failed scenario(s) (max failures: 1)".to_string());
+        result.scenarios[1].status = "skipped".to_string();
+        result.scenarios[1].aborted = true;
+        result.scenarios[1].steps[0].status = "skipped".to_string();
+        result.scenarios[1].steps[0].error = Some(ErrorInfo::new(
+            crate::runner::ABORTED,
+            "Stopped after 1 failed scenario(s) (max failures: 1)",
+        ));
+        let tap = to_tap_output(&result);
+
+        assert!(tap.contains(
+            "ok 2 Scenario 2 # SKIP Stopped after 1 failed scenario(s) (max failures: 1)\n"
+        ));
+        assert_eq!(parse_tap_output(&tap).failed, 0);
+    }
 }
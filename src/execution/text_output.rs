@@ -18,7 +18,11 @@ pub fn to_text_output(result: &ExecutionResult) -> String {
     }
     output.push_str(&format!("Status: {}\n", result.status));
     output.push_str(&format!("Duration: {}ms\n", result.duration_ms));
-    output.push_str(&format!("Timestamp: {}\n\n", result.timestamp));
+    output.push_str(&format!("Timestamp: {}\n", result.timestamp));
+    if let Some(seed) = result.seed {
+        output.push_str(&format!("Seed: {}\n", seed));
+    }
+    output.push('\n');
 
     // Scenarios
     output.push_str(&format!("Scenarios: {}\n", result.scenarios.len()));
@@ -29,13 +33,31 @@ pub fn to_text_output(result: &ExecutionResult) -> String {
             scenario.name,
             scenario.status
         ));
+        if let Some(location) = &scenario.location {
+            output.push_str(&format!(
+                "     Location: {}\n",
+                location.describe(result.feature.file.as_deref())
+            ));
+        }
+        if !scenario.tags.is_empty() {
+            output.push_str(&format!("     Tags: {}\n", scenario.tags.join(" ")));
+        }
         output.push_str(&format!("     Duration: {}ms\n", scenario.duration_ms));
+        if !scenario.attempts.is_empty() {
+            let statuses: Vec<&str> = scenario
+                .attempts
+                .iter()
+                .map(|attempt| attempt.status.as_str())
+                .collect();
+            output.push_str(&format!("     Attempts: {}\n", statuses.join(", ")));
+        }
 
         // Steps
         for (step_idx, step) in scenario.steps.iter().enumerate() {
             let symbol = match step.status.as_str() {
                 "passed" => "✓",
                 "failed" => "✗",
+                "timeout" => "⏱",
                 "skipped" => "⊘",
                 _ => "?",
             };
@@ -49,6 +71,12 @@ pub fn to_text_output(result: &ExecutionResult) -> String {
 
             if let Some(error) = &step.error {
                 output.push_str(&format!("        Error: {}\n", error.message));
+                if let Some(location) = &step.location {
+                    output.push_str(&format!(
+                        "        At: {}\n",
+                        location.describe(result.feature.file.as_deref())
+                    ));
+                }
                 if !error.suggestions.is_empty() {
                     output.push_str("        Suggestions:\n");
                     for suggestion in &error.suggestions {
@@ -68,8 +96,9 @@ pub fn to_text_output(result: &ExecutionResult) -> String {
     // Summary
     output.push_str("\n=== Summary ===\n");
     output.push_str(&format!(
-        "Scenarios: {} passed, {} failed, {} skipped (total: {})\n",
+        "Scenarios: {} passed, {} flaky, {} failed, {} skipped (total: {})\n",
         result.summary.passed_scenarios,
+        result.summary.flaky_scenarios,
         result.summary.failed_scenarios,
         result.summary.skipped_scenarios,
         result.summary.total_scenarios
@@ -81,6 +110,9 @@ pub fn to_text_output(result: &ExecutionResult) -> String {
         result.summary.skipped_steps,
         result.summary.total_steps
     ));
+    if let Some(reason) = &result.summary.aborted {
+        output.push_str(&format!("Aborted: {}\n", reason));
+    }
 
     output
 }
@@ -88,7 +120,8 @@ pub fn to_text_output(result: &ExecutionResult) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::execution::FeatureInfo;
+    use crate::execution::{ErrorInfo, FeatureInfo, ScenarioResult, StepResult};
+    use crate::gherkin::Location;
 
     #[test]
     fn test_text_output_formatting() {
@@ -106,4 +139,39 @@ mod tests {
         assert!(text.contains("Description: A test feature"));
         assert!(text.contains("=== Summary ==="));
     }
+
+    #[test]
+    fn test_text_output_shows_locations() {
+        let feature = FeatureInfo {
+            name: "Test Feature".to_string(),
+            file: Some("test.feature".to_string()),
+            description: None,
+        };
+        let mut result = ExecutionResult::new(feature);
+        let mut scenario =
+            ScenarioResult::new("Login".to_string()).with_location(Location::new(3, 3));
+        scenario.add_step(
+            StepResult::new("I click on \"#go\"".to_string(), "When".to_string())
+                .with_status("failed")
+                .with_error(ErrorInfo::new("STEP_FAILED", "Element not found"))
+                .with_location(Location::new(5, 5)),
+        );
+        result.add_scenario(scenario);
+
+        let text = to_text_output(&result);
+        assert!(text.contains("Location: test.feature:3:3"));
+        assert!(text.contains("At: test.feature:5:5"));
+    }
+
+    #[test]
+    fn test_text_output_shows_seed() {
+        let mut result = ExecutionResult::new(FeatureInfo {
+            name: "Test Feature".to_string(),
+            file: None,
+            description: None,
+        });
+        assert!(!to_text_output(&result).contains("Seed:"));
+        result.seed = Some(42);
+        assert!(to_text_output(&result).contains("Seed: 42\n"));
+    }
 }
@@ -471,6 +471,7 @@ mod tests {
         ExecutionResult {
             status: status.to_string(),
             timestamp: chrono::Local::now().to_rfc3339(),
+            seed: None,
             duration_ms: 1000,
             feature: FeatureInfo {
                 name: "Test Feature".to_string(),
@@ -483,10 +484,12 @@ mod tests {
                 passed_scenarios: 4,
                 failed_scenarios: 1,
                 skipped_scenarios: 0,
+                flaky_scenarios: 0,
                 total_steps: 20,
                 passed_steps: 18,
                 failed_steps: 2,
                 skipped_steps: 0,
+                aborted: None,
             },
         }
     }
@@ -0,0 +1,288 @@
+// Gherkin abstract syntax tree
+use super::tags::TagExpression;
+use serde::{Deserialize, Serialize};
+
+/// Position of an element in a feature file; line and column are 1-based
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Location {
+    pub fn new(line: usize, column: usize) -> Self {
+        Location { line, column }
+    }
+
+    /// Format as `file:line:column` (or `line:column` without a file)
+    pub fn describe(&self, file: Option<&str>) -> String {
+        match file {
+            Some(file) => format!("{}:{}:{}", file, self.line, self.column),
+            None => format!("{}:{}", self.line, self.column),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Feature {
+    pub name: String,
+    pub location: Location,
+    pub description: Option<String>,
+    pub tags: Vec<String>,
+    pub background: Option<Background>,
+    pub scenarios: Vec<Scenario>,
+    pub rules: Vec<Rule>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Rule {
+    pub name: String,
+    pub location: Location,
+    pub description: Option<String>,
+    pub tags: Vec<String>,
+    pub background: Option<Background>,
+    pub scenarios: Vec<Scenario>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Background {
+    pub name: String,
+    pub location: Location,
+    pub steps: Vec<Step>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Scenario {
+    pub name: String,
+    /// Where the scenario is written; for an expanded outline, the Examples row
+    pub location: Location,
+    pub description: Option<String>,
+    /// Tags written on the scenario; executable scenarios also carry the
+    /// tags inherited from their feature, rule and Examples table
+    pub tags: Vec<String>,
+    pub steps: Vec<Step>,
+    /// Examples tables; non-empty only for Scenario Outlines
+    pub examples: Vec<Examples>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Examples {
+    pub name: String,
+    pub location: Location,
+    pub tags: Vec<String>,
+    pub header: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+    /// Location of each entry in `rows`
+    pub row_locations: Vec<Location>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Step {
+    /// Keyword as written in the feature file (Given, When, Then, And, But or *)
+    pub keyword: String,
+    pub text: String,
+    pub location: Location,
+    /// Doc string or data table written below the step
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub argument: Option<StepArgument>,
+}
+
+/// Multi-line argument attached to a step
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StepArgument {
+    DocString {
+        /// Media type written after the opening delimiter, e.g. `json`
+        content_type: Option<String>,
+        content: String,
+    },
+    DataTable(Vec<Vec<String>>),
+}
+
+impl StepArgument {
+    pub fn doc_string(&self) -> Option<&str> {
+        match self {
+            StepArgument::DocString { content, .. } => Some(content),
+            StepArgument::DataTable(_) => None,
+        }
+    }
+
+    pub fn data_table(&self) -> Option<&[Vec<String>]> {
+        match self {
+            StepArgument::DataTable(rows) => Some(rows),
+            StepArgument::DocString { .. } => None,
+        }
+    }
+}
+
+impl Feature {
+    /// All scenarios in the feature, including those nested in rules, as written
+    pub fn all_scenarios(&self) -> Vec<&Scenario> {
+        self.scenarios
+            .iter()
+            .chain(self.rules.iter().flat_map(|r| r.scenarios.iter()))
+            .collect()
+    }
+
+    /// Scenarios ready for execution: Background steps are prepended and
+    /// Scenario Outlines are expanded into one scenario per Examples row
+    pub fn executable_scenarios(&self) -> Vec<Scenario> {
+        let mut scenarios = Vec::new();
+
+        for scenario in &self.scenarios {
+            scenarios.extend(scenario.expand(&[self.background.as_ref()], &self.tags));
+        }
+
+        for rule in &self.rules {
+            let inherited: Vec<String> = merge_tags(&self.tags, &rule.tags);
+            for scenario in &rule.scenarios {
+                scenarios.extend(scenario.expand(
+                    &[self.background.as_ref(), rule.background.as_ref()],
+                    &inherited,
+                ));
+            }
+        }
+
+        scenarios
+    }
+
+    /// Executable scenarios whose tags satisfy the given expression
+    pub fn scenarios_matching(&self, filter: Option<&TagExpression>) -> Vec<Scenario> {
+        self.executable_scenarios()
+            .into_iter()
+            .filter(|scenario| filter.is_none_or(|expr| expr.matches(&scenario.tags)))
+            .collect()
+    }
+}
+
+impl Scenario {
+    pub fn is_outline(&self) -> bool {
+        !self.examples.is_empty()
+    }
+
+    fn expand(&self, backgrounds: &[Option<&Background>], inherited: &[String]) -> Vec<Scenario> {
+        let background_steps: Vec<Step> = backgrounds
+            .iter()
+            .flatten()
+            .flat_map(|b| b.steps.iter().cloned())
+            .collect();
+
+        if !self.is_outline() {
+            let mut steps = background_steps;
+            steps.extend(self.steps.iter().cloned());
+            return vec![Scenario {
+                name: self.name.clone(),
+                location: self.location,
+                description: self.description.clone(),
+                tags: merge_tags(inherited, &self.tags),
+                steps,
+                examples: Vec::new(),
+            }];
+        }
+
+        let mut expanded = Vec::new();
+        for examples in &self.examples {
+            let tags = merge_tags(&merge_tags(inherited, &self.tags), &examples.tags);
+            for (row_idx, row) in examples.rows.iter().enumerate() {
+                let mut steps = background_steps.clone();
+                steps.extend(self.steps.iter().map(|step| {
+                    Step {
+                        keyword: step.keyword.clone(),
+                        text: examples.substitute(&step.text, row),
+                        location: step.location,
+                        argument: step
+                            .argument
+                            .as_ref()
+                            .map(|argument| examples.substitute_argument(argument, row)),
+                    }
+                }));
+
+                let label = if examples.name.is_empty() {
+                    "Examples".to_string()
+                } else {
+                    examples.name.clone()
+                };
+
+                expanded.push(Scenario {
+                    name: format!(
+                        "{} ({} #{})",
+                        examples.substitute(&self.name, row),
+                        label,
+                        row_idx + 1
+                    ),
+                    location: examples
+                        .row_locations
+                        .get(row_idx)
+                        .copied()
+                        .unwrap_or(examples.location),
+                    description: self.description.clone(),
+                    tags: tags.clone(),
+                    steps,
+                    examples: Vec::new(),
+                });
+            }
+        }
+
+        expanded
+    }
+}
+
+impl Examples {
+    /// Replace `<placeholder>` occurrences with the values of the given row
+    ///
+    /// The text is scanned once, so a value that contains another column's
+    /// placeholder is kept as written.
+    pub fn substitute(&self, text: &str, row: &[String]) -> String {
+        let mut result = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(start) = rest.find('<') {
+            result.push_str(&rest[..start]);
+            let after = &rest[start + 1..];
+            let value = after.find('>').and_then(|end| {
+                let column = self.header.iter().position(|c| *c == after[..end])?;
+                Some((row.get(column)?, end))
+            });
+            match value {
+                Some((value, end)) => {
+                    result.push_str(value);
+                    rest = &after[end + 1..];
+                }
+                None => {
+                    result.push('<');
+                    rest = after;
+                }
+            }
+        }
+        result.push_str(rest);
+        result
+    }
+
+    /// Apply [`Examples::substitute`] to a doc string or every table cell
+    pub fn substitute_argument(&self, argument: &StepArgument, row: &[String]) -> StepArgument {
+        match argument {
+            StepArgument::DocString {
+                content_type,
+                content,
+            } => StepArgument::DocString {
+                content_type: content_type.clone(),
+                content: self.substitute(content, row),
+            },
+            StepArgument::DataTable(rows) => StepArgument::DataTable(
+                rows.iter()
+                    .map(|cells| cells.iter().map(|c| self.substitute(c, row)).collect())
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// Combine parent and child tags, keeping the first occurrence of each
+pub(crate) fn merge_tags(parent: &[String], own: &[String]) -> Vec<String> {
+    let mut tags = parent.to_vec();
+    for tag in own {
+        if !tags.contains(tag) {
+            tags.push(tag.clone());
+        }
+    }
+    tags
+}
@@ -0,0 +1,9 @@
+// Gherkin module: feature file AST and parser
+pub mod ast;
+pub mod parser;
+pub mod tags;
+pub mod timeout;
+
+pub use ast::{Background, Examples, Feature, Location, Rule, Scenario, Step, StepArgument};
+pub use parser::{parse_feature, parse_feature_file};
+pub use tags::TagExpression;
@@ -0,0 +1,827 @@
+// Line-based Gherkin parser
+use super::ast::{Background, Examples, Feature, Location, Rule, Scenario, Step, StepArgument};
+use crate::error::{Result, WebSpecError};
+
+const STEP_KEYWORDS: [&str; 6] = ["Given", "When", "Then", "And", "But", "*"];
+const SCENARIO_KEYWORDS: [&str; 2] = ["Scenario:", "Example:"];
+const OUTLINE_KEYWORDS: [&str; 2] = ["Scenario Outline:", "Scenario Template:"];
+const EXAMPLES_KEYWORDS: [&str; 2] = ["Examples:", "Scenarios:"];
+
+/// Parse the content of a `.feature` file into a [`Feature`]
+pub fn parse_feature(content: &str) -> Result<Feature> {
+    Parser::default().parse(content)
+}
+
+/// Read and parse a `.feature` file from disk
+pub fn parse_feature_file(path: impl AsRef<std::path::Path>) -> Result<Feature> {
+    let content = std::fs::read_to_string(path)?;
+    parse_feature(&content)
+}
+
+/// Block currently receiving steps or table rows
+enum Block {
+    Background(Background),
+    Scenario { scenario: Scenario, outline: bool },
+}
+
+#[derive(Default)]
+struct Parser {
+    feature: Option<Feature>,
+    rule: Option<Rule>,
+    block: Option<Block>,
+    /// Whether free-form description lines are currently accepted
+    in_description: bool,
+    description: Vec<String>,
+    /// Line on which the current block started, for error reporting
+    block_line: usize,
+    /// Tags waiting to be attached to the next Feature, Rule, Scenario or Examples
+    pending_tags: Vec<String>,
+    pending_tags_line: usize,
+}
+
+impl Parser {
+    fn parse(mut self, content: &str) -> Result<Feature> {
+        let mut lines = content.lines().enumerate();
+
+        while let Some((idx, raw)) = lines.next() {
+            let line_no = idx + 1;
+            let line = raw.trim();
+            let location = Location::new(
+                line_no,
+                raw.chars().take_while(|c| c.is_whitespace()).count() + 1,
+            );
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if line.starts_with('@') {
+                self.flush_description();
+                self.add_tags(line_no, line)?;
+                continue;
+            }
+
+            if let Some(delimiter) = doc_string_delimiter(line) {
+                let indent = raw.len() - raw.trim_start().len();
+                let content_type = line[delimiter.len()..].trim();
+                let escaped: String = delimiter.chars().flat_map(|c| ['\\', c]).collect();
+                let mut content = Vec::new();
+                let mut closed = false;
+                for (_, inner) in lines.by_ref() {
+                    if inner.trim() == delimiter {
+                        closed = true;
+                        break;
+                    }
+                    content.push(strip_indent(inner, indent).replace(&escaped, delimiter));
+                }
+                if !closed {
+                    return Err(parse_error(line_no, "Unterminated doc string"));
+                }
+                self.reject_pending_tags("a doc string")?;
+                self.attach_argument(
+                    line_no,
+                    StepArgument::DocString {
+                        content_type: (!content_type.is_empty()).then(|| content_type.to_string()),
+                        content: content.join("\n"),
+                    },
+                )?;
+                continue;
+            }
+
+            if let Some(rest) = line.strip_prefix("Feature:") {
+                if self.feature.is_some() {
+                    return Err(parse_error(line_no, "Only one Feature is allowed per file"));
+                }
+                self.feature = Some(Feature {
+                    name: rest.trim().to_string(),
+                    location,
+                    description: None,
+                    tags: std::mem::take(&mut self.pending_tags),
+                    background: None,
+                    scenarios: Vec::new(),
+                    rules: Vec::new(),
+                });
+                self.start_description();
+                continue;
+            }
+
+            if self.feature.is_none() {
+                return Err(parse_error(
+                    line_no,
+                    format!("Expected 'Feature:' but found '{}'", line),
+                ));
+            }
+
+            if let Some(rest) = line.strip_prefix("Rule:") {
+                self.finish_block()?;
+                self.finish_rule();
+                self.rule = Some(Rule {
+                    name: rest.trim().to_string(),
+                    location,
+                    description: None,
+                    tags: std::mem::take(&mut self.pending_tags),
+                    background: None,
+                    scenarios: Vec::new(),
+                });
+                self.start_description();
+                self.block_line = line_no;
+                continue;
+            }
+
+            if let Some(rest) = line.strip_prefix("Background:") {
+                self.reject_pending_tags("Background")?;
+                self.finish_block()?;
+                if self.has_background() || self.has_scenarios() {
+                    return Err(parse_error(
+                        line_no,
+                        "Background must appear once, before any scenarios",
+                    ));
+                }
+                self.block = Some(Block::Background(Background {
+                    name: rest.trim().to_string(),
+                    location,
+                    steps: Vec::new(),
+                }));
+                self.start_description();
+                self.block_line = line_no;
+                continue;
+            }
+
+            if let Some((rest, outline)) = strip_scenario_keyword(line) {
+                self.finish_block()?;
+                self.block = Some(Block::Scenario {
+                    scenario: Scenario {
+                        name: rest.trim().to_string(),
+                        location,
+                        description: None,
+                        tags: std::mem::take(&mut self.pending_tags),
+                        steps: Vec::new(),
+                        examples: Vec::new(),
+                    },
+                    outline,
+                });
+                self.start_description();
+                self.block_line = line_no;
+                continue;
+            }
+
+            if let Some(rest) = strip_any(line, &EXAMPLES_KEYWORDS) {
+                self.flush_description();
+                match self.block.as_mut() {
+                    Some(Block::Scenario { scenario, .. }) => {
+                        scenario.examples.push(Examples {
+                            name: rest.trim().to_string(),
+                            location,
+                            tags: std::mem::take(&mut self.pending_tags),
+                            header: Vec::new(),
+                            rows: Vec::new(),
+                            row_locations: Vec::new(),
+                        });
+                    }
+                    _ => {
+                        return Err(parse_error(
+                            line_no,
+                            "Examples must belong to a Scenario Outline",
+                        ));
+                    }
+                }
+                continue;
+            }
+
+            if line.starts_with('|') {
+                self.reject_pending_tags("a table row")?;
+                self.flush_description();
+                self.add_table_row(location, line)?;
+                continue;
+            }
+
+            if let Some((keyword, text)) = split_step(line) {
+                self.reject_pending_tags("a step")?;
+                self.flush_description();
+                let step = Step {
+                    keyword: keyword.to_string(),
+                    text: text.to_string(),
+                    location,
+                    argument: None,
+                };
+                match self.block.as_mut() {
+                    Some(Block::Background(background)) => background.steps.push(step),
+                    Some(Block::Scenario { scenario, .. }) => {
+                        if !scenario.examples.is_empty() {
+                            return Err(parse_error(
+                                line_no,
+                                "Steps cannot follow an Examples table",
+                            ));
+                        }
+                        scenario.steps.push(step);
+                    }
+                    _ => {
+                        return Err(parse_error(
+                            line_no,
+                            format!("Step '{}' is not inside a Scenario or Background", line),
+                        ));
+                    }
+                }
+                continue;
+            }
+
+            if self.in_description {
+                self.description.push(line.to_string());
+                continue;
+            }
+
+            return Err(parse_error(line_no, format!("Unexpected line '{}'", line)));
+        }
+
+        self.reject_pending_tags("the end of the file")?;
+        self.finish_block()?;
+        self.finish_rule();
+
+        self.feature
+            .ok_or_else(|| parse_error(1, "Feature file must start with 'Feature:' declaration"))
+    }
+
+    fn add_tags(&mut self, line_no: usize, line: &str) -> Result<()> {
+        if self.pending_tags.is_empty() {
+            self.pending_tags_line = line_no;
+        }
+        for word in line.split_whitespace() {
+            if word.starts_with('#') {
+                break;
+            }
+            if !word.starts_with('@') || word.len() == 1 {
+                return Err(parse_error(
+                    line_no,
+                    format!("Invalid tag '{}'; tags must start with '@'", word),
+                ));
+            }
+            if !self.pending_tags.iter().any(|t| t == word) {
+                self.pending_tags.push(word.to_string());
+            }
+        }
+        Ok(())
+    }
+
+    fn reject_pending_tags(&self, target: &str) -> Result<()> {
+        if self.pending_tags.is_empty() {
+            return Ok(());
+        }
+        Err(parse_error(
+            self.pending_tags_line,
+            format!("Tags cannot be applied to {}", target),
+        ))
+    }
+
+    fn start_description(&mut self) {
+        self.in_description = true;
+        self.description.clear();
+    }
+
+    /// Stop accepting description lines and attach any collected ones to the
+    /// most recently opened element
+    fn flush_description(&mut self) {
+        if !self.in_description {
+            return;
+        }
+        self.in_description = false;
+        if self.description.is_empty() {
+            return;
+        }
+        let text = Some(self.description.join("\n"));
+        self.description.clear();
+
+        match (
+            self.block.as_mut(),
+            self.rule.as_mut(),
+            self.feature.as_mut(),
+        ) {
+            (Some(Block::Scenario { scenario, .. }), _, _) => scenario.description = text,
+            (Some(Block::Background(_)), _, _) => {}
+            (None, Some(rule), _) => rule.description = text,
+            (None, None, Some(feature)) => feature.description = text,
+            _ => {}
+        }
+    }
+
+    fn has_background(&self) -> bool {
+        match (&self.rule, &self.feature) {
+            (Some(rule), _) => rule.background.is_some(),
+            (None, Some(feature)) => feature.background.is_some(),
+            _ => false,
+        }
+    }
+
+    fn has_scenarios(&self) -> bool {
+        match (&self.rule, &self.feature) {
+            (Some(rule), _) => !rule.scenarios.is_empty(),
+            (None, Some(feature)) => !feature.scenarios.is_empty(),
+            _ => false,
+        }
+    }
+
+    fn add_table_row(&mut self, location: Location, line: &str) -> Result<()> {
+        let line_no = location.line;
+        let cells = parse_table_row(line);
+        if let Some(Block::Scenario { scenario, .. }) = self.block.as_mut()
+            && let Some(examples) = scenario.examples.last_mut()
+        {
+            if examples.header.is_empty() {
+                examples.header = cells;
+            } else if cells.len() != examples.header.len() {
+                return Err(parse_error(
+                    line_no,
+                    format!(
+                        "Examples row has {} cells but the header has {}",
+                        cells.len(),
+                        examples.header.len()
+                    ),
+                ));
+            } else {
+                examples.rows.push(cells);
+                examples.row_locations.push(location);
+            }
+            return Ok(());
+        }
+
+        let step = self
+            .last_step_mut()
+            .ok_or_else(|| parse_error(line_no, "Table row must follow a step or Examples"))?;
+        match step.argument.as_mut() {
+            None => step.argument = Some(StepArgument::DataTable(vec![cells])),
+            Some(StepArgument::DataTable(rows)) => {
+                if rows[0].len() != cells.len() {
+                    return Err(parse_error(
+                        line_no,
+                        format!(
+                            "Table row has {} cells but the first row has {}",
+                            cells.len(),
+                            rows[0].len()
+                        ),
+                    ));
+                }
+                rows.push(cells);
+            }
+            Some(StepArgument::DocString { .. }) => {
+                return Err(parse_error(
+                    line_no,
+                    "A step cannot have both a doc string and a data table",
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn attach_argument(&mut self, line_no: usize, argument: StepArgument) -> Result<()> {
+        let step = self
+            .last_step_mut()
+            .ok_or_else(|| parse_error(line_no, "Doc string must follow a step"))?;
+        if step.argument.is_some() {
+            return Err(parse_error(
+                line_no,
+                "A step cannot have more than one doc string or data table",
+            ));
+        }
+        step.argument = Some(argument);
+        Ok(())
+    }
+
+    /// Step that a doc string or data table on the next line belongs to
+    fn last_step_mut(&mut self) -> Option<&mut Step> {
+        match self.block.as_mut()? {
+            Block::Background(background) => background.steps.last_mut(),
+            Block::Scenario { scenario, .. } if scenario.examples.is_empty() => {
+                scenario.steps.last_mut()
+            }
+            Block::Scenario { .. } => None,
+        }
+    }
+
+    fn finish_block(&mut self) -> Result<()> {
+        self.flush_description();
+        let block = match self.block.take() {
+            Some(block) => block,
+            None => return Ok(()),
+        };
+
+        match block {
+            Block::Background(background) => match (self.rule.as_mut(), self.feature.as_mut()) {
+                (Some(rule), _) => rule.background = Some(background),
+                (None, Some(feature)) => feature.background = Some(background),
+                _ => {}
+            },
+            Block::Scenario { scenario, outline } => {
+                if outline && scenario.examples.iter().all(|e| e.rows.is_empty()) {
+                    return Err(parse_error(
+                        self.block_line,
+                        format!("Scenario Outline '{}' has no Examples rows", scenario.name),
+                    ));
+                }
+                match (self.rule.as_mut(), self.feature.as_mut()) {
+                    (Some(rule), _) => rule.scenarios.push(scenario),
+                    (None, Some(feature)) => feature.scenarios.push(scenario),
+                    _ => {}
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn finish_rule(&mut self) {
+        if let (Some(rule), Some(feature)) = (self.rule.take(), self.feature.as_mut()) {
+            feature.rules.push(rule);
+        }
+    }
+}
+
+fn parse_error(line: usize, message: impl Into<String>) -> WebSpecError {
+    WebSpecError::Parse {
+        line,
+        message: message.into(),
+    }
+}
+
+fn strip_any<'a>(line: &'a str, keywords: &[&str]) -> Option<&'a str> {
+    keywords.iter().find_map(|k| line.strip_prefix(k))
+}
+
+fn strip_scenario_keyword(line: &str) -> Option<(&str, bool)> {
+    if let Some(rest) = strip_any(line, &OUTLINE_KEYWORDS) {
+        return Some((rest, true));
+    }
+    strip_any(line, &SCENARIO_KEYWORDS).map(|rest| (rest, false))
+}
+
+/// Split a step line into its keyword and text
+fn split_step(line: &str) -> Option<(&str, &str)> {
+    for keyword in STEP_KEYWORDS {
+        if let Some(rest) = line.strip_prefix(keyword)
+            && rest.starts_with(' ')
+        {
+            return Some((keyword, rest.trim()));
+        }
+    }
+    None
+}
+
+/// Opening delimiter of a doc string, if the line starts one
+fn doc_string_delimiter(line: &str) -> Option<&'static str> {
+    ["\"\"\"", "```"]
+        .into_iter()
+        .find(|delimiter| line.starts_with(delimiter))
+}
+
+/// Remove up to `indent` leading whitespace characters from a doc string line
+fn strip_indent(line: &str, indent: usize) -> &str {
+    let stripped = line
+        .char_indices()
+        .take(indent)
+        .take_while(|(_, c)| c.is_whitespace())
+        .last()
+        .map_or(0, |(idx, c)| idx + c.len_utf8());
+    &line[stripped..]
+}
+
+/// Split a `| a | b |` table row into trimmed, unescaped cells
+pub(crate) fn parse_table_row(line: &str) -> Vec<String> {
+    let inner = line.trim();
+    let inner = inner.strip_prefix('|').unwrap_or(inner);
+    let inner = inner.strip_suffix('|').unwrap_or(inner);
+
+    let mut cells = Vec::new();
+    let mut current = String::new();
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('|') => current.push('|'),
+                Some('n') => current.push('\n'),
+                Some('\\') => current.push('\\'),
+                Some(other) => {
+                    current.push('\\');
+                    current.push(other);
+                }
+                None => current.push('\\'),
+            },
+            '|' => {
+                cells.push(current.trim().to_string());
+                current.clear();
+            }
+            _ => current.push(c),
+        }
+    }
+    cells.push(current.trim().to_string());
+    cells
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gherkin::TagExpression;
+
+    #[test]
+    fn test_parse_simple_feature() {
+        let feature = parse_feature(
+            r#"
+Feature: Login
+  As a user
+  I want to log in
+
+  Scenario: Valid Login
+    Given I navigate to "https://example.com"
+    When I click on "button.login"
+    And I wait 1 second
+    But I should not see "Error"
+    * I should see "Welcome"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(feature.name, "Login");
+        assert_eq!(
+            feature.description.as_deref(),
+            Some("As a user\nI want to log in")
+        );
+        assert_eq!(feature.scenarios.len(), 1);
+        let keywords: Vec<_> = feature.scenarios[0]
+            .steps
+            .iter()
+            .map(|s| s.keyword.as_str())
+            .collect();
+        assert_eq!(keywords, vec!["Given", "When", "And", "But", "*"]);
+    }
+
+    #[test]
+    fn test_background_is_prepended() {
+        let feature = parse_feature(
+            r#"
+Feature: Shop
+  Background:
+    Given I navigate to "https://shop.example.com"
+
+  Scenario: One
+    Then I should see "Products"
+
+  Scenario: Two
+    Then I should see "Cart"
+"#,
+        )
+        .unwrap();
+
+        let scenarios = feature.executable_scenarios();
+        assert_eq!(scenarios.len(), 2);
+        for scenario in &scenarios {
+            assert_eq!(scenario.steps.len(), 2);
+            assert_eq!(
+                scenario.steps[0].text,
+                r#"I navigate to "https://shop.example.com""#
+            );
+        }
+    }
+
+    #[test]
+    fn test_scenario_outline_is_expanded() {
+        let feature = parse_feature(
+            r#"
+Feature: Search
+  Scenario Outline: Search for <term>
+    When I type "<term>" into "input.search"
+    Then I should see "<result>"
+
+    Examples: Products
+      | term   | result  |
+      | laptop | Laptops |
+      | phone  | Phones  |
+"#,
+        )
+        .unwrap();
+
+        let scenarios = feature.executable_scenarios();
+        assert_eq!(scenarios.len(), 2);
+        assert_eq!(scenarios[0].name, "Search for laptop (Products #1)");
+        assert_eq!(
+            scenarios[1].steps[0].text,
+            r#"I type "phone" into "input.search""#
+        );
+        assert_eq!(scenarios[1].steps[1].text, r#"I should see "Phones""#);
+    }
+
+    #[test]
+    fn test_outline_values_are_not_substituted_again() {
+        let feature = parse_feature(
+            r#"
+Feature: Templates
+  Scenario Outline: Render <template>
+    When I type "<template>" into "<field>"
+
+    Examples:
+      | template          | field         |
+      | Hello <field> <x> | textarea.body |
+"#,
+        )
+        .unwrap();
+
+        let scenarios = feature.executable_scenarios();
+        assert_eq!(
+            scenarios[0].steps[0].text,
+            r#"I type "Hello <field> <x>" into "textarea.body""#
+        );
+        assert!(scenarios[0].name.starts_with("Render Hello <field> <x>"));
+    }
+
+    #[test]
+    fn test_rules_with_backgrounds() {
+        let feature = parse_feature(
+            r#"
+Feature: Accounts
+  Background:
+    Given I navigate to "https://example.com"
+
+  Rule: Admins
+    Background:
+      Given I click on "a.admin"
+
+    Example: Dashboard
+      Then I should see "Dashboard"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(feature.rules.len(), 1);
+        let scenarios = feature.executable_scenarios();
+        assert_eq!(scenarios.len(), 1);
+        assert_eq!(scenarios[0].steps.len(), 3);
+        assert_eq!(scenarios[0].steps[1].text, r#"I click on "a.admin""#);
+    }
+
+    #[test]
+    fn test_outline_without_examples_is_an_error() {
+        let result = parse_feature(
+            r#"
+Feature: Broken
+  Scenario Outline: Missing examples
+    Given I navigate to "<url>"
+"#,
+        );
+        assert!(matches!(result, Err(WebSpecError::Parse { line: 3, .. })));
+    }
+
+    #[test]
+    fn test_mismatched_examples_row_is_an_error() {
+        let result = parse_feature(
+            r#"
+Feature: Broken
+  Scenario Outline: Bad table
+    Given I navigate to "<url>"
+    Examples:
+      | url |
+      | a   | b |
+"#,
+        );
+        assert!(matches!(result, Err(WebSpecError::Parse { line: 7, .. })));
+    }
+
+    #[test]
+    fn test_missing_feature_is_an_error() {
+        let result = parse_feature("Scenario: Orphan\n  Given something\n");
+        assert!(matches!(result, Err(WebSpecError::Parse { line: 1, .. })));
+    }
+
+    #[test]
+    fn test_tags_are_inherited() {
+        let feature = parse_feature(
+            r#"
+@web
+Feature: Tagged
+  @smoke @fast
+  Scenario: Quick
+    Given I navigate to "https://example.com"
+
+  Rule: Slow things
+    @slow
+    Scenario Outline: Search <term>
+      When I type "<term>" into "input"
+
+      @wip
+      Examples:
+        | term |
+        | a    |
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(feature.tags, vec!["@web"]);
+        let scenarios = feature.executable_scenarios();
+        assert_eq!(scenarios[0].tags, vec!["@web", "@smoke", "@fast"]);
+        assert_eq!(scenarios[1].tags, vec!["@web", "@slow", "@wip"]);
+
+        let smoke = TagExpression::parse("@smoke and not @wip").unwrap();
+        let selected = feature.scenarios_matching(Some(&smoke));
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].name, "Quick");
+    }
+
+    #[test]
+    fn test_dangling_tags_are_an_error() {
+        let result = parse_feature(
+            r#"
+Feature: Dangling
+  Scenario: One
+    Given I navigate to "https://example.com"
+    @orphan
+"#,
+        );
+        assert!(matches!(result, Err(WebSpecError::Parse { line: 5, .. })));
+    }
+
+    #[test]
+    fn test_locations() {
+        let feature = parse_feature(
+            "Feature: Places\n\n  Scenario Outline: Visit <page>\n    Given I navigate to \"<page>\"\n\n    Examples:\n      | page |\n      | /a   |\n      | /b   |\n",
+        )
+        .unwrap();
+
+        assert_eq!(feature.location, Location::new(1, 1));
+        let outline = &feature.scenarios[0];
+        assert_eq!(outline.location, Location::new(3, 3));
+        assert_eq!(outline.steps[0].location, Location::new(4, 5));
+
+        let scenarios = feature.executable_scenarios();
+        assert_eq!(scenarios[1].location, Location::new(9, 7));
+        assert_eq!(scenarios[1].steps[0].location, Location::new(4, 5));
+    }
+
+    #[test]
+    fn test_step_arguments() {
+        let feature = parse_feature(
+            r#"
+Feature: Arguments
+  Scenario Outline: Forms
+    When I fill the form with:
+      | selector | value  |
+      | #name    | <name> |
+    Then I execute script:
+      ```js
+      console.log("<name>");
+        \`\`\`
+      ```
+
+    Examples:
+      | name  |
+      | Alice |
+"#,
+        )
+        .unwrap();
+
+        let scenarios = feature.executable_scenarios();
+        let steps = &scenarios[0].steps;
+        assert_eq!(
+            steps[0].argument.as_ref().and_then(|a| a.data_table()),
+            Some(
+                &[
+                    vec!["selector".to_string(), "value".to_string()],
+                    vec!["#name".to_string(), "Alice".to_string()],
+                ][..]
+            )
+        );
+        assert_eq!(
+            steps[1].argument,
+            Some(StepArgument::DocString {
+                content_type: Some("js".to_string()),
+                content: "console.log(\"Alice\");\n  ```".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_misplaced_step_arguments_are_errors() {
+        let orphan_table = parse_feature("Feature: A\n  Scenario: B\n    | a |\n");
+        assert!(matches!(
+            orphan_table,
+            Err(WebSpecError::Parse { line: 3, .. })
+        ));
+
+        let ragged_table = parse_feature(
+            "Feature: A\n  Scenario: B\n    Given x:\n      | a | b |\n      | c |\n",
+        );
+        assert!(matches!(
+            ragged_table,
+            Err(WebSpecError::Parse { line: 5, .. })
+        ));
+
+        let two_arguments = parse_feature(
+            "Feature: A\n  Scenario: B\n    Given x:\n      | a |\n      \"\"\"\n      b\n      \"\"\"\n",
+        );
+        assert!(matches!(
+            two_arguments,
+            Err(WebSpecError::Parse { line: 5, .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_table_row_escapes() {
+        assert_eq!(
+            parse_table_row(r"| a \| b | c\\d |"),
+            vec!["a | b".to_string(), r"c\d".to_string()]
+        );
+    }
+}
@@ -0,0 +1,202 @@
+// Boolean tag expressions such as `@smoke and not (@wip or @slow)`
+use crate::error::{Result, WebSpecError};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TagExpression {
+    Tag(String),
+    Not(Box<TagExpression>),
+    And(Box<TagExpression>, Box<TagExpression>),
+    Or(Box<TagExpression>, Box<TagExpression>),
+}
+
+impl TagExpression {
+    /// Parse a tag expression; `and` binds tighter than `or`, `not` tighter than both
+    pub fn parse(input: &str) -> Result<Self> {
+        let tokens = tokenize(input)?;
+        if tokens.is_empty() {
+            return Err(tag_error("expression is empty"));
+        }
+
+        let mut parser = ExpressionParser { tokens, pos: 0 };
+        let expression = parser.parse_or()?;
+        if let Some(token) = parser.tokens.get(parser.pos) {
+            return Err(tag_error(format!("unexpected '{}'", token)));
+        }
+        Ok(expression)
+    }
+
+    /// Evaluate the expression against a set of tags (with leading `@`)
+    pub fn matches<S: AsRef<str>>(&self, tags: &[S]) -> bool {
+        match self {
+            TagExpression::Tag(tag) => tags.iter().any(|t| t.as_ref() == tag),
+            TagExpression::Not(inner) => !inner.matches(tags),
+            TagExpression::And(left, right) => left.matches(tags) && right.matches(tags),
+            TagExpression::Or(left, right) => left.matches(tags) || right.matches(tags),
+        }
+    }
+}
+
+impl fmt::Display for TagExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TagExpression::Tag(tag) => write!(f, "{}", tag),
+            TagExpression::Not(inner) => write!(f, "not {}", inner),
+            TagExpression::And(left, right) => write!(f, "({} and {})", left, right),
+            TagExpression::Or(left, right) => write!(f, "({} or {})", left, right),
+        }
+    }
+}
+
+impl std::str::FromStr for TagExpression {
+    type Err = WebSpecError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Tag(String),
+    And,
+    Or,
+    Not,
+    Open,
+    Close,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Tag(tag) => write!(f, "{}", tag),
+            Token::And => write!(f, "and"),
+            Token::Or => write!(f, "or"),
+            Token::Not => write!(f, "not"),
+            Token::Open => write!(f, "("),
+            Token::Close => write!(f, ")"),
+        }
+    }
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let spaced = input.replace('(', " ( ").replace(')', " ) ");
+
+    for word in spaced.split_whitespace() {
+        let token = match word {
+            "(" => Token::Open,
+            ")" => Token::Close,
+            "and" => Token::And,
+            "or" => Token::Or,
+            "not" => Token::Not,
+            tag if tag.starts_with('@') && tag.len() > 1 => Token::Tag(tag.to_string()),
+            other => {
+                return Err(tag_error(format!(
+                    "'{}' is not a tag; tags must start with '@'",
+                    other
+                )));
+            }
+        };
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
+
+struct ExpressionParser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl ExpressionParser {
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn parse_or(&mut self) -> Result<TagExpression> {
+        let mut left = self.parse_and()?;
+        while self.peek() == Some(&Token::Or) {
+            self.pos += 1;
+            let right = self.parse_and()?;
+            left = TagExpression::Or(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_and(&mut self) -> Result<TagExpression> {
+        let mut left = self.parse_not()?;
+        while self.peek() == Some(&Token::And) {
+            self.pos += 1;
+            let right = self.parse_not()?;
+            left = TagExpression::And(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_not(&mut self) -> Result<TagExpression> {
+        match self.next() {
+            Some(Token::Not) => Ok(TagExpression::Not(Box::new(self.parse_not()?))),
+            Some(Token::Tag(tag)) => Ok(TagExpression::Tag(tag)),
+            Some(Token::Open) => {
+                let inner = self.parse_or()?;
+                match self.next() {
+                    Some(Token::Close) => Ok(inner),
+                    _ => Err(tag_error("missing closing ')'")),
+                }
+            }
+            Some(token) => Err(tag_error(format!("unexpected '{}'", token))),
+            None => Err(tag_error("unexpected end of expression")),
+        }
+    }
+}
+
+fn tag_error(message: impl Into<String>) -> WebSpecError {
+    WebSpecError::TagExpression(message.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_tag() {
+        let expr = TagExpression::parse("@smoke").unwrap();
+        assert!(expr.matches(&["@smoke"]));
+        assert!(!expr.matches(&["@slow"]));
+    }
+
+    #[test]
+    fn test_and_not() {
+        let expr = TagExpression::parse("@smoke and not @wip").unwrap();
+        assert!(expr.matches(&["@smoke", "@fast"]));
+        assert!(!expr.matches(&["@smoke", "@wip"]));
+        assert!(!expr.matches(&["@fast"]));
+    }
+
+    #[test]
+    fn test_precedence_and_parentheses() {
+        let expr = TagExpression::parse("@a or @b and @c").unwrap();
+        assert!(expr.matches(&["@a"]));
+        assert!(!expr.matches(&["@b"]));
+
+        let expr = TagExpression::parse("(@a or @b) and @c").unwrap();
+        assert!(!expr.matches(&["@a"]));
+        assert!(expr.matches(&["@b", "@c"]));
+    }
+
+    #[test]
+    fn test_invalid_expressions() {
+        assert!(TagExpression::parse("").is_err());
+        assert!(TagExpression::parse("smoke").is_err());
+        assert!(TagExpression::parse("@a and").is_err());
+        assert!(TagExpression::parse("(@a or @b").is_err());
+        assert!(TagExpression::parse("@a @b").is_err());
+    }
+}
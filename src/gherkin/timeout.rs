@@ -0,0 +1,91 @@
+// Step timeouts written in feature files, as a tag or a step suffix
+use std::time::Duration;
+
+/// Tag prefix setting the step timeout for a scenario, e.g. `@timeout=20s`
+pub const TIMEOUT_TAG_PREFIX: &str = "@timeout=";
+
+/// Parse `500ms`, `20s`, `2m` or a bare number of seconds
+pub fn parse_duration(text: &str) -> Option<Duration> {
+    let text = text.trim();
+    let (number, unit) = match text.find(|c: char| !c.is_ascii_digit() && c != '.') {
+        Some(idx) => text.split_at(idx),
+        None => (text, "s"),
+    };
+    let value: f64 = number.parse().ok()?;
+    let seconds = match unit.trim() {
+        "ms" => value / 1000.0,
+        "s" => value,
+        "m" => value * 60.0,
+        _ => return None,
+    };
+    Duration::try_from_secs_f64(seconds).ok()
+}
+
+/// Step text without a trailing `(timeout: 20s)`, and the timeout it sets
+///
+/// Text whose suffix is not a valid duration is returned unchanged.
+pub fn split_step_timeout(text: &str) -> (&str, Option<Duration>) {
+    let trimmed = text.trim_end();
+    let Some(rest) = trimmed.strip_suffix(')') else {
+        return (text, None);
+    };
+    let Some(idx) = rest.rfind("(timeout:") else {
+        return (text, None);
+    };
+    match parse_duration(&rest[idx + "(timeout:".len()..]) {
+        Some(timeout) => (rest[..idx].trim_end(), Some(timeout)),
+        None => (text, None),
+    }
+}
+
+/// Step timeout set by a `@timeout=...` tag; a scenario's own tag overrides inherited ones
+pub fn tag_timeout<S: AsRef<str>>(tags: &[S]) -> Option<Duration> {
+    tags.iter()
+        .rev()
+        .filter_map(|tag| tag.as_ref().strip_prefix(TIMEOUT_TAG_PREFIX))
+        .find_map(parse_duration)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("500ms"), Some(Duration::from_millis(500)));
+        assert_eq!(parse_duration("20s"), Some(Duration::from_secs(20)));
+        assert_eq!(parse_duration("1.5s"), Some(Duration::from_millis(1500)));
+        assert_eq!(parse_duration("2m"), Some(Duration::from_secs(120)));
+        assert_eq!(parse_duration("30"), Some(Duration::from_secs(30)));
+        assert_eq!(parse_duration("soon"), None);
+        assert_eq!(parse_duration("5h"), None);
+    }
+
+    #[test]
+    fn test_split_step_timeout() {
+        assert_eq!(
+            split_step_timeout(r#"I navigate to "https://example.com" (timeout: 45s)"#),
+            (
+                r#"I navigate to "https://example.com""#,
+                Some(Duration::from_secs(45))
+            )
+        );
+        assert_eq!(
+            split_step_timeout(r##"I click on "#slow""##),
+            (r##"I click on "#slow""##, None)
+        );
+        assert_eq!(
+            split_step_timeout("I wait (timeout: later)"),
+            ("I wait (timeout: later)", None)
+        );
+    }
+
+    #[test]
+    fn test_tag_timeout_prefers_the_last_tag() {
+        assert_eq!(
+            tag_timeout(&["@timeout=10s", "@smoke", "@timeout=2m"]),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(tag_timeout(&["@smoke"]), None);
+    }
+}
@@ -1,18 +1,24 @@
 pub mod automation;
+pub mod backend;
 pub mod browser;
 pub mod cli;
 pub mod converter;
 pub mod discovery;
 pub mod error;
 pub mod execution;
+pub mod gherkin;
+pub mod runner;
 pub mod validation;
 
 pub use automation::Automation;
+pub use backend::{BrowserBackend, BrowserConfig};
 pub use browser::{Browser, BrowserType};
 pub use converter::Converter;
 pub use discovery::{StepCatalog, catalog::build_step_catalog};
 pub use error::{Result, WebSpecError};
 pub use execution::{ExecutionResult, ExecutionSummary, ScenarioResult, StepResult};
+pub use gherkin::{parse_feature, parse_feature_file};
+pub use runner::Runner;
 pub use validation::{ValidationResult, validate_feature};
 
 #[derive(Debug, Clone)]
@@ -32,7 +38,7 @@ impl WebSpec {
     }
 
     pub async fn from_url(&self, url: &str) -> Result<String> {
-        let mut browser = Browser::new(self.browser_type.clone()).await?;
+        let mut browser = Browser::new(self.browser_type).await?;
         browser.navigate_to(url).await?;
         browser.wait_for_load().await?;
 
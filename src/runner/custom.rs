@@ -0,0 +1,205 @@
+// User-defined steps registered from Rust code
+use super::RunState;
+use crate::browser::Browser;
+use crate::discovery::catalog::{ParameterInfo, StepInfo};
+use crate::gherkin::StepArgument;
+use async_trait::async_trait;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// Everything a step handler can see while it runs
+pub struct StepContext<'a> {
+    pub browser: &'a mut Browser,
+    /// Values captured by the pattern's groups, in order
+    pub params: &'a [String],
+    pub argument: Option<&'a StepArgument>,
+    pub state: &'a RunState,
+}
+
+impl StepContext<'_> {
+    /// Parameter converted to an integer, or an error naming the bad value
+    pub fn int(&self, idx: usize) -> std::result::Result<i64, String> {
+        self.parse(idx, "an integer")
+    }
+
+    /// Parameter converted to a float, or an error naming the bad value
+    pub fn float(&self, idx: usize) -> std::result::Result<f64, String> {
+        self.parse(idx, "a number")
+    }
+
+    /// Parameter as captured, or an error if the pattern did not capture it
+    pub fn string(&self, idx: usize) -> std::result::Result<&str, String> {
+        self.params
+            .get(idx)
+            .map(String::as_str)
+            .ok_or_else(|| format!("missing parameter {}", idx + 1))
+    }
+
+    fn parse<T: std::str::FromStr>(
+        &self,
+        idx: usize,
+        expected: &str,
+    ) -> std::result::Result<T, String> {
+        let value = self.string(idx)?;
+        value.parse().map_err(|_| {
+            format!(
+                "parameter {} should be {}, got \"{}\"",
+                idx + 1,
+                expected,
+                value
+            )
+        })
+    }
+}
+
+/// Boxed future returned by closure-based step handlers
+pub type StepFuture<'a> =
+    Pin<Box<dyn Future<Output = std::result::Result<String, String>> + Send + 'a>>;
+
+/// Handler for a custom step; `Ok` carries the step output, `Err` the failure message
+#[async_trait]
+pub trait StepHandler: Send + Sync {
+    async fn execute(&self, ctx: StepContext<'_>) -> std::result::Result<String, String>;
+}
+
+struct FnHandler<F>(F);
+
+#[async_trait]
+impl<F> StepHandler for FnHandler<F>
+where
+    F: for<'a> Fn(StepContext<'a>) -> StepFuture<'a> + Send + Sync,
+{
+    async fn execute(&self, ctx: StepContext<'_>) -> std::result::Result<String, String> {
+        (self.0)(ctx).await
+    }
+}
+
+/// A step with its own patterns and handler, listed in the catalog like a built-in one
+///
+/// ```no_run
+/// use web_spec::runner::{CustomStep, Runner};
+///
+/// # fn example() -> web_spec::Result<()> {
+/// let runner = Runner::new().with_step(
+///     CustomStep::from_fn("login_as_admin", r"I log in as an admin", |ctx| {
+///         Box::pin(async move {
+///             ctx.browser.navigate_to("https://example.com/login?as=admin").await
+///                 .map_err(|e| e.to_string())?;
+///             Ok("Logged in as admin".to_string())
+///         })
+///     })
+///     .with_description("Log in with the admin test account"),
+/// )?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct CustomStep {
+    info: StepInfo,
+    handler: Arc<dyn StepHandler>,
+}
+
+impl CustomStep {
+    /// Custom step in the "Custom" category with a single pattern
+    pub fn new(id: &str, pattern: &str, handler: impl StepHandler + 'static) -> Self {
+        CustomStep {
+            info: StepInfo {
+                id: id.to_string(),
+                pattern: pattern.to_string(),
+                aliases: Vec::new(),
+                category: "Custom".to_string(),
+                description: format!("Custom step: {}", id),
+                parameters: Vec::new(),
+                examples: Vec::new(),
+            },
+            handler: Arc::new(handler),
+        }
+    }
+
+    /// Custom step whose handler is an async closure returning a boxed future
+    pub fn from_fn<F>(id: &str, pattern: &str, handler: F) -> Self
+    where
+        F: for<'a> Fn(StepContext<'a>) -> StepFuture<'a> + Send + Sync + 'static,
+    {
+        Self::new(id, pattern, FnHandler(handler))
+    }
+
+    pub fn with_alias(mut self, pattern: &str) -> Self {
+        self.info.aliases.push(pattern.to_string());
+        self
+    }
+
+    pub fn with_category(mut self, category: &str) -> Self {
+        self.info.category = category.to_string();
+        self
+    }
+
+    pub fn with_description(mut self, description: &str) -> Self {
+        self.info.description = description.to_string();
+        self
+    }
+
+    pub fn with_parameter(mut self, parameter: ParameterInfo) -> Self {
+        self.info.parameters.push(parameter);
+        self
+    }
+
+    pub fn with_example(mut self, example: &str) -> Self {
+        self.info.examples.push(example.to_string());
+        self
+    }
+
+    pub fn id(&self) -> &str {
+        &self.info.id
+    }
+
+    /// Catalog entry for this step
+    pub fn info(&self) -> &StepInfo {
+        &self.info
+    }
+
+    pub fn handler(&self) -> &dyn StepHandler {
+        self.handler.as_ref()
+    }
+}
+
+impl std::fmt::Debug for CustomStep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CustomStep")
+            .field("info", &self.info)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::registry::compile_patterns;
+
+    fn noop(id: &str, pattern: &str) -> CustomStep {
+        CustomStep::from_fn(id, pattern, |ctx| {
+            let first = ctx.params.first().cloned().unwrap_or_default();
+            Box::pin(async move { Ok(first) })
+        })
+    }
+
+    #[test]
+    fn test_custom_step_info() {
+        let step = noop("login_as", r#"I log in as "([^"]+)""#)
+            .with_alias(r#"I am logged in as "([^"]+)""#)
+            .with_description("Log in with a test account")
+            .with_example(r#"I log in as "admin""#);
+
+        assert_eq!(step.id(), "login_as");
+        assert_eq!(step.info().category, "Custom");
+        assert_eq!(step.info().aliases.len(), 1);
+        assert_eq!(compile_patterns(step.info()).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_invalid_pattern_is_an_error() {
+        let err = compile_patterns(noop("broken", r"I open (").info()).unwrap_err();
+        assert!(err.to_string().contains("broken"));
+    }
+}
@@ -0,0 +1,346 @@
+// Setup and teardown hooks run around features, scenarios and steps
+use super::RunState;
+use super::macro_steps::strip_keyword;
+use crate::browser::Browser;
+use crate::error::{Result, WebSpecError};
+use crate::execution::{ScenarioResult, StepResult};
+use crate::gherkin::{Scenario, TagExpression};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// Point in a run where a hook is called
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HookPoint {
+    /// Once per feature, before its first scenario
+    BeforeAll,
+    /// Once per feature, after its last scenario
+    AfterAll,
+    /// Before each scenario, after its browser context is created
+    Before,
+    /// After each scenario, even when one of its steps failed
+    After,
+    /// After each step that ran
+    AfterStep,
+}
+
+impl std::fmt::Display for HookPoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            HookPoint::BeforeAll => "BeforeAll",
+            HookPoint::AfterAll => "AfterAll",
+            HookPoint::Before => "Before",
+            HookPoint::After => "After",
+            HookPoint::AfterStep => "AfterStep",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Everything a hook handler can see while it runs
+pub struct HookContext<'a> {
+    pub browser: &'a mut Browser,
+    pub state: &'a RunState,
+    /// Scenario being run; `None` for `BeforeAll` and `AfterAll`
+    pub scenario: Option<&'a Scenario>,
+    /// Results so far: up to the finished step for `AfterStep`, the whole scenario for `After`
+    pub result: Option<&'a ScenarioResult>,
+}
+
+/// Boxed future returned by closure-based hook handlers
+pub type HookFuture<'a> =
+    Pin<Box<dyn Future<Output = std::result::Result<(), String>> + Send + 'a>>;
+
+/// Handler for a hook; `Err` carries the failure message
+#[async_trait]
+pub trait HookHandler: Send + Sync {
+    async fn run(&self, ctx: HookContext<'_>) -> std::result::Result<(), String>;
+}
+
+struct FnHandler<F>(F);
+
+#[async_trait]
+impl<F> HookHandler for FnHandler<F>
+where
+    F: for<'a> Fn(HookContext<'a>) -> HookFuture<'a> + Send + Sync,
+{
+    async fn run(&self, ctx: HookContext<'_>) -> std::result::Result<(), String> {
+        (self.0)(ctx).await
+    }
+}
+
+#[derive(Clone)]
+pub(crate) enum HookAction {
+    Handler(Arc<dyn HookHandler>),
+    /// Step texts run in order, as in a scenario
+    Steps(Vec<String>),
+}
+
+/// Code or steps run at a [`HookPoint`], optionally only for some tags or after failures
+///
+/// A failing hook is reported as a failed entry in the scenario's steps: a failing
+/// `Before` hook skips the scenario's steps, `After` hooks run regardless.
+///
+/// ```no_run
+/// use web_spec::runner::{Hook, HookPoint, Runner};
+///
+/// let runner = Runner::new().with_hook(
+///     Hook::steps(HookPoint::AfterStep, ["I take a screenshot"])
+///         .with_name("screenshot on failure")
+///         .failed_only(),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct Hook {
+    name: String,
+    point: HookPoint,
+    tags: Option<TagExpression>,
+    failed_only: bool,
+    pub(crate) action: HookAction,
+}
+
+impl Hook {
+    pub fn new(point: HookPoint, handler: impl HookHandler + 'static) -> Self {
+        Self::with_action(point, HookAction::Handler(Arc::new(handler)))
+    }
+
+    /// Hook whose handler is an async closure returning a boxed future
+    pub fn from_fn<F>(point: HookPoint, handler: F) -> Self
+    where
+        F: for<'a> Fn(HookContext<'a>) -> HookFuture<'a> + Send + Sync + 'static,
+    {
+        Self::new(point, FnHandler(handler))
+    }
+
+    /// Hook that runs step texts, with or without a leading keyword
+    pub fn steps<S: Into<String>>(point: HookPoint, steps: impl IntoIterator<Item = S>) -> Self {
+        Self::with_action(
+            point,
+            HookAction::Steps(
+                steps
+                    .into_iter()
+                    .map(|step| strip_keyword(step.into().trim()).to_string())
+                    .collect(),
+            ),
+        )
+    }
+
+    fn with_action(point: HookPoint, action: HookAction) -> Self {
+        Hook {
+            name: format!("{} hook", point),
+            point,
+            tags: None,
+            failed_only: false,
+            action,
+        }
+    }
+
+    /// Name shown for the hook in results when it fails
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Only run for scenarios whose tags match; ignored by `BeforeAll` and `AfterAll`
+    pub fn with_tags(mut self, tags: TagExpression) -> Self {
+        self.tags = Some(tags);
+        self
+    }
+
+    /// Only run after a failed step (`AfterStep`) or scenario (`After`)
+    pub fn failed_only(mut self) -> Self {
+        self.failed_only = true;
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn point(&self) -> HookPoint {
+        self.point
+    }
+
+    /// Whether the hook should run at `point` for this scenario and its results so far
+    pub fn applies(
+        &self,
+        point: HookPoint,
+        scenario: Option<&Scenario>,
+        result: Option<&ScenarioResult>,
+    ) -> bool {
+        if self.point != point {
+            return false;
+        }
+        if let (Some(tags), Some(scenario)) = (&self.tags, scenario)
+            && !tags.matches(&scenario.tags)
+        {
+            return false;
+        }
+        if !self.failed_only {
+            return true;
+        }
+        let steps = result.map(|r| r.steps.as_slice()).unwrap_or_default();
+        match point {
+            HookPoint::AfterStep => steps.last().is_some_and(StepResult::is_failure),
+            HookPoint::After => steps.iter().any(StepResult::is_failure),
+            _ => true,
+        }
+    }
+}
+
+impl std::fmt::Debug for Hook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Hook")
+            .field("name", &self.name)
+            .field("point", &self.point)
+            .field("tags", &self.tags)
+            .field("failed_only", &self.failed_only)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A hook as declared in YAML
+///
+/// ```yaml
+/// hooks:
+///   - on: before
+///     tags: "@admin"
+///     steps:
+///       - I log in as "admin" with password "secret"
+///   - on: after_step
+///     name: screenshot on failure
+///     failed_only: true
+///     steps:
+///       - I take a screenshot
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HookDefinition {
+    pub on: HookPoint,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Tag expression limiting the scenarios the hook runs for
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<String>,
+    #[serde(default)]
+    pub failed_only: bool,
+    pub steps: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct HookFile {
+    #[serde(default)]
+    hooks: Vec<HookDefinition>,
+}
+
+impl HookDefinition {
+    pub fn into_hook(self) -> Result<Hook> {
+        let mut hook = Hook::steps(self.on, self.steps);
+        if let Some(name) = &self.name {
+            hook = hook.with_name(name);
+        }
+        if let Some(tags) = &self.tags {
+            hook = hook.with_tags(TagExpression::parse(tags)?);
+        }
+        if self.failed_only {
+            hook = hook.failed_only();
+        }
+        Ok(hook)
+    }
+}
+
+/// Parse the `hooks` section of a YAML file such as `steps.yml`
+pub fn parse_hooks(content: &str) -> Result<Vec<Hook>> {
+    let file: HookFile = serde_yaml::from_str(content).map_err(|e| WebSpecError::Parse {
+        line: e.location().map_or(0, |l| l.line()),
+        message: e.to_string(),
+    })?;
+    file.hooks
+        .into_iter()
+        .map(HookDefinition::into_hook)
+        .collect()
+}
+
+/// Read and parse the hooks declared in a file such as `steps.yml`
+pub fn load_hooks(path: impl AsRef<Path>) -> Result<Vec<Hook>> {
+    parse_hooks(&std::fs::read_to_string(path)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gherkin::Location;
+
+    const HOOKS_YML: &str = r#"
+steps:
+  - id: noop
+    pattern: I do nothing
+    steps: []
+hooks:
+  - on: before
+    tags: "@admin"
+    steps:
+      - Given I navigate to "https://example.com/login"
+  - on: after_step
+    name: screenshot on failure
+    failed_only: true
+    steps:
+      - I take a screenshot
+"#;
+
+    fn scenario(tags: &[&str]) -> Scenario {
+        Scenario {
+            name: "Example".to_string(),
+            location: Location::default(),
+            description: None,
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            steps: Vec::new(),
+            examples: Vec::new(),
+        }
+    }
+
+    fn result_with(status: &str) -> ScenarioResult {
+        let mut result = ScenarioResult::new("Example".to_string());
+        result.add_step(
+            StepResult::new("a step".to_string(), "Given".to_string()).with_status(status),
+        );
+        result
+    }
+
+    #[test]
+    fn test_parse_hooks() {
+        let hooks = parse_hooks(HOOKS_YML).unwrap();
+        assert_eq!(hooks.len(), 2);
+        assert_eq!(hooks[0].point(), HookPoint::Before);
+        assert_eq!(hooks[0].name(), "Before hook");
+        assert_eq!(hooks[1].name(), "screenshot on failure");
+        assert!(matches!(&hooks[1].action, HookAction::Steps(steps) if steps.len() == 1));
+    }
+
+    #[test]
+    fn test_hook_scoped_by_tags() {
+        let hook = &parse_hooks(HOOKS_YML).unwrap()[0];
+        assert!(hook.applies(HookPoint::Before, Some(&scenario(&["@admin"])), None));
+        assert!(!hook.applies(HookPoint::Before, Some(&scenario(&["@guest"])), None));
+        assert!(!hook.applies(HookPoint::After, Some(&scenario(&["@admin"])), None));
+    }
+
+    #[test]
+    fn test_failed_only_hook() {
+        let hook = &parse_hooks(HOOKS_YML).unwrap()[1];
+        let scenario = scenario(&[]);
+        let failed = result_with("failed");
+        let passed = result_with("passed");
+        assert!(hook.applies(HookPoint::AfterStep, Some(&scenario), Some(&failed)));
+        assert!(!hook.applies(HookPoint::AfterStep, Some(&scenario), Some(&passed)));
+    }
+
+    #[test]
+    fn test_invalid_hook_tags_are_an_error() {
+        let yaml = "hooks:\n  - on: before\n    tags: \"@a and\"\n    steps: []\n";
+        assert!(parse_hooks(yaml).is_err());
+    }
+}
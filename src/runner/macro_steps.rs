@@ -0,0 +1,156 @@
+// Composite steps declared in YAML that expand into other steps
+use crate::discovery::catalog::{ParameterInfo, StepInfo};
+use crate::discovery::expression::infer_parameters;
+use crate::error::{Result, WebSpecError};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// A step phrase that runs a sequence of other steps
+///
+/// ```yaml
+/// steps:
+///   - id: login_as
+///     pattern: 'I log in as "([^"]+)" with password "([^"]+)"'
+///     params: [user, password]
+///     steps:
+///       - I navigate to "https://example.com/login"
+///       - I type "<user>" into "#username"
+///       - I type "<password>" into "#password"
+///       - I click on "#submit"
+/// ```
+///
+/// Captured values replace `<name>` for each entry in `params`, and `<1>`, `<2>`, ...
+/// by position.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MacroStep {
+    pub id: String,
+    pub pattern: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Names for the pattern's capture groups, in order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub params: Vec<String>,
+    pub steps: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct MacroFile {
+    #[serde(default)]
+    steps: Vec<MacroStep>,
+}
+
+/// Parse macro step definitions from YAML
+pub fn parse_macro_steps(content: &str) -> Result<Vec<MacroStep>> {
+    let file: MacroFile = serde_yaml::from_str(content).map_err(|e| WebSpecError::Parse {
+        line: e.location().map_or(0, |l| l.line()),
+        message: e.to_string(),
+    })?;
+    Ok(file.steps)
+}
+
+/// Read and parse a macro step file such as `steps.yml`
+pub fn load_macro_steps(path: impl AsRef<Path>) -> Result<Vec<MacroStep>> {
+    parse_macro_steps(&std::fs::read_to_string(path)?)
+}
+
+impl MacroStep {
+    /// Texts of the steps to run, with captured values substituted and keywords removed
+    pub fn expand(&self, params: &[String]) -> Vec<String> {
+        self.steps
+            .iter()
+            .map(|step| {
+                let mut text = strip_keyword(step.trim()).to_string();
+                for (idx, value) in params.iter().enumerate() {
+                    text = text.replace(&format!("<{}>", idx + 1), value);
+                    if let Some(name) = self.params.get(idx) {
+                        text = text.replace(&format!("<{}>", name), value);
+                    }
+                }
+                text
+            })
+            .collect()
+    }
+
+    /// Catalog entry listed under the "Custom" category
+    pub fn info(&self) -> StepInfo {
+        StepInfo {
+            id: self.id.clone(),
+            pattern: self.pattern.clone(),
+            aliases: self.aliases.clone(),
+            category: "Custom".to_string(),
+            description: self
+                .description
+                .clone()
+                .unwrap_or_else(|| format!("Runs {} steps", self.steps.len())),
+            parameters: infer_parameters(&self.pattern)
+                .into_iter()
+                .enumerate()
+                .map(|(idx, inferred)| match self.params.get(idx) {
+                    Some(name) => ParameterInfo {
+                        name: name.clone(),
+                        description: format!("Substituted for <{}>", name),
+                        ..inferred
+                    },
+                    None => inferred,
+                })
+                .collect(),
+            examples: Vec::new(),
+        }
+    }
+}
+
+/// Step text without its leading Gherkin keyword
+pub(crate) fn strip_keyword(text: &str) -> &str {
+    for keyword in ["Given ", "When ", "Then ", "And ", "But ", "* "] {
+        if let Some(rest) = text.strip_prefix(keyword) {
+            return rest.trim_start();
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STEPS_YML: &str = r##"
+steps:
+  - id: login_as
+    pattern: 'I log in as "([^"]+)" with password "([^"]+)"'
+    params: [user, password]
+    description: Log in through the login form
+    steps:
+      - Given I navigate to "https://example.com/login"
+      - And I type "<user>" into "#username"
+      - I type "<2>" into "#password"
+"##;
+
+    #[test]
+    fn test_parse_and_expand_macro_steps() {
+        let steps = parse_macro_steps(STEPS_YML).unwrap();
+        assert_eq!(steps.len(), 1);
+
+        let expanded = steps[0].expand(&["admin".to_string(), "secret".to_string()]);
+        assert_eq!(
+            expanded,
+            vec![
+                r#"I navigate to "https://example.com/login""#,
+                r##"I type "admin" into "#username""##,
+                r##"I type "secret" into "#password""##,
+            ]
+        );
+
+        let info = steps[0].info();
+        assert_eq!(info.category, "Custom");
+        assert_eq!(info.description, "Log in through the login form");
+        assert_eq!(info.parameters.len(), 2);
+    }
+
+    #[test]
+    fn test_invalid_yaml_reports_line() {
+        let err = parse_macro_steps("steps:\n  - id: broken\n    steps: [\n").unwrap_err();
+        assert!(matches!(err, WebSpecError::Parse { .. }));
+    }
+}
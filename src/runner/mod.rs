@@ -0,0 +1,1182 @@
+// Feature runner: executes parsed Gherkin features against a browser
+pub mod custom;
+pub mod hooks;
+pub mod macro_steps;
+pub mod order;
+pub mod pool;
+pub mod registry;
+mod steps;
+
+pub use custom::{CustomStep, StepContext, StepFuture, StepHandler};
+pub use hooks::{
+    Hook, HookContext, HookDefinition, HookFuture, HookHandler, HookPoint, load_hooks, parse_hooks,
+};
+pub use macro_steps::{MacroStep, load_macro_steps, parse_macro_steps};
+pub use order::ScenarioOrder;
+pub use pool::{BrowserPool, PooledBrowser};
+pub use registry::{StepRegistry, build_step_registry};
+
+use crate::discovery::StepCatalog;
+use crate::gherkin::{Feature, Location, Scenario, TagExpression};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+use crate::browser::Browser;
+use crate::execution::{ErrorInfo, ExecutionResult, FeatureInfo, ScenarioResult, StepResult};
+use crate::gherkin::timeout::{split_step_timeout, tag_timeout};
+use crate::gherkin::{Step, StepArgument};
+use hooks::HookAction;
+
+/// How deeply macro steps may expand into other macro steps
+const MAX_MACRO_DEPTH: usize = 16;
+
+/// Lists of values extracted from pages, keyed by name
+pub type ExtractedData = Arc<RwLock<HashMap<String, Vec<String>>>>;
+
+/// Single values stored by steps, keyed by name
+pub type StoredValues = Arc<RwLock<HashMap<String, String>>>;
+
+/// Error code on the first step of scenarios skipped after the failure limit was reached
+pub const ABORTED: &str = "ABORTED";
+
+/// Tag for scenarios that continue in the previous scenario's browser context and
+/// stored values instead of starting fresh
+pub const SHARED_CONTEXT_TAG: &str = "@shared-context";
+
+/// Values extracted and stored by steps while a feature runs
+///
+/// Stored values are cleared when an isolated scenario starts; extracted data is
+/// kept for the whole run.
+#[derive(Debug, Clone, Default)]
+pub struct RunState {
+    pub extracted: ExtractedData,
+    pub stored: StoredValues,
+}
+
+/// Executes parsed features against a browser and reports an [`ExecutionResult`]
+///
+/// ```no_run
+/// # async fn example() -> web_spec::Result<()> {
+/// use web_spec::runner::Runner;
+/// use web_spec::{Browser, parse_feature_file};
+///
+/// let feature = parse_feature_file("features/login.feature")?;
+/// let mut browser = Browser::new_chromiumoxide().await?;
+/// let result = Runner::new().run(&feature, &mut browser).await;
+/// println!("{}: {}", result.feature.name, result.status);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Runner {
+    registry: StepRegistry,
+    tags: Option<TagExpression>,
+    locations: Option<Vec<Location>>,
+    order: ScenarioOrder,
+    strict: bool,
+    hooks: Vec<Hook>,
+    step_timeout: Option<Duration>,
+    scenario_timeout: Option<Duration>,
+    retries: usize,
+    failure_limit: Option<Arc<FailureLimit>>,
+}
+
+/// Number of failed scenarios after which a run stops starting new ones
+#[derive(Debug)]
+struct FailureLimit {
+    max: usize,
+    failures: AtomicUsize,
+}
+
+impl Runner {
+    /// Runner with the built-in step registry and no tag filter
+    pub fn new() -> Self {
+        Runner {
+            registry: build_step_registry(),
+            tags: None,
+            locations: None,
+            order: ScenarioOrder::Defined,
+            strict: false,
+            hooks: Vec::new(),
+            step_timeout: None,
+            scenario_timeout: None,
+            retries: 0,
+            failure_limit: None,
+        }
+    }
+
+    pub fn with_registry(mut self, registry: StepRegistry) -> Self {
+        self.registry = registry;
+        self
+    }
+
+    /// Only run scenarios whose tags match the expression
+    pub fn with_tags(mut self, tags: Option<TagExpression>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Only run scenarios at these locations; an outline's example rows have their own
+    pub fn with_locations(mut self, locations: Option<Vec<Location>>) -> Self {
+        self.locations = locations;
+        self
+    }
+
+    /// Run scenarios in file order or shuffled by a seed
+    pub fn with_order(mut self, order: ScenarioOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    pub fn order(&self) -> ScenarioOrder {
+        self.order
+    }
+
+    /// Fail steps that match more than one handler instead of running the first
+    ///
+    /// Strict runs pick among handlers as validation does, so a pattern matching
+    /// the whole step beats an earlier one matching only part of it.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Add a custom step to the runner's registry
+    ///
+    /// Fails if the step's patterns are invalid or its id is already registered.
+    pub fn with_step(mut self, step: CustomStep) -> crate::error::Result<Self> {
+        self.registry.register_step(step)?;
+        Ok(self)
+    }
+
+    /// Add macro steps, e.g. from [`load_macro_steps`]
+    pub fn with_macros(
+        mut self,
+        steps: impl IntoIterator<Item = MacroStep>,
+    ) -> crate::error::Result<Self> {
+        for step in steps {
+            self.registry.register_macro(step)?;
+        }
+        Ok(self)
+    }
+
+    /// Time each step and hook may take before it is cancelled; unlimited by default
+    ///
+    /// A `@timeout=` tag or a `(timeout: ...)` suffix sets the limit for its steps.
+    pub fn with_step_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.step_timeout = timeout;
+        self
+    }
+
+    /// Time each scenario's steps may take in total; unlimited by default
+    pub fn with_scenario_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.scenario_timeout = timeout;
+        self
+    }
+
+    /// Run a failed scenario up to `retries` more times; one that then passes is "flaky"
+    pub fn with_retries(mut self, retries: usize) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// Stop starting scenarios once `max` of them have failed; `None` never stops
+    ///
+    /// Clones of the runner share the count, so a batch, or a rerun of several files,
+    /// stops as a whole. Only [`BatchExecutor::run`](crate::execution::BatchExecutor::run)
+    /// starts the count again. Scenarios not started are reported as skipped with an
+    /// `ABORTED` reason.
+    pub fn with_max_failures(mut self, max: Option<usize>) -> Self {
+        self.failure_limit = max.map(|max| {
+            Arc::new(FailureLimit {
+                max,
+                failures: AtomicUsize::new(0),
+            })
+        });
+        self
+    }
+
+    /// Why the run stopped starting scenarios, once the failure limit is reached
+    pub fn aborted(&self) -> Option<String> {
+        let limit = self.failure_limit.as_ref()?;
+        let failures = limit.failures.load(Ordering::SeqCst);
+        (failures >= limit.max).then(|| {
+            format!(
+                "Stopped after {} failed scenario(s) (max failures: {})",
+                failures, limit.max
+            )
+        })
+    }
+
+    /// Start counting failed scenarios from zero for a new batch
+    pub(crate) fn reset_failures(&self) {
+        if let Some(limit) = &self.failure_limit {
+            limit.failures.store(0, Ordering::SeqCst);
+        }
+    }
+
+    /// Add a hook; hooks at the same point run in the order they were added
+    pub fn with_hook(mut self, hook: Hook) -> Self {
+        self.hooks.push(hook);
+        self
+    }
+
+    /// Add hooks, e.g. from [`load_hooks`]
+    pub fn with_hooks(mut self, hooks: impl IntoIterator<Item = Hook>) -> Self {
+        self.hooks.extend(hooks);
+        self
+    }
+
+    pub fn hooks(&self) -> &[Hook] {
+        &self.hooks
+    }
+
+    pub fn registry(&self) -> &StepRegistry {
+        &self.registry
+    }
+
+    /// Catalog of every step this runner understands, custom and macro steps included
+    pub fn catalog(&self) -> StepCatalog {
+        self.registry.catalog()
+    }
+
+    /// Executable scenarios of the feature selected by the tag and location filters,
+    /// in the order they run
+    pub fn scenarios(&self, feature: &Feature) -> Vec<Scenario> {
+        let mut scenarios = feature.scenarios_matching(self.tags.as_ref());
+        if let Some(locations) = &self.locations {
+            scenarios.retain(|scenario| locations.contains(&scenario.location));
+        }
+        self.order.apply(&mut scenarios);
+        scenarios
+    }
+}
+
+impl Default for Runner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Runner {
+    /// Run every selected scenario of the feature in order
+    pub async fn run(&self, feature: &Feature, browser: &mut Browser) -> ExecutionResult {
+        self.run_with_state(feature, browser, &RunState::default())
+            .await
+    }
+
+    /// Parse and run a feature file, recording its path in the result
+    pub async fn run_file(
+        &self,
+        path: impl AsRef<std::path::Path>,
+        browser: &mut Browser,
+    ) -> crate::error::Result<ExecutionResult> {
+        let path = path.as_ref();
+        let feature = crate::gherkin::parse_feature_file(path)?;
+        let mut result = self.run(&feature, browser).await;
+        result.feature.file = Some(path.to_string_lossy().to_string());
+        Ok(result)
+    }
+
+    /// Like [`Runner::run`], but steps read and write the given state
+    pub async fn run_with_state(
+        &self,
+        feature: &Feature,
+        browser: &mut Browser,
+        state: &RunState,
+    ) -> ExecutionResult {
+        let start_time = std::time::Instant::now();
+        let scenarios = self.scenarios(feature);
+        if scenarios.is_empty() {
+            return self.feature_result(feature, Vec::new(), start_time);
+        }
+
+        let mut scenario_results = Vec::new();
+        match self
+            .run_hooks(HookPoint::BeforeAll, None, None, browser, state)
+            .await
+        {
+            Some(failure) => {
+                scenario_results = scenarios
+                    .iter()
+                    .map(|scenario| blocked_by_hook(scenario, &failure))
+                    .collect();
+            }
+            None => {
+                for scenario in &scenarios {
+                    scenario_results.push(self.run_scenario(scenario, browser, state).await);
+                }
+            }
+        }
+        if let Some(failure) = self
+            .run_hooks(HookPoint::AfterAll, None, None, browser, state)
+            .await
+        {
+            add_after_all_failure(&mut scenario_results, failure);
+        }
+        self.feature_result(feature, scenario_results, start_time)
+    }
+
+    /// Run the selected scenarios concurrently on browsers from the pool
+    ///
+    /// Each scenario gets its own [`RunState`]; results keep the scenarios' order.
+    /// A scenario that cannot get a browser is failed with `BROWSER_UNAVAILABLE`.
+    /// `BeforeAll` and `AfterAll` hooks run on a browser from the same pool.
+    pub async fn run_parallel(&self, feature: &Feature, pool: &BrowserPool) -> ExecutionResult {
+        let start_time = std::time::Instant::now();
+        let scenarios = self.scenarios(feature);
+        if scenarios.is_empty() {
+            return self.feature_result(feature, Vec::new(), start_time);
+        }
+
+        let mut scenario_results = match self.run_pool_hooks(HookPoint::BeforeAll, pool, None).await
+        {
+            Some(failure) => scenarios
+                .iter()
+                .map(|scenario| blocked_by_hook(scenario, &failure))
+                .collect(),
+            None => {
+                futures_util::future::join_all(scenarios.iter().map(|scenario| async move {
+                    if let Some(skipped) = self.skip_if_aborted(scenario) {
+                        return skipped;
+                    }
+                    match pool.acquire().await {
+                        Ok(mut browser) => {
+                            self.run_scenario(scenario, &mut browser, &RunState::default())
+                                .await
+                        }
+                        Err(e) => scenario_not_started(
+                            scenario,
+                            ErrorInfo::new(
+                                "BROWSER_UNAVAILABLE",
+                                format!("Failed to launch browser: {}", e),
+                            ),
+                        ),
+                    }
+                }))
+                .await
+            }
+        };
+        if let Some(failure) = self.run_pool_hooks(HookPoint::AfterAll, pool, None).await {
+            add_after_all_failure(&mut scenario_results, failure);
+        }
+        self.feature_result(feature, scenario_results, start_time)
+    }
+
+    /// Feature result from its scenario results, in the order they ran
+    pub(crate) fn feature_result(
+        &self,
+        feature: &Feature,
+        scenario_results: Vec<ScenarioResult>,
+        start_time: std::time::Instant,
+    ) -> ExecutionResult {
+        let mut result = ExecutionResult::new(FeatureInfo {
+            name: feature.name.clone(),
+            file: None,
+            description: feature.description.clone(),
+        });
+        for scenario_result in scenario_results {
+            result.summary.add_scenario_result(&scenario_result);
+            result.add_scenario(scenario_result);
+        }
+        result.duration_ms = start_time.elapsed().as_millis() as u64;
+        result.seed = self.order.seed();
+        if result.scenarios.iter().any(|s| s.aborted) {
+            result.summary.aborted = self.aborted();
+        }
+        result.update_status();
+        result
+    }
+
+    /// Run the steps of one scenario and its hooks; steps after a failure are skipped
+    ///
+    /// Unless tagged [`SHARED_CONTEXT_TAG`], the scenario starts in a fresh browser
+    /// context with no stored values, so it cannot see what earlier scenarios left.
+    /// `After` hooks run even when a step or `Before` hook failed. A failed scenario
+    /// is retried as configured with [`Runner::with_retries`], keeping every attempt.
+    /// Retries of a shared-context scenario start from the stored values it started
+    /// with, but stay in the browser context the failed attempt left behind.
+    pub async fn run_scenario(
+        &self,
+        scenario: &Scenario,
+        browser: &mut Browser,
+        state: &RunState,
+    ) -> ScenarioResult {
+        if let Some(skipped) = self.skip_if_aborted(scenario) {
+            return skipped;
+        }
+        let shared = scenario.tags.iter().any(|t| t == SHARED_CONTEXT_TAG);
+        let stored_before = if shared && self.retries > 0 {
+            Some(state.stored.read().await.clone())
+        } else {
+            None
+        };
+        let mut earlier = Vec::new();
+        let mut result = self.run_attempt(scenario, browser, state).await;
+        while result.status == "failed" && earlier.len() < self.retries {
+            earlier.push(result);
+            if let Some(stored) = &stored_before {
+                *state.stored.write().await = stored.clone();
+            }
+            result = self.run_attempt(scenario, browser, state).await;
+        }
+        if result.status == "failed" {
+            self.count_failure();
+        }
+        result.with_earlier_attempts(earlier)
+    }
+
+    /// Failed result for a scenario cancelled before it finished, e.g. by a batch's
+    /// feature timeout; its `After` hooks still run on the browser
+    pub(crate) async fn cancelled_scenario(
+        &self,
+        scenario: &Scenario,
+        error: ErrorInfo,
+        browser: &mut Browser,
+        state: &RunState,
+    ) -> ScenarioResult {
+        let mut result = scenario_not_started(scenario, error);
+        if let Some(failure) = self
+            .run_hooks(
+                HookPoint::After,
+                Some(scenario),
+                Some(&result),
+                browser,
+                state,
+            )
+            .await
+        {
+            result.add_step(failure);
+            result.update_status();
+        }
+        self.count_failure();
+        result
+    }
+
+    /// Count a failed scenario towards the failure limit
+    pub(crate) fn count_failure(&self) {
+        if let Some(limit) = &self.failure_limit {
+            limit.failures.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    /// Skipped result for a scenario not started because the failure limit was reached
+    pub(crate) fn skip_if_aborted(&self, scenario: &Scenario) -> Option<ScenarioResult> {
+        let reason = self.aborted()?;
+        let mut result = ScenarioResult::new(scenario.name.clone())
+            .with_tags(scenario.tags.clone())
+            .with_location(scenario.location);
+        for (idx, step) in scenario.steps.iter().enumerate() {
+            let step_result = StepResult::new(step.text.clone(), step.keyword.clone())
+                .with_location(step.location)
+                .with_status("skipped");
+            result.add_step(if idx == 0 {
+                step_result.with_error(ErrorInfo::new(ABORTED, reason.clone()))
+            } else {
+                step_result
+            });
+        }
+        result.update_status();
+        result.aborted = true;
+        Some(result)
+    }
+
+    /// One run of a scenario, in a fresh context unless it shares the previous one
+    async fn run_attempt(
+        &self,
+        scenario: &Scenario,
+        browser: &mut Browser,
+        state: &RunState,
+    ) -> ScenarioResult {
+        if !scenario.tags.iter().any(|t| t == SHARED_CONTEXT_TAG) {
+            if let Err(e) = browser.new_context().await {
+                return scenario_not_started(
+                    scenario,
+                    ErrorInfo::new(
+                        "BROWSER_CONTEXT_FAILED",
+                        format!("Failed to open a fresh browser context: {}", e),
+                    )
+                    .with_suggestion(format!(
+                        "Tag the scenario {} to keep the previous context",
+                        SHARED_CONTEXT_TAG
+                    )),
+                );
+            }
+            state.stored.write().await.clear();
+        }
+
+        let start_time = std::time::Instant::now();
+        let scenario_deadline = self
+            .scenario_timeout
+            .map(|timeout| tokio::time::Instant::now() + timeout);
+        let mut result = ScenarioResult::new(scenario.name.clone())
+            .with_tags(scenario.tags.clone())
+            .with_location(scenario.location);
+        let mut failed = false;
+        if let Some(failure) = self
+            .run_hooks(
+                HookPoint::Before,
+                Some(scenario),
+                Some(&result),
+                browser,
+                state,
+            )
+            .await
+        {
+            result.add_step(failure);
+            failed = true;
+        }
+
+        for step in &scenario.steps {
+            let step_result = StepResult::new(step.text.clone(), step.keyword.clone())
+                .with_location(step.location);
+            if failed {
+                result.add_step(step_result.with_status("skipped"));
+                continue;
+            }
+
+            let step_start = tokio::time::Instant::now();
+            let (text, step_timeout) = split_step_timeout(&step.text);
+            let step_timeout = step_timeout
+                .or_else(|| tag_timeout(&scenario.tags))
+                .or(self.step_timeout);
+            let mut step_result = match self.resolve(text) {
+                Err(error) => step_result.with_status("failed").with_error(error),
+                Ok((name, params)) => {
+                    let step_deadline = step_timeout.map(|timeout| step_start + timeout);
+                    let deadline = match (scenario_deadline, step_deadline) {
+                        (Some(scenario), Some(step)) => Some(scenario.min(step)),
+                        (scenario, step) => scenario.or(step),
+                    };
+                    let run =
+                        self.execute(&name, &params, step.argument.as_ref(), browser, state, 0);
+                    let outcome = match deadline {
+                        Some(deadline) => tokio::time::timeout_at(deadline, run).await,
+                        None => Ok(run.await),
+                    };
+                    match outcome {
+                        Ok(Ok(output)) if output.is_empty() => step_result.with_status("passed"),
+                        Ok(Ok(output)) => step_result.with_status("passed").with_output(output),
+                        Ok(Err(e)) => step_result.with_status("failed").with_error(
+                            ErrorInfo::new("STEP_EXECUTION_FAILED", e)
+                                .with_suggestion("Review the step definition"),
+                        ),
+                        Err(_) if deadline != step_deadline => step_result
+                            .with_status("timeout")
+                            .with_error(
+                                ErrorInfo::new(
+                                    "SCENARIO_TIMEOUT",
+                                    format!(
+                                        "Scenario timed out after {:?}",
+                                        self.scenario_timeout.unwrap_or_default()
+                                    ),
+                                )
+                                .with_suggestion("Raise --scenario-timeout or split the scenario"),
+                            ),
+                        Err(_) => step_result.with_status("timeout").with_error(
+                            ErrorInfo::new(
+                                "STEP_TIMEOUT",
+                                format!(
+                                    "Step timed out after {:?}",
+                                    step_timeout.unwrap_or_default()
+                                ),
+                            )
+                            .with_suggestion(
+                                "Raise the limit with a '(timeout: 2m)' suffix, a @timeout=2m tag or --step-timeout",
+                            ),
+                        ),
+                    }
+                }
+            };
+            step_result.duration_ms = step_start.elapsed().as_millis() as u64;
+
+            failed = step_result.is_failure();
+            result.add_step(step_result);
+            if let Some(failure) = self
+                .run_hooks(
+                    HookPoint::AfterStep,
+                    Some(scenario),
+                    Some(&result),
+                    browser,
+                    state,
+                )
+                .await
+            {
+                result.add_step(failure);
+                failed = true;
+            }
+        }
+
+        if let Some(failure) = self
+            .run_hooks(
+                HookPoint::After,
+                Some(scenario),
+                Some(&result),
+                browser,
+                state,
+            )
+            .await
+        {
+            result.add_step(failure);
+        }
+
+        result.duration_ms = start_time.elapsed().as_millis() as u64;
+        result.update_status();
+        result
+    }
+
+    /// Execute a single step, returning its output message
+    ///
+    /// The step is cancelled after its `(timeout: ...)` suffix or the runner's step
+    /// timeout, if either is set.
+    pub async fn run_step(
+        &self,
+        step: &Step,
+        browser: &mut Browser,
+        state: &RunState,
+    ) -> Result<String, String> {
+        let (text, timeout) = split_step_timeout(&step.text);
+        let (name, params) = self.resolve(text).map_err(|e| e.message)?;
+        let run = self.execute(&name, &params, step.argument.as_ref(), browser, state, 0);
+        match timeout.or(self.step_timeout) {
+            Some(timeout) => tokio::time::timeout(timeout, run)
+                .await
+                .unwrap_or_else(|_| Err(format!("Step timed out after {:?}", timeout))),
+            None => run.await,
+        }
+    }
+
+    /// Run the hooks for `point` that apply, returning a failed entry for the first
+    /// one that fails
+    async fn run_hooks(
+        &self,
+        point: HookPoint,
+        scenario: Option<&Scenario>,
+        result: Option<&ScenarioResult>,
+        browser: &mut Browser,
+        state: &RunState,
+    ) -> Option<StepResult> {
+        for hook in self
+            .hooks
+            .iter()
+            .filter(|hook| hook.applies(point, scenario, result))
+        {
+            let start_time = std::time::Instant::now();
+            let run = async {
+                match &hook.action {
+                    HookAction::Handler(handler) => {
+                        let ctx = HookContext {
+                            browser: &mut *browser,
+                            state,
+                            scenario,
+                            result,
+                        };
+                        handler.run(ctx).await
+                    }
+                    HookAction::Steps(steps) => self.run_hook_steps(steps, browser, state).await,
+                }
+            };
+            let outcome = match self.step_timeout {
+                Some(timeout) => tokio::time::timeout(timeout, run)
+                    .await
+                    .unwrap_or_else(|_| Err(format!("timed out after {:?}", timeout))),
+                None => run.await,
+            };
+            if let Err(e) = outcome {
+                let mut failure = hook_failure(point, hook.name(), e);
+                failure.duration_ms = start_time.elapsed().as_millis() as u64;
+                return Some(failure);
+            }
+        }
+        None
+    }
+
+    /// Run feature-level hooks on a browser from the pool
+    ///
+    /// Hooks still running at `deadline` are cancelled and reported as failed;
+    /// their browser is discarded rather than returned to the pool.
+    pub(crate) async fn run_pool_hooks(
+        &self,
+        point: HookPoint,
+        pool: &BrowserPool,
+        deadline: Option<tokio::time::Instant>,
+    ) -> Option<StepResult> {
+        if !self.hooks.iter().any(|hook| hook.point() == point) {
+            return None;
+        }
+        let name = format!("{} hooks", point);
+        let timed_out = || hook_failure(point, &name, "the feature timed out".to_string());
+        let acquired = match deadline {
+            Some(deadline) => tokio::time::timeout_at(deadline, pool.acquire()).await,
+            None => Ok(pool.acquire().await),
+        };
+        let mut browser = match acquired {
+            Ok(Ok(browser)) => browser,
+            Ok(Err(e)) => {
+                return Some(hook_failure(
+                    point,
+                    &name,
+                    format!("Failed to launch browser: {}", e),
+                ));
+            }
+            Err(_) => return Some(timed_out()),
+        };
+
+        let state = RunState::default();
+        let run = self.run_hooks(point, None, None, &mut browser, &state);
+        let Some(deadline) = deadline else {
+            return run.await;
+        };
+        let outcome = tokio::time::timeout_at(deadline, run).await;
+        match outcome {
+            Ok(failure) => failure,
+            Err(_) => {
+                // The page may still be busy with the abandoned hook
+                browser.discard().await;
+                Some(timed_out())
+            }
+        }
+    }
+
+    /// Run the step texts of a hook, stopping at the first failure
+    async fn run_hook_steps(
+        &self,
+        steps: &[String],
+        browser: &mut Browser,
+        state: &RunState,
+    ) -> Result<(), String> {
+        for text in steps {
+            let (name, params) = self
+                .resolve(text)
+                .map_err(|e| format!("{}: {}", text, e.message))?;
+            self.execute(&name, &params, None, browser, state, 0)
+                .await
+                .map_err(|e| format!("{}: {}", text, e))?;
+        }
+        Ok(())
+    }
+
+    /// Handler name and parameters for a step, or the error to report for it
+    ///
+    /// In strict mode a step matching several handlers is an error rather than
+    /// running the first registered one.
+    fn resolve(&self, step_text: &str) -> Result<(String, Vec<String>), ErrorInfo> {
+        let mut matches = if self.strict {
+            self.registry.match_all(step_text)
+        } else {
+            self.registry.match_step(step_text).into_iter().collect()
+        };
+        if matches.len() > 1 {
+            let candidates: Vec<&str> = matches.iter().map(|(name, _)| name.as_str()).collect();
+            return Err(ErrorInfo::new(
+                "AMBIGUOUS_STEP",
+                format!(
+                    "Step '{}' matches {} steps: {}",
+                    step_text,
+                    candidates.len(),
+                    candidates.join(", ")
+                ),
+            )
+            .with_suggestion("Reword the step or change one of the patterns so only one matches"));
+        }
+        if !matches.is_empty() {
+            return Ok(matches.swap_remove(0));
+        }
+        Err(match self.registry.explain_mismatch(step_text) {
+            Some((name, reason)) => ErrorInfo::new(
+                "INVALID_PARAMETER",
+                format!("Step matches '{}' but {}", name, reason),
+            )
+            .with_suggestion(format!("Run 'web-spec search-steps {}'", name)),
+            None => ErrorInfo::new("UNKNOWN_STEP", format!("Unknown step: {}", step_text))
+                .with_suggestion("Run 'web-spec list-steps' to see available steps"),
+        })
+    }
+
+    /// Dispatch a matched step to its macro, custom or built-in handler
+    async fn execute(
+        &self,
+        name: &str,
+        params: &[String],
+        argument: Option<&StepArgument>,
+        browser: &mut Browser,
+        state: &RunState,
+        depth: usize,
+    ) -> Result<String, String> {
+        if let Some(macro_step) = self.registry.macro_step(name) {
+            return self
+                .run_macro(macro_step, params, browser, state, depth)
+                .await;
+        }
+        match self.registry.custom_step(name) {
+            Some(custom) => {
+                let ctx = StepContext {
+                    browser,
+                    params,
+                    argument,
+                    state,
+                };
+                custom.handler().execute(ctx).await
+            }
+            None => {
+                steps::execute_step(
+                    browser,
+                    name,
+                    params,
+                    argument,
+                    &state.extracted,
+                    &state.stored,
+                )
+                .await
+            }
+        }
+    }
+
+    /// Run the expanded steps of a macro, stopping at the first failure
+    async fn run_macro(
+        &self,
+        macro_step: &MacroStep,
+        params: &[String],
+        browser: &mut Browser,
+        state: &RunState,
+        depth: usize,
+    ) -> Result<String, String> {
+        if depth >= MAX_MACRO_DEPTH {
+            return Err(format!(
+                "Macro step '{}' is nested more than {} levels deep",
+                macro_step.id, MAX_MACRO_DEPTH
+            ));
+        }
+
+        let mut outputs = Vec::new();
+        for (idx, text) in macro_step.expand(params).iter().enumerate() {
+            let (name, params) = self.resolve(text).map_err(|e| {
+                format!(
+                    "Step {} of '{}' ({}): {}",
+                    idx + 1,
+                    macro_step.id,
+                    text,
+                    e.message
+                )
+            })?;
+            let output = Box::pin(self.execute(&name, &params, None, browser, state, depth + 1))
+                .await
+                .map_err(|e| {
+                    format!(
+                        "Step {} of '{}' ({}) failed: {}",
+                        idx + 1,
+                        macro_step.id,
+                        text,
+                        e
+                    )
+                })?;
+            if !output.is_empty() {
+                outputs.push(output);
+            }
+        }
+        Ok(outputs.join("; "))
+    }
+}
+
+/// Failed result for a scenario that could not be started; its first step carries the error
+pub(crate) fn scenario_not_started(scenario: &Scenario, error: ErrorInfo) -> ScenarioResult {
+    let mut result = ScenarioResult::new(scenario.name.clone())
+        .with_tags(scenario.tags.clone())
+        .with_location(scenario.location);
+    for (idx, step) in scenario.steps.iter().enumerate() {
+        let step_result =
+            StepResult::new(step.text.clone(), step.keyword.clone()).with_location(step.location);
+        result.add_step(if idx == 0 {
+            step_result.with_status("failed").with_error(error.clone())
+        } else {
+            step_result.with_status("skipped")
+        });
+    }
+    result.update_status();
+    result
+}
+
+/// Failed entry recorded in a scenario's steps for a hook
+fn hook_failure(point: HookPoint, name: &str, message: String) -> StepResult {
+    StepResult::new(name.to_string(), point.to_string())
+        .with_status("failed")
+        .with_error(
+            ErrorInfo::new(
+                "HOOK_FAILED",
+                format!("{} hook '{}' failed: {}", point, name, message),
+            )
+            .with_suggestion("Check the hook's steps or handler"),
+        )
+}
+
+/// Result for a scenario whose steps were skipped because a feature hook failed
+pub(crate) fn blocked_by_hook(scenario: &Scenario, failure: &StepResult) -> ScenarioResult {
+    let mut result = ScenarioResult::new(scenario.name.clone())
+        .with_tags(scenario.tags.clone())
+        .with_location(scenario.location);
+    result.add_step(failure.clone());
+    for step in &scenario.steps {
+        result.add_step(
+            StepResult::new(step.text.clone(), step.keyword.clone())
+                .with_location(step.location)
+                .with_status("skipped"),
+        );
+    }
+    result.update_status();
+    result
+}
+
+/// Record a failed `AfterAll` hook on the last scenario
+pub(crate) fn add_after_all_failure(results: &mut [ScenarioResult], failure: StepResult) {
+    if let Some(last) = results.last_mut() {
+        last.add_step(failure);
+        last.update_status();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gherkin::parse_feature;
+
+    #[test]
+    fn test_runner_applies_tag_filter() {
+        let feature = parse_feature(
+            r#"
+Feature: Tagged
+  @smoke
+  Scenario: Quick
+    Given I navigate to "https://example.com"
+
+  Scenario: Slow
+    Given I navigate to "https://example.com"
+"#,
+        )
+        .unwrap();
+
+        let runner = Runner::new().with_tags(Some(TagExpression::parse("@smoke").unwrap()));
+        let scenarios = runner.scenarios(&feature);
+        assert_eq!(scenarios.len(), 1);
+        assert_eq!(scenarios[0].name, "Quick");
+        assert_eq!(Runner::new().scenarios(&feature).len(), 2);
+    }
+
+    #[test]
+    fn test_runner_shuffles_scenarios_by_seed() {
+        let text: String = (1..=8)
+            .map(|i| {
+                format!(
+                    "  Scenario: S{}\n    Given I navigate to \"https://example.com\"\n",
+                    i
+                )
+            })
+            .collect();
+        let feature = parse_feature(&format!("Feature: Ordered\n{}", text)).unwrap();
+        let names = |runner: Runner| -> Vec<String> {
+            runner
+                .scenarios(&feature)
+                .into_iter()
+                .map(|scenario| scenario.name)
+                .collect()
+        };
+
+        let defined = names(Runner::new());
+        assert_eq!(defined[0], "S1");
+        let shuffled = names(Runner::new().with_order(ScenarioOrder::Random(42)));
+        assert_ne!(shuffled, defined);
+        assert_eq!(
+            shuffled,
+            names(Runner::new().with_order(ScenarioOrder::Random(42)))
+        );
+    }
+
+    #[test]
+    fn test_runner_skips_scenarios_after_max_failures() {
+        let feature = parse_feature(
+            r#"
+Feature: Limited
+  Scenario: Later
+    Given I navigate to "https://example.com"
+    Then the title should be "Example Domain"
+
+  Scenario: Empty
+"#,
+        )
+        .unwrap();
+        let scenarios = feature.executable_scenarios();
+        let scenario = &scenarios[0];
+
+        let runner = Runner::new().with_max_failures(Some(2));
+        let shared = runner.clone();
+        assert!(runner.skip_if_aborted(scenario).is_none());
+        if let Some(limit) = &runner.failure_limit {
+            limit.failures.fetch_add(2, Ordering::SeqCst);
+        }
+
+        let skipped = shared.skip_if_aborted(scenario).unwrap();
+        assert_eq!(skipped.status, "skipped");
+        assert!(skipped.aborted);
+        assert_eq!(
+            shared.aborted().as_deref(),
+            Some("Stopped after 2 failed scenario(s) (max failures: 2)")
+        );
+        assert!(shared.skip_if_aborted(&scenarios[1]).unwrap().aborted);
+        assert!(Runner::new().aborted().is_none());
+
+        shared.reset_failures();
+        assert!(runner.aborted().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_failure_limit_spans_the_files_of_a_rerun() {
+        let dir = tempfile::tempdir().unwrap();
+        let files: Vec<_> = ["first", "second"]
+            .into_iter()
+            .map(|name| {
+                let path = dir.path().join(format!("{}.feature", name));
+                std::fs::write(
+                    &path,
+                    format!(
+                        "Feature: {}\n  Scenario: Fails\n    Given I navigate to \"https://example.com\"\n",
+                        name
+                    ),
+                )
+                .unwrap();
+                path
+            })
+            .collect();
+
+        // As `run --rerun-failed --max-failures 1` runs each file with a clone
+        let runner = Runner::new().with_max_failures(Some(1));
+        let mut browser = Browser::from_backend(crate::backend::no_page::NoPage);
+        let mut results = Vec::new();
+        for file in &files {
+            results.push(runner.clone().run_file(file, &mut browser).await.unwrap());
+        }
+
+        assert_eq!(results[0].scenarios[0].status, "failed");
+        assert!(!results[0].scenarios[0].aborted);
+        assert!(results[1].scenarios[0].aborted);
+        assert_eq!(
+            results[1].summary.aborted.as_deref(),
+            Some("Stopped after 1 failed scenario(s) (max failures: 1)")
+        );
+        // Reported like `batch_summary.aborted` for batches
+        let json = serde_json::to_value(&results[1]).unwrap();
+        assert_eq!(
+            json["summary"]["aborted"],
+            "Stopped after 1 failed scenario(s) (max failures: 1)"
+        );
+        assert!(serde_json::to_value(&results[0]).unwrap()["summary"]["aborted"].is_null());
+    }
+
+    #[test]
+    fn test_runner_applies_location_filter() {
+        let feature = parse_feature(
+            r#"
+Feature: Outline
+  Scenario Outline: Visit
+    Given I navigate to "<url>"
+
+    Examples:
+      | url                 |
+      | https://example.com |
+      | https://example.org |
+"#,
+        )
+        .unwrap();
+
+        let runner = Runner::new().with_locations(Some(vec![Location::new(9, 7)]));
+        let scenarios = runner.scenarios(&feature);
+        assert_eq!(scenarios.len(), 1);
+        assert_eq!(
+            scenarios[0].steps[0].text,
+            r#"I navigate to "https://example.org""#
+        );
+    }
+
+    #[test]
+    fn test_strict_runner_refuses_ambiguous_steps() {
+        let noop = |id: &str, pattern: &str| {
+            CustomStep::from_fn(id, pattern, |_| Box::pin(async { Ok(String::new()) }))
+        };
+        let runner = Runner::new()
+            .with_step(noop("open_admin", r#"I open "admin""#))
+            .unwrap()
+            .with_step(noop("open_page", "I open {string}"))
+            .unwrap();
+
+        assert_eq!(runner.resolve(r#"I open "admin""#).unwrap().0, "open_admin");
+
+        let strict = runner.with_strict(true);
+        let error = strict.resolve(r#"I open "admin""#).unwrap_err();
+        assert_eq!(error.code, "AMBIGUOUS_STEP");
+        assert!(error.message.contains("open_admin, open_page"));
+        assert_eq!(strict.resolve(r#"I open "home""#).unwrap().0, "open_page");
+        assert_eq!(
+            strict.resolve("I frobnicate").unwrap_err().code,
+            "UNKNOWN_STEP"
+        );
+    }
+
+    #[test]
+    fn test_with_step_rejects_duplicate_ids_and_bad_patterns() {
+        let noop = |id: &str, pattern: &str| {
+            CustomStep::from_fn(id, pattern, |_| Box::pin(async { Ok(String::new()) }))
+        };
+        let runner = Runner::new().with_step(noop("login", "I log in")).unwrap();
+
+        assert!(
+            runner
+                .clone()
+                .with_step(noop("login", "I sign in"))
+                .is_err()
+        );
+        assert!(
+            runner
+                .clone()
+                .with_step(noop("browser_available", "I go home"))
+                .is_err()
+        );
+        assert!(runner.with_step(noop("broken", "I open (")).is_err());
+    }
+
+    #[test]
+    fn test_feature_hook_failures_fail_scenarios() {
+        let feature = parse_feature(
+            r#"
+Feature: Hooks
+  Scenario: First
+    Given I navigate to "https://example.com"
+    Then I should see "Example"
+"#,
+        )
+        .unwrap();
+        let scenarios = Runner::new().scenarios(&feature);
+        let failure = hook_failure(HookPoint::BeforeAll, "seed data", "boom".to_string());
+
+        let mut results = vec![blocked_by_hook(&scenarios[0], &failure)];
+        assert_eq!(results[0].status, "failed");
+        assert_eq!(results[0].steps[0].keyword, "BeforeAll");
+        assert_eq!(
+            results[0].steps[0].error.as_ref().unwrap().code,
+            "HOOK_FAILED"
+        );
+        assert!(results[0].steps[1..].iter().all(|s| s.status == "skipped"));
+
+        results[0] = ScenarioResult::new("First".to_string());
+        results[0].add_step(
+            StepResult::new("a step".to_string(), "Given".to_string()).with_status("passed"),
+        );
+        results[0].update_status();
+        add_after_all_failure(
+            &mut results,
+            hook_failure(HookPoint::AfterAll, "cleanup", "boom".to_string()),
+        );
+        assert_eq!(results[0].status, "failed");
+        assert_eq!(results[0].steps.len(), 2);
+    }
+}
@@ -0,0 +1,117 @@
+// Order in which the runner executes scenarios
+use std::fmt;
+use std::str::FromStr;
+
+/// Scenario order: as written in the file, or shuffled by a seed
+///
+/// Parsed from `defined`, `random` (a fresh seed) or `random:SEED`; the same seed
+/// always gives the same order for the same scenarios.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScenarioOrder {
+    #[default]
+    Defined,
+    Random(u64),
+}
+
+impl ScenarioOrder {
+    /// Random order with a seed taken from the clock
+    pub fn random() -> Self {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or_default();
+        ScenarioOrder::Random(splitmix64(&mut (nanos ^ std::process::id() as u64)))
+    }
+
+    pub fn seed(&self) -> Option<u64> {
+        match self {
+            ScenarioOrder::Defined => None,
+            ScenarioOrder::Random(seed) => Some(*seed),
+        }
+    }
+
+    /// Put items in this order; a Fisher-Yates shuffle driven by the seed
+    pub fn apply<T>(&self, items: &mut [T]) {
+        let ScenarioOrder::Random(seed) = *self else {
+            return;
+        };
+        let mut state = seed;
+        for i in (1..items.len()).rev() {
+            let j = (splitmix64(&mut state) % (i as u64 + 1)) as usize;
+            items.swap(i, j);
+        }
+    }
+}
+
+impl FromStr for ScenarioOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "defined" => Ok(ScenarioOrder::Defined),
+            "random" => Ok(ScenarioOrder::random()),
+            other => other
+                .strip_prefix("random:")
+                .and_then(|seed| seed.trim().parse().ok())
+                .map(ScenarioOrder::Random)
+                .ok_or_else(|| {
+                    format!(
+                        "Invalid order '{}': expected defined, random or random:SEED",
+                        other
+                    )
+                }),
+        }
+    }
+}
+
+impl fmt::Display for ScenarioOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScenarioOrder::Defined => write!(f, "defined"),
+            ScenarioOrder::Random(seed) => write!(f, "random:{}", seed),
+        }
+    }
+}
+
+/// SplitMix64: small, fast and identical on every platform
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_order() {
+        assert_eq!("defined".parse(), Ok(ScenarioOrder::Defined));
+        assert_eq!("random:42".parse(), Ok(ScenarioOrder::Random(42)));
+        assert!(matches!(
+            "random".parse::<ScenarioOrder>(),
+            Ok(ScenarioOrder::Random(_))
+        ));
+        assert!("shuffled".parse::<ScenarioOrder>().is_err());
+        assert_eq!(ScenarioOrder::Random(7).to_string(), "random:7");
+    }
+
+    #[test]
+    fn test_same_seed_gives_same_order() {
+        let shuffle = |order: ScenarioOrder| {
+            let mut items: Vec<usize> = (0..20).collect();
+            order.apply(&mut items);
+            items
+        };
+
+        assert_eq!(shuffle(ScenarioOrder::Defined), (0..20).collect::<Vec<_>>());
+        let first = shuffle(ScenarioOrder::Random(42));
+        assert_eq!(first, shuffle(ScenarioOrder::Random(42)));
+        assert_ne!(first, shuffle(ScenarioOrder::Random(43)));
+        let mut sorted = first.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..20).collect::<Vec<_>>());
+    }
+}
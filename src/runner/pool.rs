@@ -0,0 +1,172 @@
+// Pool of browsers shared by concurrently running scenarios
+use crate::backend::BrowserConfig;
+use crate::browser::{Browser, BrowserType};
+use crate::error::Result;
+use std::ops::{Deref, DerefMut};
+use std::sync::Mutex;
+use tokio::sync::{Semaphore, SemaphorePermit};
+
+/// Up to `size` isolated browsers, launched on first use and reused afterwards
+///
+/// ```no_run
+/// # async fn example() -> web_spec::Result<()> {
+/// use web_spec::parse_feature_file;
+/// use web_spec::runner::{BrowserPool, Runner};
+///
+/// let feature = parse_feature_file("features/login.feature")?;
+/// let pool = BrowserPool::new(4);
+/// let result = Runner::new().run_parallel(&feature, &pool).await;
+/// pool.close().await;
+/// # Ok(())
+/// # }
+/// ```
+pub struct BrowserPool {
+    idle: Mutex<Vec<Browser>>,
+    permits: Semaphore,
+    size: usize,
+    config: BrowserConfig,
+}
+
+impl BrowserPool {
+    /// Pool of at most `size` browsers (at least one)
+    pub fn new(size: usize) -> Self {
+        let size = size.max(1);
+        BrowserPool {
+            idle: Mutex::new(Vec::new()),
+            permits: Semaphore::new(size),
+            size,
+            config: BrowserConfig::default(),
+        }
+    }
+
+    /// Launch browsers with this backend instead of the default one
+    pub fn with_browser_type(mut self, browser_type: BrowserType) -> Self {
+        self.config.backend = browser_type;
+        self
+    }
+
+    /// Launch browsers with these options
+    pub fn with_config(mut self, config: BrowserConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Pool over already launched browsers; replacements are launched isolated, with
+    /// the same backend
+    pub fn from_browsers(browsers: Vec<Browser>) -> Self {
+        let mut pool = Self::new(browsers.len());
+        if let Some(browser) = browsers.first() {
+            pool.config.backend = browser.browser_type();
+        }
+        *pool.idle.lock().unwrap() = browsers;
+        pool
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Wait for a free browser, launching one if none is idle
+    ///
+    /// The browser goes back to the pool when the guard is dropped.
+    pub async fn acquire(&self) -> Result<PooledBrowser<'_>> {
+        let permit = self
+            .permits
+            .acquire()
+            .await
+            .expect("browser pool semaphore is never closed");
+        let idle = self.idle.lock().unwrap().pop();
+        let browser = match idle {
+            Some(browser) => browser,
+            None => Browser::launch_isolated(&self.config).await?,
+        };
+        Ok(PooledBrowser {
+            pool: self,
+            browser: Some(browser),
+            _permit: permit,
+        })
+    }
+
+    /// Close every idle browser
+    pub async fn close(self) {
+        for browser in self.idle.into_inner().unwrap() {
+            let _ = browser.close().await;
+        }
+    }
+}
+
+impl std::fmt::Debug for BrowserPool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BrowserPool")
+            .field("size", &self.size)
+            .field("backend", &self.config.backend)
+            .field("idle", &self.idle.lock().map(|b| b.len()).unwrap_or(0))
+            .finish()
+    }
+}
+
+/// A browser borrowed from a [`BrowserPool`]
+pub struct PooledBrowser<'a> {
+    pool: &'a BrowserPool,
+    browser: Option<Browser>,
+    _permit: SemaphorePermit<'a>,
+}
+
+impl PooledBrowser<'_> {
+    /// Close the browser instead of returning it, e.g. after a timeout left it busy;
+    /// the pool launches a replacement when needed
+    pub async fn discard(mut self) {
+        if let Some(browser) = self.browser.take() {
+            let _ = browser.close().await;
+        }
+    }
+}
+
+impl Deref for PooledBrowser<'_> {
+    type Target = Browser;
+
+    fn deref(&self) -> &Browser {
+        self.browser.as_ref().expect("pooled browser is present")
+    }
+}
+
+impl DerefMut for PooledBrowser<'_> {
+    fn deref_mut(&mut self) -> &mut Browser {
+        self.browser.as_mut().expect("pooled browser is present")
+    }
+}
+
+impl Drop for PooledBrowser<'_> {
+    fn drop(&mut self) {
+        if let Some(browser) = self.browser.take() {
+            self.pool.idle.lock().unwrap().push(browser);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pool_has_at_least_one_browser() {
+        assert_eq!(BrowserPool::new(0).size(), 1);
+        assert_eq!(BrowserPool::new(4).size(), 4);
+        assert_eq!(BrowserPool::from_browsers(Vec::new()).size(), 1);
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn test_pool_reuses_browsers() {
+        let pool = BrowserPool::new(1);
+        {
+            let mut browser = pool.acquire().await.unwrap();
+            browser.navigate_to("about:blank").await.unwrap();
+        }
+        let _browser = pool.acquire().await.unwrap();
+        assert!(pool.idle.lock().unwrap().is_empty());
+        drop(_browser);
+        assert_eq!(pool.idle.lock().unwrap().len(), 1);
+        pool.close().await;
+    }
+}
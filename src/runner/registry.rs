@@ -0,0 +1,379 @@
+// Step pattern registry mapping step text to step handlers
+use super::custom::CustomStep;
+use super::macro_steps::MacroStep;
+use crate::discovery::catalog::{StepCatalog, StepInfo, build_step_catalog};
+use crate::discovery::definitions::{STEP_DEFINITIONS, find_definition};
+use crate::discovery::expression::{StepPattern, closest_matches, infer_parameters};
+use crate::error::{Result, WebSpecError};
+
+/// Ordered list of step patterns; the first matching pattern wins
+///
+/// Custom and macro steps are tried before the built-in patterns.
+#[derive(Debug, Clone, Default)]
+pub struct StepRegistry {
+    patterns: Vec<(StepPattern, String)>,
+    custom_patterns: Vec<(StepPattern, String)>,
+    custom_steps: Vec<CustomStep>,
+    macro_steps: Vec<MacroStep>,
+}
+
+impl StepRegistry {
+    pub fn new() -> Self {
+        Self {
+            patterns: Vec::new(),
+            custom_patterns: Vec::new(),
+            custom_steps: Vec::new(),
+            macro_steps: Vec::new(),
+        }
+    }
+
+    /// Register a regex or Cucumber Expression pattern for the named step handler
+    ///
+    /// Panics if the pattern does not compile.
+    pub fn register(&mut self, pattern: &str, name: &str) {
+        let compiled = StepPattern::compile(pattern)
+            .unwrap_or_else(|e| panic!("invalid step pattern {}: {}", pattern, e));
+        self.patterns.push((compiled, name.to_string()));
+    }
+
+    /// Register a custom step, checking its patterns and that its id is not taken
+    pub fn register_step(&mut self, step: CustomStep) -> Result<()> {
+        self.register_custom_patterns(step.info())?;
+        self.custom_steps.push(step);
+        Ok(())
+    }
+
+    /// Register a macro step that expands into other steps
+    pub fn register_macro(&mut self, step: MacroStep) -> Result<()> {
+        let info = step.info();
+        let captures = StepPattern::compile(&info.pattern)
+            .map(|p| p.parameters.len())
+            .unwrap_or(0);
+        if step.params.len() > captures {
+            return Err(WebSpecError::StepPattern(format!(
+                "{} names {} params but its pattern captures {}",
+                step.id,
+                step.params.len(),
+                captures
+            )));
+        }
+        self.register_custom_patterns(&info)?;
+        self.macro_steps.push(step);
+        Ok(())
+    }
+
+    fn register_custom_patterns(&mut self, info: &StepInfo) -> Result<()> {
+        let taken = self.custom_step(&info.id).is_some()
+            || self.macro_step(&info.id).is_some()
+            || find_definition(&info.id).is_some();
+        if taken {
+            return Err(WebSpecError::StepPattern(format!(
+                "step id '{}' is already registered",
+                info.id
+            )));
+        }
+        for pattern in compile_patterns(info)? {
+            self.custom_patterns.push((pattern, info.id.clone()));
+        }
+        Ok(())
+    }
+
+    /// Custom step registered under the given id
+    pub fn custom_step(&self, id: &str) -> Option<&CustomStep> {
+        self.custom_steps.iter().find(|s| s.id() == id)
+    }
+
+    pub fn custom_steps(&self) -> &[CustomStep] {
+        &self.custom_steps
+    }
+
+    /// Macro step registered under the given id
+    pub fn macro_step(&self, id: &str) -> Option<&MacroStep> {
+        self.macro_steps.iter().find(|s| s.id == id)
+    }
+
+    pub fn macro_steps(&self) -> &[MacroStep] {
+        &self.macro_steps
+    }
+
+    /// Built-in step catalog extended with the registered custom and macro steps
+    pub fn catalog(&self) -> StepCatalog {
+        let mut catalog = build_step_catalog();
+        for step in &self.custom_steps {
+            let mut info = step.info().clone();
+            if info.parameters.is_empty() {
+                info.parameters = infer_parameters(&info.pattern);
+            }
+            catalog.add_step(info);
+        }
+        for step in &self.macro_steps {
+            catalog.add_step(step.info());
+        }
+        catalog
+    }
+
+    /// Find the handler name and captured parameters for a step
+    ///
+    /// When several handlers match, the first registered one wins; see
+    /// [`StepRegistry::match_all`] to detect that.
+    pub fn match_step(&self, step_text: &str) -> Option<(String, Vec<String>)> {
+        self.custom_patterns
+            .iter()
+            .chain(&self.patterns)
+            .find_map(|(pattern, name)| Some((name.clone(), pattern.captures(step_text)?)))
+    }
+
+    /// Every distinct handler that matches the step as closely as any other
+    ///
+    /// Custom and macro steps shadow built-ins, so built-in handlers are only
+    /// returned when no custom pattern matches, and built-ins drop out for the
+    /// steps they yield to. More than one result means the step is ambiguous.
+    pub fn match_all(&self, step_text: &str) -> Vec<(String, Vec<String>)> {
+        let mut matches = closest_matches(tier(&self.custom_patterns), step_text);
+        if matches.is_empty() {
+            matches = closest_matches(tier(&self.patterns), step_text);
+        }
+        matches
+            .into_iter()
+            .map(|(name, params)| (name.to_string(), params))
+            .collect()
+    }
+
+    /// For text that matches no pattern, the handler name and the reason a typed
+    /// parameter was rejected, e.g. a word where `{int}` is expected
+    pub fn explain_mismatch(&self, step_text: &str) -> Option<(String, String)> {
+        self.custom_patterns
+            .iter()
+            .chain(&self.patterns)
+            .find_map(|(pattern, name)| Some((name.clone(), pattern.explain_mismatch(step_text)?)))
+    }
+
+    /// Registered patterns and their handler names, in match order
+    pub fn patterns(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.custom_patterns
+            .iter()
+            .chain(&self.patterns)
+            .map(|(pattern, name)| (pattern.source.as_str(), name.as_str()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.custom_patterns.len() + self.patterns.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+fn tier(patterns: &[(StepPattern, String)]) -> impl Iterator<Item = (&StepPattern, &str)> {
+    patterns
+        .iter()
+        .map(|(pattern, name)| (pattern, name.as_str()))
+}
+
+/// Compile a step's primary pattern and aliases
+pub(crate) fn compile_patterns(info: &StepInfo) -> Result<Vec<StepPattern>> {
+    std::iter::once(&info.pattern)
+        .chain(&info.aliases)
+        .map(|pattern| {
+            StepPattern::compile(pattern)
+                .map_err(|e| WebSpecError::StepPattern(format!("{} ({}): {}", info.id, pattern, e)))
+        })
+        .collect()
+}
+
+/// Registry matching the steps of a catalog, e.g. to validate steps without running them
+///
+/// Steps with a built-in definition are registered as built-ins and the rest
+/// shadow them like custom steps. Patterns that do not compile are left out.
+pub fn registry_for_catalog(catalog: &StepCatalog) -> StepRegistry {
+    let mut registry = StepRegistry::new();
+    for info in catalog.all_steps() {
+        let tier = if find_definition(&info.id).is_some() {
+            &mut registry.patterns
+        } else {
+            &mut registry.custom_patterns
+        };
+        for pattern in std::iter::once(&info.pattern).chain(&info.aliases) {
+            if let Ok(compiled) = StepPattern::compile(pattern) {
+                tier.push((compiled, info.id.clone()));
+            }
+        }
+    }
+    registry
+}
+
+/// Registry with every built-in step pattern, derived from [`STEP_DEFINITIONS`]
+pub fn build_step_registry() -> StepRegistry {
+    let mut registry = StepRegistry::new();
+    for definition in STEP_DEFINITIONS {
+        for pattern in definition.patterns {
+            registry.register(pattern, definition.id);
+        }
+    }
+    registry
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_match_step_captures_parameters() {
+        let registry = build_step_registry();
+        let (name, params) = registry
+            .match_step(r#"I type "hello" into "input.search""#)
+            .unwrap();
+        assert_eq!(name, "type_text");
+        assert_eq!(params, vec!["hello", "input.search"]);
+    }
+
+    #[test]
+    fn test_builtin_steps_are_not_ambiguous() {
+        let registry = build_step_registry();
+        for (text, name) in [
+            (r#"I click the "checkout" button"#, "click_button"),
+            (r#"I take a screenshot "home.png""#, "screenshot"),
+            (
+                r#"there should be 3 ".item""#,
+                "should_see_exact_count_elements",
+            ),
+            ("I extract all h2 headings", "extract_h2"),
+            (r#"I extract all ".item""#, "extract_all_by_selector"),
+            ("I scroll to bottom", "scroll_bottom"),
+            ("I set viewport to 800x600", "resize_window"),
+        ] {
+            let matches = registry.match_all(text);
+            assert_eq!(matches.len(), 1, "{} matches {:?}", text, matches);
+            assert_eq!(matches[0].0, name);
+            assert_eq!(registry.match_step(text).unwrap().0, name);
+        }
+        let (name, params) = registry.match_step(r#"there should be 3 ".item""#).unwrap();
+        assert_eq!(name, "should_see_exact_count_elements");
+        assert_eq!(params, vec!["3", ".item"]);
+    }
+
+    #[test]
+    fn test_match_all_reports_every_candidate() {
+        let mut registry = build_step_registry();
+        registry
+            .register_step(custom("open_admin", r#"I open "admin""#))
+            .unwrap();
+        registry
+            .register_step(custom("open_page", r"I open {string}"))
+            .unwrap();
+
+        let names: Vec<String> = registry
+            .match_all(r#"I open "admin""#)
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(names, vec!["open_admin", "open_page"]);
+        assert_eq!(
+            registry.match_step(r#"I open "admin""#).unwrap().0,
+            "open_admin"
+        );
+    }
+
+    #[test]
+    fn test_match_step_keeps_registration_order() {
+        let mut registry = build_step_registry();
+        registry
+            .register_step(custom("open_page", r#"I open "([^"]+)""#))
+            .unwrap();
+        registry
+            .register_step(custom("open_admin_page", r#"I open "admin" page"#))
+            .unwrap();
+
+        let text = r#"I open "admin" page"#;
+        assert_eq!(registry.match_step(text).unwrap().0, "open_page");
+        let names: Vec<String> = registry
+            .match_all(text)
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(names, vec!["open_admin_page"]);
+    }
+
+    #[test]
+    fn test_unknown_step_does_not_match() {
+        let registry = build_step_registry();
+        assert!(registry.match_step("I foobarbaz something").is_none());
+    }
+
+    fn custom(id: &str, pattern: &str) -> CustomStep {
+        CustomStep::from_fn(id, pattern, |_| Box::pin(async { Ok(String::new()) }))
+    }
+
+    #[test]
+    fn test_custom_steps_match_first_and_join_the_catalog() {
+        let mut registry = build_step_registry();
+        registry
+            .register_step(custom("open_dashboard", r#"I open "dashboard""#))
+            .unwrap();
+
+        let (name, params) = registry.match_step(r#"I open "dashboard""#).unwrap();
+        assert_eq!(name, "open_dashboard");
+        assert!(params.is_empty());
+        assert!(registry.custom_step("open_dashboard").is_some());
+
+        let catalog = registry.catalog();
+        let info = catalog.find_by_id("open_dashboard").unwrap();
+        assert_eq!(info.category, "Custom");
+        assert!(catalog.categories.contains(&"Custom".to_string()));
+    }
+
+    #[test]
+    fn test_register_step_rejects_duplicates_and_bad_patterns() {
+        let mut registry = StepRegistry::new();
+        registry
+            .register_step(custom("login", r"I log in"))
+            .unwrap();
+        assert!(
+            registry
+                .register_step(custom("login", r"I sign in"))
+                .is_err()
+        );
+        assert!(registry.register_step(custom("broken", r"I (")).is_err());
+        assert_eq!(registry.len(), 1);
+    }
+
+    #[test]
+    fn test_macro_steps_match_and_join_the_catalog() {
+        let steps = crate::runner::macro_steps::parse_macro_steps(
+            r##"
+steps:
+  - id: search_for
+    pattern: 'I search the site for "([^"]+)"'
+    params: [query]
+    steps:
+      - I type "<query>" into "#search"
+      - I press "Enter"
+  - id: too_many_params
+    pattern: 'I do nothing'
+    params: [what]
+    steps: []
+"##,
+        )
+        .unwrap();
+
+        let mut registry = build_step_registry();
+        registry.register_macro(steps[0].clone()).unwrap();
+        assert!(registry.register_macro(steps[0].clone()).is_err());
+        assert!(registry.register_macro(steps[1].clone()).is_err());
+
+        let (name, params) = registry
+            .match_step(r#"I search the site for "shoes""#)
+            .unwrap();
+        assert_eq!(name, "search_for");
+        assert_eq!(params, vec!["shoes"]);
+        assert_eq!(
+            registry
+                .catalog()
+                .find_by_id("search_for")
+                .unwrap()
+                .category,
+            "Custom"
+        );
+    }
+}
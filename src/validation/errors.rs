@@ -1,4 +1,5 @@
 // Validation error types
+use crate::gherkin::Location;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -12,6 +13,8 @@ pub struct ValidationResult {
 pub struct ValidationError {
     pub step_number: Option<usize>,
     pub step_text: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
     pub error_type: String,
     pub message: String,
     pub suggestions: Vec<String>,
@@ -21,6 +24,8 @@ pub struct ValidationError {
 pub struct ValidationWarning {
     pub step_number: Option<usize>,
     pub step_text: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
     pub warning_type: String,
     pub message: String,
 }
@@ -67,6 +72,7 @@ impl ValidationError {
         ValidationError {
             step_number: None,
             step_text: None,
+            location: None,
             error_type: error_type.into(),
             message: message.into(),
             suggestions: Vec::new(),
@@ -79,6 +85,11 @@ impl ValidationError {
         self
     }
 
+    pub fn with_location(mut self, location: Location) -> Self {
+        self.location = Some(location);
+        self
+    }
+
     pub fn with_suggestion(mut self, suggestion: impl Into<String>) -> Self {
         self.suggestions.push(suggestion.into());
         self
@@ -95,6 +106,7 @@ impl ValidationWarning {
         ValidationWarning {
             step_number: None,
             step_text: None,
+            location: None,
             warning_type: warning_type.into(),
             message: message.into(),
         }
@@ -105,4 +117,9 @@ impl ValidationWarning {
         self.step_text = Some(step_text);
         self
     }
+
+    pub fn with_location(mut self, location: Location) -> Self {
+        self.location = Some(location);
+        self
+    }
 }
@@ -1,19 +1,63 @@
 // Feature-level validation
 use super::errors::{ValidationError, ValidationResult, ValidationWarning};
-use crate::discovery::catalog::build_step_catalog;
+use crate::discovery::catalog::{StepCatalog, build_step_catalog};
+use crate::error::WebSpecError;
+use crate::gherkin::ast::merge_tags;
+use crate::gherkin::{
+    Background, Examples, Feature, Location, Scenario, Step, TagExpression, parse_feature,
+};
+use crate::runner::registry::registry_for_catalog;
 use std::fs;
 
+/// Options controlling which parts of a feature file are validated
+#[derive(Debug, Clone, Default)]
+pub struct ValidationOptions {
+    /// Only validate scenarios whose tags match this expression
+    pub tags: Option<TagExpression>,
+    /// Steps to validate against; defaults to the built-in catalog
+    pub catalog: Option<StepCatalog>,
+}
+
+impl ValidationOptions {
+    /// Validate against a catalog that includes custom steps, e.g. [`crate::Runner::catalog`]
+    pub fn with_catalog(mut self, catalog: StepCatalog) -> Self {
+        self.catalog = Some(catalog);
+        self
+    }
+}
+
 pub fn validate_feature(feature_file_path: &str) -> Result<ValidationResult, String> {
+    validate_feature_with(feature_file_path, &ValidationOptions::default())
+}
+
+pub fn validate_feature_with(
+    feature_file_path: &str,
+    options: &ValidationOptions,
+) -> Result<ValidationResult, String> {
     // Read file
     let content =
         fs::read_to_string(feature_file_path).map_err(|e| format!("Failed to read file: {}", e))?;
 
-    validate_feature_content(&content)
+    validate_feature_content_with(&content, options)
 }
 
 pub fn validate_feature_content(content: &str) -> Result<ValidationResult, String> {
+    validate_feature_content_with(content, &ValidationOptions::default())
+}
+
+pub fn validate_feature_content_with(
+    content: &str,
+    options: &ValidationOptions,
+) -> Result<ValidationResult, String> {
     let mut result = ValidationResult::new();
-    let catalog = build_step_catalog();
+    let built_in;
+    let catalog = match &options.catalog {
+        Some(catalog) => catalog,
+        None => {
+            built_in = build_step_catalog();
+            &built_in
+        }
+    };
 
     // Basic syntax checks
     if !content.to_uppercase().contains("FEATURE:") {
@@ -21,38 +65,65 @@ pub fn validate_feature_content(content: &str) -> Result<ValidationResult, Strin
             "MISSING_FEATURE",
             "Feature file must start with 'Feature:' declaration",
         ));
+        return Ok(result);
     }
 
-    if !content.to_uppercase().contains("SCENARIO:") {
-        result.add_warning(ValidationWarning::new(
-            "NO_SCENARIOS",
-            "Feature file contains no scenarios",
-        ));
+    let feature = match parse_feature(content) {
+        Ok(feature) => feature,
+        Err(e) => {
+            let mut error = ValidationError::new("PARSE_ERROR", e.to_string())
+                .with_suggestion("Check the Gherkin syntax near the reported line");
+            if let WebSpecError::Parse { line, .. } = e {
+                error = error.with_location(Location::new(line, 1));
+            }
+            result.add_error(error);
+            return Ok(result);
+        }
+    };
+
+    if feature.all_scenarios().is_empty() {
+        result.add_warning(
+            ValidationWarning::new("NO_SCENARIOS", "Feature file contains no scenarios")
+                .with_location(feature.location),
+        );
+    } else if options.tags.is_some() && feature.scenarios_matching(options.tags.as_ref()).is_empty()
+    {
+        result.add_warning(
+            ValidationWarning::new(
+                "NO_MATCHING_SCENARIOS",
+                "No scenarios match the tag expression",
+            )
+            .with_location(feature.location),
+        );
     }
 
-    // Parse and validate each step
-    let lines: Vec<&str> = content.lines().collect();
+    // Validate each step in file order: backgrounds once, outline steps once
+    // per Examples row so substituted values are checked too. Step numbers
+    // count every step in the file, selected or not.
+    let registry = registry_for_catalog(catalog);
     let mut step_number = 0;
+    for block in blocks_in_file_order(&feature, options.tags.as_ref()) {
+        for step in block.steps {
+            step_number += 1;
+            if !block.selected {
+                continue;
+            }
 
-    for (_line_idx, line) in lines.iter().enumerate() {
-        let trimmed = line.trim();
-
-        // Skip comments and empty lines
-        if trimmed.is_empty() || trimmed.starts_with('#') {
-            continue;
-        }
+            let variants: Vec<String> = match &block.examples {
+                Some(examples) => examples
+                    .iter()
+                    .flat_map(|e| e.rows.iter().map(|row| e.substitute(&step.text, row)))
+                    .collect(),
+                None => vec![step.text.clone()],
+            };
 
-        // Check if this is a step line (starts with Given, When, Then, And, But)
-        if is_step_line(trimmed) {
-            step_number += 1;
-            let step_text = extract_step_text(trimmed);
-
-            // Validate this step
-            if let Err(error) =
-                crate::validation::step::validate_step(step_text, step_number, &catalog)
-            {
-                // Add line number context
-                result.add_error(error);
+            for text in variants {
+                if let Err(error) =
+                    crate::validation::step::validate_step(&text, step_number, &registry, catalog)
+                {
+                    result.add_error(error.with_location(step.location));
+                    break;
+                }
             }
         }
     }
@@ -60,24 +131,81 @@ pub fn validate_feature_content(content: &str) -> Result<ValidationResult, Strin
     Ok(result)
 }
 
-fn is_step_line(line: &str) -> bool {
-    let trimmed = line.trim();
-    trimmed.starts_with("Given ")
-        || trimmed.starts_with("When ")
-        || trimmed.starts_with("Then ")
-        || trimmed.starts_with("And ")
-        || trimmed.starts_with("But ")
+/// A list of steps from the feature with the Examples tables that apply to it
+struct StepBlock<'a> {
+    steps: &'a [Step],
+    examples: Option<Vec<&'a Examples>>,
+    selected: bool,
 }
 
-fn extract_step_text(line: &str) -> &str {
-    let trimmed = line.trim();
+/// Step lists of the feature in the order they appear in the file, marked
+/// as selected when the tag filter (if any) matches
+fn blocks_in_file_order<'a>(
+    feature: &'a Feature,
+    filter: Option<&TagExpression>,
+) -> Vec<StepBlock<'a>> {
+    fn push_container<'a>(
+        blocks: &mut Vec<StepBlock<'a>>,
+        background: &'a Option<Background>,
+        scenarios: &'a [Scenario],
+        inherited: &[String],
+        filter: Option<&TagExpression>,
+    ) {
+        let matches = |tags: &[String]| filter.is_none_or(|expr| expr.matches(tags));
+        let mut container_blocks = Vec::new();
 
-    // Remove the keyword (Given, When, Then, And, But)
-    if let Some(pos) = trimmed.find(' ') {
-        &trimmed[pos + 1..]
-    } else {
-        trimmed
+        for scenario in scenarios {
+            let tags = merge_tags(inherited, &scenario.tags);
+            if scenario.is_outline() {
+                let examples: Vec<&Examples> = scenario
+                    .examples
+                    .iter()
+                    .filter(|e| matches(&merge_tags(&tags, &e.tags)))
+                    .collect();
+                container_blocks.push(StepBlock {
+                    steps: &scenario.steps,
+                    selected: !examples.is_empty(),
+                    examples: Some(examples),
+                });
+            } else {
+                container_blocks.push(StepBlock {
+                    steps: &scenario.steps,
+                    examples: None,
+                    selected: matches(&tags),
+                });
+            }
+        }
+
+        if let Some(background) = background {
+            blocks.push(StepBlock {
+                steps: &background.steps,
+                examples: None,
+                selected: container_blocks.iter().any(|b| b.selected),
+            });
+        }
+        blocks.extend(container_blocks);
     }
+
+    let mut blocks = Vec::new();
+    push_container(
+        &mut blocks,
+        &feature.background,
+        &feature.scenarios,
+        &feature.tags,
+        filter,
+    );
+    for rule in &feature.rules {
+        let inherited = merge_tags(&feature.tags, &rule.tags);
+        push_container(
+            &mut blocks,
+            &rule.background,
+            &rule.scenarios,
+            &inherited,
+            filter,
+        );
+    }
+
+    blocks
 }
 
 #[cfg(test)]
@@ -109,6 +237,7 @@ Feature: Login
         let result = validate_feature_content(feature).unwrap();
         assert!(!result.is_valid(), "Invalid step should fail validation");
         assert!(!result.errors.is_empty());
+        assert_eq!(result.errors[0].location, Some(Location::new(4, 5)));
     }
 
     #[test]
@@ -121,4 +250,87 @@ Scenario: No feature
         let result = validate_feature_content(feature).unwrap();
         assert!(!result.is_valid(), "Missing Feature should fail");
     }
+
+    #[test]
+    fn test_validate_outline_substitutes_examples() {
+        let feature = r#"
+Feature: Search
+  Background:
+    Given I navigate to "https://example.com"
+
+  Scenario Outline: Search
+    When I type "<term>" into "input.search"
+    Then I should see "<term>"
+
+    Examples:
+      | term   |
+      | laptop |
+"#;
+
+        let result = validate_feature_content(feature).unwrap();
+        assert!(result.is_valid(), "Outline steps should validate");
+    }
+
+    #[test]
+    fn test_validate_reports_parse_errors() {
+        let feature = r#"
+Feature: Broken
+  Scenario Outline: No examples
+    Given I navigate to "<url>"
+"#;
+
+        let result = validate_feature_content(feature).unwrap();
+        assert!(!result.is_valid());
+        assert_eq!(result.errors[0].error_type, "PARSE_ERROR");
+        assert_eq!(result.errors[0].location.map(|l| l.line), Some(3));
+    }
+
+    #[test]
+    fn test_validate_only_tagged_scenarios() {
+        let feature = r#"
+Feature: Tags
+  @smoke
+  Scenario: Good
+    Given I navigate to "https://example.com"
+
+  @wip
+  Scenario: Unfinished
+    Given I foobarbaz something
+"#;
+
+        let all = validate_feature_content(feature).unwrap();
+        assert!(!all.is_valid());
+
+        let options = ValidationOptions {
+            tags: Some(TagExpression::parse("not @wip").unwrap()),
+            ..Default::default()
+        };
+        let smoke = validate_feature_content_with(feature, &options).unwrap();
+        assert!(
+            smoke.is_valid(),
+            "Excluded scenarios should not be validated"
+        );
+    }
+
+    #[test]
+    fn test_validate_against_custom_catalog() {
+        let feature = r#"
+Feature: Custom
+  Scenario: Admin
+    Given I log in as an admin
+"#;
+        assert!(!validate_feature_content(feature).unwrap().is_valid());
+
+        let step =
+            crate::runner::CustomStep::from_fn("login_as_admin", r"I log in as an admin", |_| {
+                Box::pin(async { Ok(String::new()) })
+            });
+        let runner = crate::Runner::new().with_step(step).unwrap();
+        let options = ValidationOptions::default().with_catalog(runner.catalog());
+        assert!(
+            validate_feature_content_with(feature, &options)
+                .unwrap()
+                .is_valid()
+        );
+    }
 }
@@ -4,4 +4,4 @@ pub mod feature;
 pub mod step;
 
 pub use errors::{ValidationError, ValidationResult, ValidationWarning};
-pub use feature::validate_feature;
+pub use feature::{ValidationOptions, validate_feature, validate_feature_with};
@@ -1,30 +1,61 @@
 // Step-level validation
 use super::errors::ValidationError;
 use crate::discovery::catalog::StepCatalog;
-use regex::Regex;
-
+use crate::gherkin::timeout::split_step_timeout;
+use crate::runner::registry::StepRegistry;
+
+/// Check a step against the compiled `registry`, suggesting similar `catalog` steps
+///
+/// Build the registry once per run with
+/// [`registry_for_catalog`](crate::runner::registry::registry_for_catalog).
+#[allow(clippy::result_large_err)]
 pub fn validate_step(
     step_text: &str,
     step_number: usize,
+    registry: &StepRegistry,
     catalog: &StepCatalog,
 ) -> Result<(), ValidationError> {
-    // Try to match against any registered step pattern (including aliases)
-    for step_info in catalog.all_steps() {
-        // Check main pattern
-        if let Ok(regex) = Regex::new(&step_info.pattern) {
-            if regex.is_match(step_text) {
-                return Ok(());
-            }
+    // A trailing `(timeout: ...)` is read by the runner, not matched
+    let step_text = split_step_timeout(step_text).0;
+
+    // Custom and macro steps shadow built-ins, as in the runner
+    let matches = registry.match_all(step_text);
+    match matches.len() {
+        0 => {}
+        1 => return Ok(()),
+        _ => {
+            let candidates: Vec<&str> = matches.iter().map(|(id, _)| id.as_str()).collect();
+            return Err(ValidationError::new(
+                "AMBIGUOUS_STEP",
+                format!(
+                    "Step '{}' matches {} steps: {}",
+                    step_text,
+                    candidates.len(),
+                    candidates.join(", ")
+                ),
+            )
+            .with_step(step_number, step_text.to_string())
+            .with_suggestions(
+                candidates
+                    .iter()
+                    .map(|id| format!("Run 'web-spec search-steps {}' to see its patterns", id))
+                    .collect(),
+            )
+            .with_suggestion("Reword the step or change one of the patterns so only one matches"));
         }
+    }
 
-        // Check aliases
-        for alias in &step_info.aliases {
-            if let Ok(regex) = Regex::new(alias) {
-                if regex.is_match(step_text) {
-                    return Ok(());
-                }
-            }
-        }
+    // A step that only fails on a typed parameter gets a precise error
+    if let Some((id, reason)) = registry.explain_mismatch(step_text) {
+        return Err(ValidationError::new(
+            "INVALID_PARAMETER",
+            format!("Step '{}' matches '{}' but {}", step_text, id, reason),
+        )
+        .with_step(step_number, step_text.to_string())
+        .with_suggestion(format!(
+            "Run 'web-spec search-steps {}' to see the expected parameters",
+            id
+        )));
     }
 
     // If no match found, try to find similar steps with enhanced suggestions
@@ -98,18 +129,92 @@ fn find_similar_steps_with_details(step_text: &str, catalog: &StepCatalog) -> Ve
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::runner::registry::registry_for_catalog;
+
+    #[allow(clippy::result_large_err)]
+    fn validate(
+        step_text: &str,
+        step_number: usize,
+        catalog: &StepCatalog,
+    ) -> Result<(), ValidationError> {
+        validate_step(
+            step_text,
+            step_number,
+            &registry_for_catalog(catalog),
+            catalog,
+        )
+    }
 
     #[test]
     fn test_validate_known_step() {
         let catalog = crate::discovery::catalog::build_step_catalog();
-        let result = validate_step("I click on \"button.login\"", 1, &catalog);
+        let result = validate("I click on \"button.login\"", 1, &catalog);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_validate_ignores_timeout_suffix() {
+        let catalog = crate::discovery::catalog::build_step_catalog();
+        let result = validate("I click on \"button.login\" (timeout: 5s)", 1, &catalog);
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_validate_unknown_step() {
         let catalog = crate::discovery::catalog::build_step_catalog();
-        let result = validate_step("I foobarbaz on \"button\"", 1, &catalog);
+        let result = validate("I foobarbaz on \"button\"", 1, &catalog);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_validate_reports_badly_typed_parameters() {
+        let mut catalog = crate::discovery::catalog::build_step_catalog();
+        catalog.add_step(crate::discovery::catalog::StepInfo {
+            id: "open_tab".to_string(),
+            pattern: "I open tab number {int}".to_string(),
+            aliases: vec![],
+            category: "Custom".to_string(),
+            description: "Open a tab".to_string(),
+            parameters: vec![],
+            examples: vec![],
+        });
+
+        assert!(validate("I open tab number 2", 1, &catalog).is_ok());
+        let error = validate("I open tab number two", 1, &catalog).unwrap_err();
+        assert_eq!(error.error_type, "INVALID_PARAMETER");
+        assert!(error.message.contains("an integer"));
+    }
+
+    fn custom_step(id: &str, pattern: &str) -> crate::discovery::catalog::StepInfo {
+        crate::discovery::catalog::StepInfo {
+            id: id.to_string(),
+            pattern: pattern.to_string(),
+            aliases: vec![],
+            category: "Custom".to_string(),
+            description: format!("Custom step {}", id),
+            parameters: vec![],
+            examples: vec![],
+        }
+    }
+
+    #[test]
+    fn test_validate_reports_ambiguous_steps() {
+        let mut catalog = crate::discovery::catalog::build_step_catalog();
+        catalog.add_step(custom_step("open_admin", r#"I open "admin""#));
+        catalog.add_step(custom_step("open_page", "I open {string}"));
+
+        let error = validate(r#"I open "admin""#, 3, &catalog).unwrap_err();
+        assert_eq!(error.error_type, "AMBIGUOUS_STEP");
+        assert!(error.message.contains("open_admin, open_page"));
+        assert_eq!(error.step_number, Some(3));
+        assert!(validate(r#"I open "reports""#, 3, &catalog).is_ok());
+    }
+
+    #[test]
+    fn test_custom_steps_shadow_builtins_without_ambiguity() {
+        let mut catalog = crate::discovery::catalog::build_step_catalog();
+        catalog.add_step(custom_step("click_login", r##"I click on "#login""##));
+
+        assert!(validate(r##"I click on "#login""##, 1, &catalog).is_ok());
+    }
 }
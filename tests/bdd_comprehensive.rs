@@ -210,6 +210,91 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_batch_empty_shards_write_results() {
+        let temp_dir = create_temp_dir();
+        fs::write(
+            temp_dir.path().join("only.feature"),
+            "Feature: Only feature\n",
+        )
+        .expect("Failed to write feature file");
+
+        let mut total_features = 0;
+        for index in 1..=3 {
+            let output_file = temp_dir.path().join(format!("shard_{}.json", index));
+            let output = Command::new(&get_cargo_bin())
+                .args(&[
+                    "batch",
+                    "--path",
+                    temp_dir.path().to_str().unwrap(),
+                    "--shard",
+                    &format!("{}/3", index),
+                    "--format",
+                    "json",
+                    "--output",
+                    output_file.to_str().unwrap(),
+                ])
+                .output()
+                .expect("Failed to run batch command");
+
+            assert_eq!(output.status.code().unwrap_or(-1), 0);
+            let json: serde_json::Value = serde_json::from_str(
+                &fs::read_to_string(&output_file).expect("Every shard should write its result"),
+            )
+            .expect("Shard result should be JSON");
+            total_features += json["batch_summary"]["total_features"].as_u64().unwrap();
+        }
+        assert_eq!(total_features, 1);
+    }
+
+    #[test]
+    fn test_batch_stops_starting_features_unless_continuing() {
+        let temp_dir = create_temp_dir();
+        for name in ["first", "second"] {
+            fs::write(
+                temp_dir.path().join(format!("{}.feature", name)),
+                "Feature: Failing\n  Scenario: Fails\n    Given a step nobody defined\n",
+            )
+            .expect("Failed to write feature file");
+        }
+
+        let statuses = |continue_flag: &str| {
+            let output_file = temp_dir.path().join("result.json");
+            let output = Command::new(&get_cargo_bin())
+                .args(&[
+                    "batch",
+                    "--path",
+                    temp_dir.path().to_str().unwrap(),
+                    "--sequential",
+                    continue_flag,
+                    "--format",
+                    "json",
+                    "--output",
+                    output_file.to_str().unwrap(),
+                ])
+                .output()
+                .expect("Failed to run batch command");
+            assert_eq!(output.status.code().unwrap_or(-1), 0);
+
+            let json: serde_json::Value =
+                serde_json::from_str(&fs::read_to_string(&output_file).unwrap()).unwrap();
+            let mut statuses: Vec<String> = json["features"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|feature| feature["status"].as_str().unwrap().to_string())
+                .collect();
+            statuses.sort();
+            statuses
+        };
+
+        assert_eq!(
+            statuses("--continue-on-failure=false"),
+            vec!["failed", "skipped"]
+        );
+        assert_eq!(statuses("--continue-on-failure"), vec!["failed", "failed"]);
+    }
+
     #[test]
     fn test_export_schema() {
         let temp_dir = create_temp_dir();
@@ -107,3 +107,19 @@ fn test_search_steps_json_output() {
         "JSON output should be array or object"
     );
 }
+
+#[test]
+fn test_list_steps_includes_macro_steps() {
+    let output = run_cli_command(&[
+        "list-steps",
+        "--category",
+        "Custom",
+        "--steps",
+        "examples/steps.yml",
+    ]);
+    assert!(output.status.success(), "list-steps with --steps failed");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("[Custom] search_example_site"));
+    assert!(stdout.contains("[Custom] open_example_home"));
+}